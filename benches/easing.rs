@@ -0,0 +1,183 @@
+//! Benchmarks for the easing systems.
+//!
+//! Each benchmark spawns a number of entities with a single easing state, and measures running
+//! the easing systems in [`RunFixedMainLoop`] once. No fixed timesteps are run, so only
+//! the easing systems and the bookkeeping around them are measured.
+//!
+//! Run with `cargo bench --bench easing`.
+//!
+//! The serial and parallel iteration paths of the easing systems are selected at compile time,
+//! so they are compared by running the benchmarks with different features:
+//!
+//! - Parallel: `cargo bench --bench easing`
+//! - Serial: `cargo bench --bench easing --features deterministic`
+//!
+//! The entity counts range from `1_000` to `100_000` to show the entity count at which
+//! parallel iteration starts to pay off.
+//!
+//! Before the throughput benchmarks, the maximum angular error of [`RotationEasingMode::Nlerp`]
+//! compared to `slerp` is printed for several rotation angles per fixed timestep.
+
+use bevy::{ecs::query::QueryData, math::ops, prelude::*};
+use bevy_transform_interpolation::{
+    hermite::{RotationHermiteEasing, TranslationHermiteEasing},
+    prelude::*,
+    RotationEasingState, ScaleEasingState, TranslationEasingState, VelocitySource,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const ENTITY_COUNTS: [usize; 4] = [1_000, 10_000, 50_000, 100_000];
+
+#[derive(Component, Default)]
+struct LinearVelocity(Vec3);
+
+#[derive(Component, Default)]
+struct AngularVelocity(Vec3);
+
+#[derive(QueryData)]
+struct LinVelSource;
+
+impl VelocitySource for LinVelSource {
+    type Previous = LinearVelocity;
+    type Current = LinearVelocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.0
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.0
+    }
+}
+
+#[derive(QueryData)]
+struct AngVelSource;
+
+impl VelocitySource for AngVelSource {
+    type Previous = AngularVelocity;
+    type Current = AngularVelocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.0
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.0
+    }
+}
+
+/// Creates an app with the easing plugins and no fixed timesteps.
+fn create_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        // Use a fixed overstep fraction, since no time passes.
+        TransformEasingPlugin {
+            overstep_clamp: OverstepClamp { min: 0.5, max: 0.5 },
+            ..default()
+        },
+        TransformHermiteEasingPlugin::<LinVelSource, AngVelSource>::default(),
+    ));
+
+    app.finish();
+    app.cleanup();
+
+    app
+}
+
+fn translation_state() -> TranslationEasingState {
+    TranslationEasingState {
+        start: Some(Vec3::ZERO),
+        end: Some(Vec3::new(1.0, 2.0, 3.0)),
+    }
+}
+
+fn rotation_state() -> RotationEasingState {
+    RotationEasingState {
+        start: Some(Quat::IDENTITY),
+        end: Some(Quat::from_rotation_y(1.0)),
+    }
+}
+
+fn scale_state() -> ScaleEasingState {
+    ScaleEasingState {
+        start: Some(Vec3::ONE),
+        end: Some(Vec3::splat(2.0)),
+    }
+}
+
+/// Benchmarks easing for `count` entities spawned with the given bundle.
+fn bench_easing<B: Bundle>(c: &mut Criterion, name: &str, bundle: impl Fn() -> B) {
+    let mut group = c.benchmark_group(name);
+
+    for count in ENTITY_COUNTS {
+        let mut app = create_app();
+        app.world_mut()
+            .spawn_batch((0..count).map(|_| (Transform::default(), bundle())));
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| app.world_mut().run_schedule(RunFixedMainLoop));
+        });
+    }
+
+    group.finish();
+}
+
+/// The rotation angles per fixed timestep, in degrees, for which the quality of `nlerp` is measured.
+const NLERP_STEP_ANGLES: [f32; 7] = [1.0, 5.0, 10.0, 30.0, 45.0, 90.0, 180.0];
+
+/// Prints the maximum angular error of `nlerp` compared to `slerp` over the easing alpha,
+/// for each angle in [`NLERP_STEP_ANGLES`].
+fn nlerp_quality() {
+    println!("nlerp_quality: maximum angular error compared to slerp");
+
+    for degrees in NLERP_STEP_ANGLES {
+        let start = Quat::IDENTITY;
+        let end = Quat::from_rotation_y(degrees.to_radians());
+
+        let max_error = (0..=1000)
+            .map(|i| {
+                let alpha = i as f32 / 1000.0;
+                let nlerp = start.lerp(end, alpha).normalize();
+                let slerp = start.slerp(end, alpha);
+                // `Quat::angle_between` loses precision for small angles, so compare the signed angles instead.
+                let angle = |q: Quat| 2.0 * ops::atan2(q.y, q.w);
+                (angle(nlerp) - angle(slerp)).abs()
+            })
+            .fold(0.0, f32::max);
+
+        println!("  {degrees:>5.1}° per step: {:.6}°", max_error.to_degrees());
+    }
+}
+
+fn easing_benchmarks(c: &mut Criterion) {
+    nlerp_quality();
+
+    bench_easing(c, "ease_translation_lerp", translation_state);
+    bench_easing(c, "ease_translation_lerp_culled", || {
+        (translation_state(), EasingCulled)
+    });
+    bench_easing(c, "ease_rotation_slerp", rotation_state);
+    bench_easing(c, "ease_rotation_nlerp", || {
+        (rotation_state(), RotationEasingMode::Nlerp)
+    });
+    bench_easing(c, "ease_scale_lerp", scale_state);
+    bench_easing(c, "ease_translation_hermite", || {
+        (
+            translation_state(),
+            TranslationHermiteEasing,
+            LinearVelocity(Vec3::X),
+        )
+    });
+    bench_easing(c, "ease_rotation_hermite", || {
+        (
+            rotation_state(),
+            RotationHermiteEasing,
+            AngularVelocity(Vec3::Y),
+        )
+    });
+}
+
+criterion_group!(benches, easing_benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,205 @@
+//! This example showcases how the `FixedAnimationPlugin` can be used to run Bevy's animation system
+//! in the fixed timestep, and how the animated bones of a skeleton can be interpolated.
+//!
+//! Both skeletons play the same animation at a very low fixed timestep. The bones of the top skeleton
+//! use transform interpolation, so the animation appears smooth, while the bottom skeleton is only updated
+//! on fixed ticks.
+
+use bevy::{
+    animation::{animated_field, AnimationTarget, AnimationTargetId},
+    color::palettes::{
+        css::WHITE,
+        tailwind::{CYAN_400, RED_400},
+    },
+    prelude::*,
+};
+use bevy_transform_interpolation::{
+    animation::{FixedAnimationPlayer, FixedAnimationPlugin},
+    prelude::*,
+};
+
+/// The names of the bones in each skeleton, from the root to the tip.
+const BONES: [&str; 3] = ["upper", "lower", "hand"];
+
+/// The length of each bone.
+const BONE_LENGTH: f32 = 120.0;
+
+fn main() {
+    let mut app = App::new();
+
+    // Add the `FixedAnimationPlugin` to animate players with `FixedAnimationPlayer` in `FixedUpdate`,
+    // and the `TransformInterpolationPlugin` to interpolate the animated transforms.
+    app.add_plugins((
+        DefaultPlugins,
+        TransformInterpolationPlugin::default(),
+        FixedAnimationPlugin,
+    ));
+
+    // Set the fixed timestep to just 5 Hz for demonstration purposes.
+    app.insert_resource(Time::<Fixed>::from_hz(5.0));
+
+    // Setup the scene and UI.
+    app.add_systems(Startup, (setup, setup_text));
+
+    // Run the app.
+    app.run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    // Spawn a camera.
+    commands.spawn(Camera2d);
+
+    // Create an animation that swings each bone back and forth.
+    let names = BONES.map(Name::new);
+    let mut clip = AnimationClip::default();
+    for (i, bone) in BONES.iter().enumerate() {
+        let target_id = AnimationTargetId::from_names(names[..=i].iter());
+        let swing = 0.6 + 0.2 * i as f32;
+
+        clip.add_curve_to_target(
+            target_id,
+            UnevenSampleAutoCurve::new([0.0, 1.0, 2.0].into_iter().zip([
+                Quat::from_rotation_z(-swing),
+                Quat::from_rotation_z(swing),
+                Quat::from_rotation_z(-swing),
+            ]))
+            .map(|curve| AnimatableCurve::new(animated_field!(Transform::rotation), curve))
+            .unwrap_or_else(|_| panic!("failed to create rotation curve for bone `{bone}`")),
+        );
+    }
+
+    let (graph, animation_index) = AnimationGraph::from_clip(clips.add(clip));
+    let graph = graphs.add(graph);
+
+    let mesh = meshes.add(Rectangle::new(BONE_LENGTH, 20.0));
+
+    // This skeleton uses transform interpolation.
+    spawn_skeleton(
+        &mut commands,
+        Vec3::new(-BONE_LENGTH * 1.5, 120.0, 0.0),
+        mesh.clone(),
+        materials.add(Color::from(CYAN_400)),
+        graph.clone(),
+        animation_index,
+        true,
+    );
+
+    // This skeleton is animated in `FixedUpdate` without any smoothing.
+    spawn_skeleton(
+        &mut commands,
+        Vec3::new(-BONE_LENGTH * 1.5, -120.0, 0.0),
+        mesh,
+        materials.add(Color::from(RED_400)),
+        graph,
+        animation_index,
+        false,
+    );
+}
+
+/// Spawns a chain of bones animated by an `AnimationPlayer` on the root bone.
+fn spawn_skeleton(
+    commands: &mut Commands,
+    position: Vec3,
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+    graph: Handle<AnimationGraph>,
+    animation_index: AnimationNodeIndex,
+    interpolate: bool,
+) {
+    let mut player = AnimationPlayer::default();
+    player.play(animation_index).repeat();
+
+    let root = commands
+        .spawn((
+            Transform::from_translation(position),
+            Visibility::default(),
+            player,
+            AnimationGraphHandle(graph),
+            // Animate the skeleton in `FixedUpdate`.
+            FixedAnimationPlayer,
+        ))
+        .id();
+
+    let names = BONES.map(Name::new);
+    let mut parent = root;
+    for (i, name) in names.iter().enumerate() {
+        let target_id = AnimationTargetId::from_names(names[..=i].iter());
+
+        // The first bone is attached to the root, and each following bone to the end of the previous one.
+        let offset = if i == 0 { 0.0 } else { BONE_LENGTH };
+
+        let mut entity = commands.spawn((
+            name.clone(),
+            Transform::from_xyz(offset, 0.0, 0.0),
+            Visibility::default(),
+            AnimationTarget {
+                id: target_id,
+                player: root,
+            },
+        ));
+
+        if interpolate {
+            entity.insert(TransformInterpolation);
+        }
+
+        // Offset the mesh so that the bone rotates around its start.
+        entity.with_child((
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(material.clone()),
+            Transform::from_xyz(BONE_LENGTH / 2.0, 0.0, 0.0),
+        ));
+
+        let entity = entity.id();
+        commands.entity(parent).add_child(entity);
+        parent = entity;
+    }
+}
+
+fn setup_text(mut commands: Commands) {
+    let font = TextFont {
+        font_size: 20.0,
+        ..default()
+    };
+
+    commands.spawn((
+        Text::new("Fixed Hz: 5"),
+        TextColor::from(WHITE),
+        font.clone(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+
+    commands.spawn((
+        Text::new("Interpolation"),
+        TextColor::from(CYAN_400),
+        font.clone(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(50.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+
+    commands.spawn((
+        Text::new("No Interpolation"),
+        TextColor::from(RED_400),
+        font.clone(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(75.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
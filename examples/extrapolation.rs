@@ -14,13 +14,12 @@ use bevy::{
         css::WHITE,
         tailwind::{CYAN_400, LIME_400, RED_400},
     },
-    ecs::query::QueryData,
     prelude::*,
 };
 use bevy_transform_interpolation::{
     extrapolation::{TransformExtrapolation, TransformExtrapolationPlugin},
     prelude::*,
-    VelocitySource,
+    velocity::{AngVel2dSource, LinVel2dSource},
 };
 
 const MOVEMENT_SPEED: f32 = 250.0;
@@ -35,7 +34,7 @@ fn main() {
         DefaultPlugins,
         TransformInterpolationPlugin::default(),
         // We must specify "velocity sources" to tell the plugin how to extract velocity information.
-        // These are implemented below this function.
+        // The ready-made 2D velocity sources are defined below this function.
         TransformExtrapolationPlugin::<LinVelSource, AngVelSource>::default(),
     ));
 
@@ -56,6 +55,11 @@ fn main() {
     app.run();
 }
 
+/// The velocity sources used for extrapolation.
+/// For extrapolation, only the current velocity is needed.
+type LinVelSource = LinVel2dSource<LinearVelocity>;
+type AngVelSource = AngVel2dSource<AngularVelocity>;
+
 /// The linear velocity of an entity indicating its movement speed and direction.
 #[derive(Component, Deref, DerefMut)]
 struct LinearVelocity(Vec2);
@@ -64,40 +68,6 @@ struct LinearVelocity(Vec2);
 #[derive(Component, Deref, DerefMut)]
 struct AngularVelocity(f32);
 
-#[derive(QueryData)]
-struct LinVelSource;
-
-impl VelocitySource for LinVelSource {
-    // Components storing the previous and current velocities.
-    // Note: For extrapolation, the `Previous` component is not used, so we can make it the same as `Current`.
-    type Previous = LinearVelocity;
-    type Current = LinearVelocity;
-
-    fn previous(start: &Self::Previous) -> Vec3 {
-        start.0.extend(0.0)
-    }
-
-    fn current(end: &Self::Current) -> Vec3 {
-        end.0.extend(0.0)
-    }
-}
-
-#[derive(QueryData)]
-struct AngVelSource;
-
-impl VelocitySource for AngVelSource {
-    type Previous = AngularVelocity;
-    type Current = AngularVelocity;
-
-    fn previous(start: &Self::Previous) -> Vec3 {
-        Vec3::Z * start.0
-    }
-
-    fn current(end: &Self::Current) -> Vec3 {
-        Vec3::Z * end.0
-    }
-}
-
 fn setup(
     mut commands: Commands,
     mut materials: ResMut<Assets<ColorMaterial>>,
@@ -14,13 +14,12 @@ use bevy::{
         css::WHITE,
         tailwind::{CYAN_400, LIME_400, RED_400},
     },
-    ecs::query::QueryData,
     prelude::*,
 };
 use bevy_transform_interpolation::{
-    hermite::{RotationHermiteEasing, TransformHermiteEasingPlugin, TranslationHermiteEasing},
+    hermite::TransformHermiteEasingPlugin,
     prelude::*,
-    VelocitySource,
+    velocity::{AngVel2dSource, LinVel2dSource, Previous, TrackPreviousPlugin},
 };
 
 const MOVEMENT_SPEED: f32 = 250.0;
@@ -35,14 +34,14 @@ fn main() {
         DefaultPlugins,
         TransformInterpolationPlugin::default(),
         // We must specify "velocity sources" to tell the plugin how to extract velocity information.
-        // These are implemented below this function.
+        // The ready-made 2D velocity sources are defined below this function.
         TransformHermiteEasingPlugin::<LinVelSource, AngVelSource>::default(),
+        // Hermite interpolation also needs the velocities from the previous fixed timestep.
+        // These plugins store them in `Previous` components automatically.
+        TrackPreviousPlugin::<LinearVelocity>::default(),
+        TrackPreviousPlugin::<AngularVelocity>::default(),
     ));
 
-    // Optional: Make the previous velocity components required for Hermite interpolation to insert them automatically.
-    app.register_required_components::<TranslationHermiteEasing, PreviousLinearVelocity>();
-    app.register_required_components::<RotationHermiteEasing, PreviousAngularVelocity>();
-
     // Set the fixed timestep to just 5 Hz for demonstration purposes.
     app.insert_resource(Time::<Fixed>::from_hz(5.0));
 
@@ -50,9 +49,6 @@ fn main() {
     app.add_systems(Startup, (setup, setup_text))
         .add_systems(Update, (change_timestep, update_timestep_text));
 
-    // Update the previous velocity components in `FixedPreUpdate`.
-    app.add_systems(FixedPreUpdate, update_previous_velocity);
-
     // Move entities in `FixedUpdate`. The movement should appear smooth for interpolated entities.
     app.add_systems(
         FixedUpdate,
@@ -63,67 +59,18 @@ fn main() {
     app.run();
 }
 
+/// The velocity sources used for Hermite interpolation.
+type LinVelSource = LinVel2dSource<LinearVelocity, Previous<LinearVelocity>>;
+type AngVelSource = AngVel2dSource<AngularVelocity, Previous<AngularVelocity>>;
+
 /// The linear velocity of an entity indicating its movement speed and direction.
-#[derive(Component, Default, Deref, DerefMut)]
+#[derive(Component, Clone, Default, Deref, DerefMut)]
 struct LinearVelocity(Vec2);
 
-/// The previous linear velocity of an entity indicating its movement speed and direction during the previous frame.
-#[derive(Component, Default, Deref, DerefMut)]
-struct PreviousLinearVelocity(Vec2);
-
 /// The angular velocity of an entity indicating its rotation speed.
-#[derive(Component, Default, Deref, DerefMut)]
+#[derive(Component, Clone, Default, Deref, DerefMut)]
 struct AngularVelocity(f32);
 
-/// The previous angular velocity of an entity indicating its rotation speed during the previous frame.
-#[derive(Component, Default, Deref, DerefMut)]
-struct PreviousAngularVelocity(f32);
-
-#[derive(QueryData)]
-struct LinVelSource;
-
-impl VelocitySource for LinVelSource {
-    type Previous = PreviousLinearVelocity;
-    type Current = LinearVelocity;
-
-    fn previous(start: &Self::Previous) -> Vec3 {
-        start.0.extend(0.0)
-    }
-
-    fn current(end: &Self::Current) -> Vec3 {
-        end.0.extend(0.0)
-    }
-}
-
-#[derive(QueryData)]
-struct AngVelSource;
-
-impl VelocitySource for AngVelSource {
-    type Previous = PreviousAngularVelocity;
-    type Current = AngularVelocity;
-
-    fn previous(start: &Self::Previous) -> Vec3 {
-        Vec3::Z * start.0
-    }
-
-    fn current(end: &Self::Current) -> Vec3 {
-        Vec3::Z * end.0
-    }
-}
-
-fn update_previous_velocity(
-    mut lin_vel_query: Query<(&LinearVelocity, &mut PreviousLinearVelocity)>,
-    mut ang_vel_query: Query<(&AngularVelocity, &mut PreviousAngularVelocity)>,
-) {
-    for (lin_vel, mut prev_lin_vel) in &mut lin_vel_query {
-        prev_lin_vel.0 = lin_vel.0;
-    }
-
-    for (ang_vel, mut prev_ang_vel) in &mut ang_vel_query {
-        prev_ang_vel.0 = ang_vel.0;
-    }
-}
-
 fn setup(
     mut commands: Commands,
     mut materials: ResMut<Assets<ColorMaterial>>,
@@ -0,0 +1,241 @@
+//! This example showcases how the diagnostics and gizmos of `bevy_transform_interpolation`
+//! can be used to understand and tune easing.
+//!
+//! A panel shows the number of interpolated and extrapolated entities, the current overstep alpha
+//! used for easing, and the average and maximum easing distance measured by the
+//! `TransformInterpolationDiagnosticsPlugin`. The gizmo visualization of the easing states
+//! drawn by the `TransformEasingGizmosPlugin` can be toggled with the G key.
+//!
+//! Run with `cargo run --example interpolation_debug --features gizmos`.
+
+use bevy::{
+    color::palettes::{
+        css::WHITE,
+        tailwind::{CYAN_400, LIME_400},
+    },
+    diagnostic::{DiagnosticPath, DiagnosticsStore},
+    prelude::*,
+};
+use bevy_transform_interpolation::{
+    diagnostics::TransformInterpolationDiagnosticsPlugin,
+    extrapolation::{TransformExtrapolation, TransformExtrapolationPlugin},
+    gizmos::{EasingGizmoConfig, TransformEasingGizmosPlugin},
+    prelude::*,
+    velocity::{AngVel2dSource, LinVel2dSource},
+};
+
+const MOVEMENT_SPEED: f32 = 250.0;
+const ROTATION_SPEED: f32 = 2.0;
+const ENTITY_COUNT: usize = 10;
+
+fn main() {
+    let mut app = App::new();
+
+    // Add the easing plugins, along with the diagnostics and gizmos used for debugging.
+    app.add_plugins((
+        DefaultPlugins,
+        TransformInterpolationPlugin::default(),
+        TransformExtrapolationPlugin::<LinVelSource, AngVelSource>::default(),
+        TransformInterpolationDiagnosticsPlugin::default(),
+        TransformEasingGizmosPlugin,
+    ));
+
+    // Draw larger rotation arcs to make them visible at this scale.
+    app.insert_resource(EasingGizmoConfig {
+        rotation_arc_radius: 40.0,
+        ..default()
+    });
+
+    // Set the fixed timestep to just 5 Hz for demonstration purposes.
+    app.insert_resource(Time::<Fixed>::from_hz(5.0));
+
+    // Setup the scene and UI, and update the debug panel in `Update`.
+    app.add_systems(Startup, (setup, setup_text))
+        .add_systems(Update, (change_timestep, toggle_gizmos, update_debug_text));
+
+    // Move entities in `FixedUpdate`.
+    app.add_systems(
+        FixedUpdate,
+        (flip_movement_direction.before(movement), movement, rotate),
+    );
+
+    // Run the app.
+    app.run();
+}
+
+/// The velocity sources used for extrapolation.
+type LinVelSource = LinVel2dSource<LinearVelocity>;
+type AngVelSource = AngVel2dSource<AngularVelocity>;
+
+/// The linear velocity of an entity indicating its movement speed and direction.
+#[derive(Component, Deref, DerefMut)]
+struct LinearVelocity(Vec2);
+
+/// The angular velocity of an entity indicating its rotation speed.
+#[derive(Component, Deref, DerefMut)]
+struct AngularVelocity(f32);
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    // Spawn a camera.
+    commands.spawn(Camera2d);
+
+    let mesh = meshes.add(Rectangle::from_length(30.0));
+    let interpolation_material = materials.add(Color::from(CYAN_400));
+    let extrapolation_material = materials.add(Color::from(LIME_400));
+
+    for i in 0..ENTITY_COUNT {
+        // Give each entity a different speed so that the easing distances vary.
+        let speed = MOVEMENT_SPEED * (0.5 + i as f32 / ENTITY_COUNT as f32);
+        let y = 300.0 - 60.0 * i as f32;
+
+        // Alternate between interpolation and extrapolation.
+        if i % 2 == 0 {
+            commands.spawn((
+                Mesh2d(mesh.clone()),
+                MeshMaterial2d(interpolation_material.clone()),
+                Transform::from_xyz(-400.0, y, 0.0),
+                TransformInterpolation,
+                LinearVelocity(Vec2::new(speed, 0.0)),
+                AngularVelocity(ROTATION_SPEED),
+            ));
+        } else {
+            commands.spawn((
+                Mesh2d(mesh.clone()),
+                MeshMaterial2d(extrapolation_material.clone()),
+                Transform::from_xyz(-400.0, y, 0.0),
+                TransformExtrapolation,
+                LinearVelocity(Vec2::new(speed, 0.0)),
+                AngularVelocity(ROTATION_SPEED),
+            ));
+        }
+    }
+}
+
+/// Flips the movement directions of objects when they reach the left or right side of the screen.
+fn flip_movement_direction(mut query: Query<(&Transform, &mut LinearVelocity)>) {
+    for (transform, mut lin_vel) in &mut query {
+        if (transform.translation.x > 400.0 && lin_vel.0.x > 0.0)
+            || (transform.translation.x < -400.0 && lin_vel.0.x < 0.0)
+        {
+            lin_vel.0.x = -lin_vel.0.x;
+        }
+    }
+}
+
+/// Changes the timestep of the simulation when the up or down arrow keys are pressed.
+fn change_timestep(mut time: ResMut<Time<Fixed>>, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.pressed(KeyCode::ArrowUp) {
+        let new_timestep = (time.delta_secs_f64() * 0.9).max(1.0 / 255.0);
+        time.set_timestep_seconds(new_timestep);
+    }
+    if keyboard_input.pressed(KeyCode::ArrowDown) {
+        let new_timestep = (time.delta_secs_f64() * 1.1).min(1.0);
+        time.set_timestep_seconds(new_timestep);
+    }
+}
+
+/// Toggles the gizmo visualization of easing states when the G key is pressed.
+fn toggle_gizmos(mut config: ResMut<EasingGizmoConfig>, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::KeyG) {
+        let enabled = !config.draw_translation;
+        config.draw_translation = enabled;
+        config.draw_rotation = enabled;
+        config.draw_scale = enabled;
+    }
+}
+
+/// Moves entities based on their `LinearVelocity`.
+fn movement(mut query: Query<(&mut Transform, &LinearVelocity)>, time: Res<Time>) {
+    let delta_secs = time.delta_secs();
+
+    for (mut transform, lin_vel) in &mut query {
+        transform.translation += lin_vel.extend(0.0) * delta_secs;
+    }
+}
+
+/// Rotates entities based on their `AngularVelocity`.
+fn rotate(mut query: Query<(&mut Transform, &AngularVelocity)>, time: Res<Time>) {
+    let delta_secs = time.delta_secs();
+
+    for (mut transform, ang_vel) in &mut query {
+        transform.rotate_local_z(ang_vel.0 * delta_secs);
+    }
+}
+
+#[derive(Component)]
+struct DebugText;
+
+fn setup_text(mut commands: Commands) {
+    let font = TextFont {
+        font_size: 20.0,
+        ..default()
+    };
+
+    commands.spawn((
+        DebugText,
+        Text::default(),
+        TextColor::from(WHITE),
+        font.clone(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+
+    commands.spawn((
+        Text::new("Change Timestep With Up/Down Arrow\nToggle Gizmos With G"),
+        TextColor::from(WHITE),
+        font,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
+
+fn update_debug_text(
+    mut text: Single<&mut Text, With<DebugText>>,
+    interpolated: Query<(), With<TranslationInterpolation>>,
+    extrapolated: Query<(), With<TranslationExtrapolation>>,
+    overstep: Res<EasingOverstep>,
+    diagnostics: Res<DiagnosticsStore>,
+    gizmo_config: Res<EasingGizmoConfig>,
+    time: Res<Time<Fixed>>,
+) {
+    let smoothed = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(|diagnostic| diagnostic.smoothed())
+            .unwrap_or(0.0)
+    };
+
+    let average_distance = smoothed(&TransformInterpolationDiagnosticsPlugin::EASING_DISTANCE);
+    let max_distance = smoothed(&TransformInterpolationDiagnosticsPlugin::MAX_EASING_DISTANCE);
+    let timestep = time.timestep().as_secs_f32().recip();
+    let gizmos = if gizmo_config.draw_translation {
+        "On"
+    } else {
+        "Off"
+    };
+
+    text.0 = format!(
+        "Fixed Hz: {timestep:.2}\n\
+         Interpolated: {}\n\
+         Extrapolated: {}\n\
+         Overstep Alpha: {:.2}\n\
+         Average Easing Distance: {average_distance:.2}\n\
+         Max Easing Distance: {max_distance:.2}\n\
+         Gizmos: {gizmos}",
+        interpolated.iter().count(),
+        extrapolated.iter().count(),
+        overstep.0,
+    );
+}
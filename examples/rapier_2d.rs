@@ -0,0 +1,152 @@
+//! This example showcases how `Transform` easing can be used with the Rapier physics engine
+//! to make the movement of rigid bodies appear smooth at fixed timesteps.
+//!
+//! Rapier must run in the fixed timestep for easing to work. This is done using
+//! `RapierPhysicsPlugin::in_fixed_schedule`. The `RapierInterpolationPlugin` sets up
+//! interpolation, extrapolation, and Hermite interpolation using Rapier's `Velocity` component.
+//!
+//! Run with `cargo run --example rapier_2d --features rapier2d`.
+
+use bevy::{
+    color::palettes::{
+        css::WHITE,
+        tailwind::{CYAN_400, LIME_400, RED_400},
+    },
+    prelude::*,
+};
+use bevy_rapier2d::prelude::*;
+use bevy_transform_interpolation::{
+    extrapolation::TransformExtrapolation, prelude::*, rapier2d::RapierInterpolationPlugin,
+};
+
+const MOVEMENT_SPEED: f32 = 250.0;
+const ROTATION_SPEED: f32 = 2.0;
+const FIXED_HZ: f64 = 5.0;
+
+fn main() {
+    let mut app = App::new();
+
+    // Run Rapier in `FixedPostUpdate`, and set up easing using Rapier's velocity components.
+    app.add_plugins((
+        DefaultPlugins,
+        RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule(),
+        RapierInterpolationPlugin,
+    ));
+
+    // Set the fixed timestep to just 5 Hz for demonstration purposes,
+    // and make Rapier step by the same amount.
+    app.insert_resource(Time::<Fixed>::from_hz(FIXED_HZ));
+    app.insert_resource(TimestepMode::Fixed {
+        dt: 1.0 / FIXED_HZ as f32,
+        substeps: 1,
+    });
+
+    // Setup the scene and UI.
+    app.add_systems(Startup, (setup, setup_text));
+
+    // Change the velocities of rigid bodies in `FixedUpdate`, before the physics step.
+    app.add_systems(FixedUpdate, flip_movement_direction);
+
+    // Run the app.
+    app.run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    // Spawn a camera.
+    commands.spawn(Camera2d);
+
+    let mesh = meshes.add(Rectangle::from_length(60.0));
+
+    // Kinematic rigid bodies are moved by their velocity, and are not affected by gravity.
+    let velocity = Velocity {
+        linvel: Vec2::new(MOVEMENT_SPEED, 0.0),
+        angvel: ROTATION_SPEED,
+    };
+
+    // This rigid body uses transform interpolation.
+    commands.spawn((
+        Name::new("Interpolation"),
+        RigidBody::KinematicVelocityBased,
+        velocity,
+        Mesh2d(mesh.clone()),
+        MeshMaterial2d(materials.add(Color::from(CYAN_400)).clone()),
+        Transform::from_xyz(-500.0, 120.0, 0.0),
+        TransformInterpolation,
+    ));
+
+    // This rigid body uses transform extrapolation.
+    commands.spawn((
+        Name::new("Extrapolation"),
+        RigidBody::KinematicVelocityBased,
+        velocity,
+        Mesh2d(mesh.clone()),
+        MeshMaterial2d(materials.add(Color::from(LIME_400)).clone()),
+        Transform::from_xyz(-500.0, 0.0, 0.0),
+        TransformExtrapolation,
+    ));
+
+    // This rigid body is simulated in the fixed timestep without any smoothing.
+    commands.spawn((
+        Name::new("No Interpolation"),
+        RigidBody::KinematicVelocityBased,
+        velocity,
+        Mesh2d(mesh.clone()),
+        MeshMaterial2d(materials.add(Color::from(RED_400)).clone()),
+        Transform::from_xyz(-500.0, -120.0, 0.0),
+    ));
+}
+
+/// Flips the movement directions of rigid bodies when they reach the left or right side of the screen.
+fn flip_movement_direction(mut query: Query<(&Transform, &mut Velocity)>) {
+    for (transform, mut velocity) in &mut query {
+        if transform.translation.x > 500.0 && velocity.linvel.x > 0.0 {
+            velocity.linvel = Vec2::new(-MOVEMENT_SPEED, 0.0);
+        } else if transform.translation.x < -500.0 && velocity.linvel.x < 0.0 {
+            velocity.linvel = Vec2::new(MOVEMENT_SPEED, 0.0);
+        }
+    }
+}
+
+fn setup_text(mut commands: Commands) {
+    let font = TextFont {
+        font_size: 20.0,
+        ..default()
+    };
+
+    for (i, (text, color)) in [
+        ("Interpolation", CYAN_400),
+        ("Extrapolation", LIME_400),
+        ("No Interpolation", RED_400),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands.spawn((
+            Text::new(text),
+            TextColor::from(color),
+            font.clone(),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0 + 25.0 * i as f32),
+                left: Val::Px(10.0),
+                ..default()
+            },
+        ));
+    }
+
+    commands.spawn((
+        Text::new(format!("Fixed Hz: {FIXED_HZ}")),
+        TextColor::from(WHITE),
+        font,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
@@ -0,0 +1,105 @@
+//! This example showcases how the eased `Transform` of an entity can be recorded
+//! and exported for debugging.
+//!
+//! The `EasingRecorderPlugin` records the eased `Transform` along with the `start` and `end`
+//! easing states every frame for entities with the `EasingRecorder` component.
+//! Press `R` to export the recording to `easing_recording.csv`.
+
+use bevy::{
+    color::palettes::{css::WHITE, tailwind::CYAN_400},
+    prelude::*,
+};
+use bevy_transform_interpolation::{
+    prelude::*,
+    recording::{EasingRecorder, EasingRecorderPlugin, ExportEasingRecording},
+};
+
+const MOVEMENT_SPEED: f32 = 250.0;
+
+fn main() {
+    let mut app = App::new();
+
+    // Add the `EasingRecorderPlugin` alongside the `TransformInterpolationPlugin` to record eased transforms.
+    app.add_plugins((
+        DefaultPlugins,
+        TransformInterpolationPlugin::default(),
+        EasingRecorderPlugin::default(),
+    ));
+
+    // Set the fixed timestep to just 5 Hz for demonstration purposes.
+    app.insert_resource(Time::<Fixed>::from_hz(5.0));
+
+    // Setup the scene and UI, and export the recording in `Update`.
+    app.add_systems(Startup, (setup, setup_text))
+        .add_systems(Update, export_recording);
+
+    // Move entities in `FixedUpdate`.
+    app.add_systems(FixedUpdate, movement);
+
+    // Run the app.
+    app.run();
+}
+
+/// The linear velocity of an entity indicating its movement speed and direction.
+#[derive(Component, Deref, DerefMut)]
+struct LinearVelocity(Vec2);
+
+fn setup(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    // Spawn a camera.
+    commands.spawn(Camera2d);
+
+    // This entity uses transform interpolation, and records the last 10 seconds of easing at 60 FPS.
+    commands.spawn((
+        Name::new("Interpolation"),
+        Mesh2d(meshes.add(Rectangle::from_length(60.0))),
+        MeshMaterial2d(materials.add(Color::from(CYAN_400))),
+        Transform::from_xyz(-500.0, 0.0, 0.0),
+        TransformInterpolation,
+        EasingRecorder::with_max_samples(600),
+        LinearVelocity(Vec2::new(MOVEMENT_SPEED, 0.0)),
+    ));
+}
+
+/// Moves entities based on their `LinearVelocity`, flipping the direction at the edges of the screen.
+fn movement(mut query: Query<(&mut Transform, &mut LinearVelocity)>, time: Res<Time>) {
+    let delta_secs = time.delta_secs();
+
+    for (mut transform, mut lin_vel) in &mut query {
+        if transform.translation.x > 500.0 && lin_vel.x > 0.0 {
+            lin_vel.0 = Vec2::new(-MOVEMENT_SPEED, 0.0);
+        } else if transform.translation.x < -500.0 && lin_vel.x < 0.0 {
+            lin_vel.0 = Vec2::new(MOVEMENT_SPEED, 0.0);
+        }
+
+        transform.translation += lin_vel.extend(0.0) * delta_secs;
+    }
+}
+
+/// Exports the recorded samples to a CSV file when `R` is pressed.
+fn export_recording(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::KeyR) {
+        commands.queue(ExportEasingRecording::new("easing_recording.csv"));
+        info!("Exported easing recording to `easing_recording.csv`");
+    }
+}
+
+fn setup_text(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Press R to export the recording to `easing_recording.csv`"),
+        TextColor::from(WHITE),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+}
@@ -0,0 +1,175 @@
+//! Running Bevy's animation system in the fixed timestep, so that animated transforms can be interpolated.
+//!
+//! Requires the `animation` feature.
+//!
+//! See the [`FixedAnimationPlugin`] for more information.
+
+use core::mem;
+
+use bevy::{
+    animation::{
+        advance_animations, animate_targets,
+        transition::{advance_transitions, expire_completed_transitions, AnimationTransitions},
+    },
+    app::Animation,
+    ecs::query::QueryFilter,
+    prelude::*,
+};
+
+/// A plugin that advances and evaluates animations of [`AnimationPlayer`]s with the [`FixedAnimationPlayer`] component
+/// in [`FixedUpdate`] instead of [`PostUpdate`], so that the animated [`Transform`]s of bones and other animation targets
+/// can be eased like any other movement in the fixed timestep.
+///
+/// By default, Bevy's `AnimationPlugin` animates targets once per frame in [`PostUpdate`], after easing
+/// has already been applied. The animated [`Transform`] then overwrites the eased one, and because it is changed
+/// outside of the fixed timestep, it is treated as a teleport. With this plugin, animations of fixed players are advanced
+/// by the fixed timestep instead, and the `end` of easing is updated in [`FixedLast`] like for any other change in [`FixedUpdate`].
+///
+/// Other animation players are still animated once per frame in [`PostUpdate`] as usual.
+///
+/// # Usage
+///
+/// Add the plugin along with an easing plugin such as the [`TransformInterpolationPlugin`],
+/// add the [`FixedAnimationPlayer`] component to animation players, and add interpolation to their animation targets.
+///
+/// ```no_run
+/// use bevy::{animation::AnimationTarget, prelude::*};
+/// use bevy_transform_interpolation::{
+///     animation::{FixedAnimationPlayer, FixedAnimationPlugin},
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((
+///         DefaultPlugins,
+///         TransformInterpolationPlugin::default(),
+///         FixedAnimationPlugin,
+///     ));
+///
+///     app.add_observer(animate_players_in_fixed_timestep)
+///         .add_observer(interpolate_animation_targets);
+///
+///     // ...
+/// }
+///
+/// // Animate all animation players in the fixed timestep.
+/// fn animate_players_in_fixed_timestep(trigger: Trigger<OnAdd, AnimationPlayer>, mut commands: Commands) {
+///     commands.entity(trigger.entity()).insert(FixedAnimationPlayer);
+/// }
+///
+/// // Interpolate all animation targets, such as the bones of loaded skeletons.
+/// fn interpolate_animation_targets(trigger: Trigger<OnAdd, AnimationTarget>, mut commands: Commands) {
+///     commands.entity(trigger.entity()).insert(TransformInterpolation);
+/// }
+/// ```
+///
+/// # Caveats
+///
+/// Only the systems for advancing transitions and animations and for evaluating animation targets
+/// are run in the fixed timestep. Bevy's system for preparing [`AnimationGraph`]s for evaluation,
+/// `thread_animation_graphs`, is `pub(crate)` in `bevy_animation`, so it can't be run in [`FixedUpdate`].
+/// Bevy's [`Animation`] system set therefore keeps running in [`PostUpdate`] to prepare the graphs,
+/// and the [`AnimationGraphHandle`] and [`AnimationTransitions`] of fixed players are temporarily taken
+/// out while it runs, so that their animations are not also advanced by the frame time.
+/// An [`AnimationGraph`] that was just added is only used by fixed players after it has been prepared
+/// in [`PostUpdate`].
+///
+/// Animations are advanced by the fixed timestep, so they start, finish, and trigger events
+/// on fixed timestep boundaries.
+///
+/// This plugin requires Bevy's `AnimationPlugin`, which is included in the [`DefaultPlugins`].
+///
+/// [`TransformInterpolationPlugin`]: crate::interpolation::TransformInterpolationPlugin
+#[derive(Debug, Default)]
+pub struct FixedAnimationPlugin;
+
+impl Plugin for FixedAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<FixedAnimationPlayer>();
+
+        app.init_resource::<StashedAnimations>();
+
+        // Hide fixed players from the animation systems in `PostUpdate`,
+        // which still prepare animation graphs and animate the other players.
+        app.add_systems(
+            PostUpdate,
+            (
+                stash_animations::<With<FixedAnimationPlayer>>.before(Animation),
+                restore_stashed_animations.after(Animation),
+            ),
+        );
+
+        // Advance and evaluate the animations of fixed players in the fixed timestep.
+        app.add_systems(
+            FixedUpdate,
+            (
+                stash_animations::<Without<FixedAnimationPlayer>>,
+                advance_transitions,
+                advance_animations,
+                animate_targets,
+                expire_completed_transitions,
+                restore_stashed_animations,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// A marker component for [`AnimationPlayer`]s whose animations are advanced and evaluated
+/// in the fixed timestep by the [`FixedAnimationPlugin`].
+///
+/// Players without this component are animated once per frame in [`PostUpdate`] as usual.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct FixedAnimationPlayer;
+
+/// The animation graphs and transitions that were taken out of animation players
+/// to hide them from the animation systems running in the current schedule.
+#[derive(Resource, Default)]
+struct StashedAnimations(Vec<(Entity, AnimationGraphHandle, Option<AnimationTransitions>)>);
+
+/// Takes the [`AnimationGraphHandle`] and [`AnimationTransitions`] out of the animation players matching `F`.
+///
+/// Without a valid animation graph, Bevy's animation systems skip the player and its targets,
+/// and without transitions, the transitions of the player are not advanced or expired.
+fn stash_animations<F: QueryFilter>(
+    mut players: Query<
+        (
+            Entity,
+            &mut AnimationGraphHandle,
+            Option<&mut AnimationTransitions>,
+        ),
+        (With<AnimationPlayer>, F),
+    >,
+    mut stashed: ResMut<StashedAnimations>,
+) {
+    for (entity, mut graph, transitions) in &mut players {
+        stashed.0.push((
+            entity,
+            mem::take(&mut *graph),
+            transitions.map(|mut transitions| mem::take(&mut *transitions)),
+        ));
+    }
+}
+
+/// Puts the stashed [`AnimationGraphHandle`]s and [`AnimationTransitions`] back into their animation players.
+fn restore_stashed_animations(
+    mut players: Query<(&mut AnimationGraphHandle, Option<&mut AnimationTransitions>)>,
+    mut stashed: ResMut<StashedAnimations>,
+) {
+    for (entity, graph, transitions) in stashed.0.drain(..) {
+        // Players that were despawned in between drop their stashed state.
+        let Ok((mut player_graph, player_transitions)) = players.get_mut(entity) else {
+            continue;
+        };
+
+        *player_graph = graph;
+        if let (Some(mut player_transitions), Some(transitions)) = (player_transitions, transitions)
+        {
+            *player_transitions = transitions;
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! [`Transform`] easing for targets that arrive asynchronously with uncertain timing,
+//! such as results from background tasks or network messages.
+//!
+//! See the [`AsyncTransformEasingPlugin`] for more information.
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+use crate::{for_each_mut, EasingPaused, EasingSchedules, TransformEasingSet};
+
+/// A plugin for easing [`Transform`] towards targets that arrive asynchronously with uncertain timing.
+///
+/// The default easing backends assume that the `end` of the easing is known at the end of each fixed timestep,
+/// and that the next one arrives exactly one timestep later. When targets are instead produced by a background task
+/// or received over the network, they can arrive earlier or later than expected.
+///
+/// The [`AsyncTransformEasing`] component handles this gracefully:
+///
+/// - When a new target arrives, easing restarts from the *current eased* [`Transform`] rather than from the previous target.
+///   This means that targets arriving early never cause a visible jump.
+/// - When the next target is late, the [`Transform`] holds at the latest target until a new one arrives.
+///
+/// This plugin is independent from the [`TransformEasingPlugin`](crate::TransformEasingPlugin),
+/// and the easing state components are not used for [`AsyncTransformEasing`].
+///
+/// # Usage
+///
+/// Add the [`AsyncTransformEasing`] component to an entity, and push new targets to it with
+/// [`AsyncTransformEasing::push_target`] whenever they arrive, along with the expected time
+/// until the next target arrives.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::async_easing::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+///     commands.spawn((transform, AsyncTransformEasing::new(transform)));
+/// }
+///
+/// # #[derive(Component)]
+/// # struct TaskResult(Option<Transform>);
+/// #
+/// fn receive_results(mut query: Query<(&mut AsyncTransformEasing, &mut TaskResult)>) {
+///     for (mut easing, mut result) in &mut query {
+///         if let Some(target) = result.0.take() {
+///             // The next result is expected to arrive in roughly 1/30th of a second.
+///             easing.push_target(target, 1.0 / 30.0);
+///         }
+///     }
+/// }
+/// ```
+///
+/// Note that the [`Transform`] of entities with [`AsyncTransformEasing`] is written every frame,
+/// so it should not be modified directly. Use [`AsyncTransformEasing::teleport`] instead.
+#[derive(Debug)]
+pub struct AsyncTransformEasingPlugin {
+    /// The schedules that the async easing systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for AsyncTransformEasingPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl AsyncTransformEasingPlugin {
+    /// Sets the schedules that the async easing systems run in.
+    ///
+    /// Targets are eased once per frame in the `fixed_main_loop` schedule.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for AsyncTransformEasingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AsyncTransformEasing>();
+        app.init_resource::<EasingPaused>();
+
+        // Ease at the same point in the frame as the other easing backends.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            ease_async_transforms
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformEasingSet::UpdateEasingTick)
+                .run_if(resource_equals(EasingPaused(false))),
+        );
+    }
+}
+
+/// Eases the [`Transform`] of an entity towards targets that arrive asynchronously with uncertain timing.
+///
+/// See the [`AsyncTransformEasingPlugin`] for more information.
+///
+/// # Example
+///
+/// Even when targets arrive with a lot of jitter, the eased translation never jumps:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::async_easing::AsyncTransformEasing;
+///
+/// let mut easing = AsyncTransformEasing::new(Transform::IDENTITY);
+///
+/// // Targets move by 0.1 units every 0.1 seconds on average, but arrive with jitter.
+/// let intervals = [0.07, 0.13, 0.1, 0.12, 0.08, 0.1, 0.13, 0.07];
+/// let mut arrivals = intervals.iter().scan(0.0, |time, interval| {
+///     *time += interval;
+///     Some(*time)
+/// });
+///
+/// let delta_secs = 1.0 / 60.0;
+/// let mut time = 0.0;
+/// let mut next_arrival = arrivals.next();
+/// let mut target_count = 0;
+/// let mut previous = easing.current.translation;
+///
+/// for _ in 0..60 {
+///     time += delta_secs;
+///
+///     if next_arrival.is_some_and(|arrival| time >= arrival) {
+///         target_count += 1;
+///         let target = Transform::from_xyz(target_count as f32 * 0.1, 0.0, 0.0);
+///         easing.push_target(target, 0.1);
+///         next_arrival = arrivals.next();
+///     }
+///
+///     let current = easing.advance(delta_secs).translation;
+///
+///     // Without easing, the translation would jump by 0.1 units whenever a target arrives.
+///     assert!(current.distance(previous) < 0.05);
+///     previous = current;
+/// }
+///
+/// // Once targets stop arriving, the translation holds at the last target.
+/// let last_target = Vec3::X * target_count as f32 * 0.1;
+/// assert!(easing.current.translation.distance(last_target) < 1e-6);
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct AsyncTransformEasing {
+    /// The [`Transform`] that easing started from when the latest target arrived.
+    pub start: Transform,
+    /// The latest target [`Transform`].
+    pub end: Transform,
+    /// The current eased [`Transform`].
+    pub current: Transform,
+    /// The time elapsed since the latest target arrived, in seconds.
+    pub elapsed_secs: f32,
+    /// The expected time between the latest target and the next one, in seconds.
+    pub duration_secs: f32,
+}
+
+impl Default for AsyncTransformEasing {
+    fn default() -> Self {
+        Self::new(Transform::IDENTITY)
+    }
+}
+
+impl AsyncTransformEasing {
+    /// Creates a new [`AsyncTransformEasing`] that is at rest at the given [`Transform`].
+    pub const fn new(transform: Transform) -> Self {
+        Self {
+            start: transform,
+            end: transform,
+            current: transform,
+            elapsed_secs: 0.0,
+            duration_secs: 0.0,
+        }
+    }
+
+    /// Pushes a new target [`Transform`] that just arrived, along with the expected time
+    /// in seconds until the next target arrives.
+    ///
+    /// Easing restarts from the current eased [`Transform`], so early targets do not cause a visible jump.
+    pub fn push_target(&mut self, target: Transform, expected_duration_secs: f32) {
+        self.start = self.current;
+        self.end = target;
+        self.elapsed_secs = 0.0;
+        self.duration_secs = expected_duration_secs.max(0.0);
+    }
+
+    /// Immediately moves to the given [`Transform`] without easing.
+    pub fn teleport(&mut self, transform: Transform) {
+        *self = Self::new(transform);
+    }
+
+    /// Returns the current easing alpha in the range `[0.0, 1.0]`.
+    ///
+    /// The alpha stays at `1.0` while waiting for a late target.
+    pub fn alpha(&self) -> f32 {
+        if self.duration_secs <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed_secs / self.duration_secs).min(1.0)
+        }
+    }
+
+    /// Advances the easing by `delta_secs` and returns the new eased [`Transform`].
+    pub fn advance(&mut self, delta_secs: f32) -> Transform {
+        self.elapsed_secs += delta_secs;
+        let alpha = self.alpha();
+
+        self.current = Transform {
+            translation: self.start.translation.lerp(self.end.translation, alpha),
+            rotation: self.start.rotation.slerp(self.end.rotation, alpha),
+            scale: self.start.scale.lerp(self.end.scale, alpha),
+        };
+        self.current
+    }
+}
+
+/// Eases the transforms of entities with [`AsyncTransformEasing`] towards their latest targets.
+fn ease_async_transforms(
+    mut query: Query<(&mut Transform, &mut AsyncTransformEasing)>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for_each_mut(&mut query, |(mut transform, mut easing)| {
+        // Only write when the value changes to avoid triggering change detection.
+        transform.set_if_neq(easing.advance(delta_secs));
+    });
+}
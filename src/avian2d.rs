@@ -0,0 +1,100 @@
+//! Ready-made velocity sources and plugins for the 2D version of the [Avian](https://github.com/Jondolf/avian) physics engine.
+//!
+//! Requires the `avian2d` feature. The `avian2d` and `avian3d` features can be enabled at the same time,
+//! and the 3D integration is in the [`avian3d`](crate::avian3d) module.
+//!
+//! See the [`AvianInterpolationPlugin`] for more information.
+
+use ::avian2d::prelude::{AngularVelocity, LinearVelocity};
+use bevy::prelude::*;
+
+use crate::{
+    extrapolation::TransformExtrapolationPlugin,
+    hermite::TransformHermiteEasingPlugin,
+    interpolation::TransformInterpolationPlugin,
+    velocity::{AngVel2dSource, LinVel2dSource},
+};
+
+/// A [`VelocitySource`](crate::VelocitySource) for Avian's 2D [`LinearVelocity`] component.
+///
+/// Avian does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+pub type AvianLinVelSource = LinVel2dSource<LinearVelocity>;
+
+/// A [`VelocitySource`](crate::VelocitySource) for Avian's 2D [`AngularVelocity`] component.
+///
+/// Avian does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+pub type AvianAngVelSource = AngVel2dSource<AngularVelocity>;
+
+/// A plugin that sets up transform interpolation, extrapolation, and Hermite interpolation
+/// using the velocity components of Avian 2D.
+///
+/// This adds the following plugins if they have not been added yet:
+///
+/// - [`TransformInterpolationPlugin`]
+/// - [`TransformExtrapolationPlugin`] with [`AvianLinVelSource`] and [`AvianAngVelSource`]
+/// - [`TransformHermiteEasingPlugin`] with [`AvianLinVelSource`] and [`AvianAngVelSource`]
+///
+/// Easing can then be enabled for individual rigid bodies with components such as
+/// [`TransformInterpolation`](crate::interpolation::TransformInterpolation),
+/// [`TransformExtrapolation`](crate::extrapolation::TransformExtrapolation),
+/// and [`TransformHermiteEasing`](crate::hermite::TransformHermiteEasing).
+///
+/// Requires the `avian2d` feature.
+///
+/// # Usage
+///
+/// ```ignore
+/// use avian2d::prelude::*;
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{avian2d::AvianInterpolationPlugin, prelude::*};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default(),
+///             AvianInterpolationPlugin,
+///         ))
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Interpolate the rigid body with Hermite interpolation.
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::circle(0.5),
+///         LinearVelocity(Vec2::X),
+///         TransformInterpolation,
+///         TransformHermiteEasing,
+///     ));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct AvianInterpolationPlugin;
+
+impl Plugin for AvianInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TransformInterpolationPlugin>() {
+            app.add_plugins(TransformInterpolationPlugin::default());
+        }
+        if !app
+            .is_plugin_added::<TransformExtrapolationPlugin<AvianLinVelSource, AvianAngVelSource>>()
+        {
+            app.add_plugins(TransformExtrapolationPlugin::<
+                AvianLinVelSource,
+                AvianAngVelSource,
+            >::default());
+        }
+        if !app
+            .is_plugin_added::<TransformHermiteEasingPlugin<AvianLinVelSource, AvianAngVelSource>>()
+        {
+            app.add_plugins(TransformHermiteEasingPlugin::<
+                AvianLinVelSource,
+                AvianAngVelSource,
+            >::default());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Ready-made velocity sources and plugins for the 3D version of the [Avian](https://github.com/Jondolf/avian) physics engine.
+//!
+//! Requires the `avian3d` feature. The `avian2d` and `avian3d` features can be enabled at the same time,
+//! and the 2D integration is in the [`avian2d`](crate::avian2d) module.
+//!
+//! See the [`AvianInterpolationPlugin`] for more information.
+
+use ::avian3d::prelude::{AngularVelocity, LinearVelocity};
+use bevy::{ecs::query::QueryData, prelude::*};
+
+use crate::{
+    extrapolation::TransformExtrapolationPlugin, hermite::TransformHermiteEasingPlugin,
+    interpolation::TransformInterpolationPlugin, VelocitySource,
+};
+
+/// A [`VelocitySource`] for Avian's 3D [`LinearVelocity`] component.
+///
+/// Avian does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+#[derive(QueryData)]
+pub struct AvianLinVelSource;
+
+impl VelocitySource for AvianLinVelSource {
+    type Previous = LinearVelocity;
+    type Current = LinearVelocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.0
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.0
+    }
+}
+
+/// A [`VelocitySource`] for Avian's 3D [`AngularVelocity`] component.
+///
+/// Avian does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+#[derive(QueryData)]
+pub struct AvianAngVelSource;
+
+impl VelocitySource for AvianAngVelSource {
+    type Previous = AngularVelocity;
+    type Current = AngularVelocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.0
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.0
+    }
+}
+
+/// A plugin that sets up transform interpolation, extrapolation, and Hermite interpolation
+/// using the velocity components of Avian 3D.
+///
+/// This adds the following plugins if they have not been added yet:
+///
+/// - [`TransformInterpolationPlugin`]
+/// - [`TransformExtrapolationPlugin`] with [`AvianLinVelSource`] and [`AvianAngVelSource`]
+/// - [`TransformHermiteEasingPlugin`] with [`AvianLinVelSource`] and [`AvianAngVelSource`]
+///
+/// Easing can then be enabled for individual rigid bodies with components such as
+/// [`TransformInterpolation`](crate::interpolation::TransformInterpolation),
+/// [`TransformExtrapolation`](crate::extrapolation::TransformExtrapolation),
+/// and [`TransformHermiteEasing`](crate::hermite::TransformHermiteEasing).
+///
+/// Requires the `avian3d` feature.
+///
+/// # Usage
+///
+/// ```ignore
+/// use avian3d::prelude::*;
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{avian3d::AvianInterpolationPlugin, prelude::*};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default(),
+///             AvianInterpolationPlugin,
+///         ))
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Interpolate the rigid body with Hermite interpolation.
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::sphere(0.5),
+///         LinearVelocity(Vec3::X),
+///         TransformInterpolation,
+///         TransformHermiteEasing,
+///     ));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct AvianInterpolationPlugin;
+
+impl Plugin for AvianInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TransformInterpolationPlugin>() {
+            app.add_plugins(TransformInterpolationPlugin::default());
+        }
+        if !app
+            .is_plugin_added::<TransformExtrapolationPlugin<AvianLinVelSource, AvianAngVelSource>>()
+        {
+            app.add_plugins(TransformExtrapolationPlugin::<
+                AvianLinVelSource,
+                AvianAngVelSource,
+            >::default());
+        }
+        if !app
+            .is_plugin_added::<TransformHermiteEasingPlugin<AvianLinVelSource, AvianAngVelSource>>()
+        {
+            app.add_plugins(TransformHermiteEasingPlugin::<
+                AvianLinVelSource,
+                AvianAngVelSource,
+            >::default());
+        }
+    }
+}
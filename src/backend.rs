@@ -0,0 +1,317 @@
+//! A trait-based API for implementing custom easing backends.
+//!
+//! See the [`EasingBackend`] trait for more information.
+
+use bevy::{
+    ecs::query::{QueryItem, ReadOnlyQueryData},
+    prelude::*,
+};
+
+use crate::{
+    for_each_mut, target::CustomEaseTarget, EasingCulled, EasingFlags, EasingOverstep,
+    EasingSchedules, EasingTimeScale, NoRotationEasing, NoScaleEasing, NoTranslationEasing,
+    NonlinearRotationEasing, NonlinearScaleEasing, NonlinearTranslationEasing, OverstepClamp,
+    RotationEasingState, ScaleEasingState, SkipEasingOnce, TransformEasingSet,
+    TranslationEasingMask, TranslationEasingState,
+};
+
+/// The context passed to the easing functions of an [`EasingBackend`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EasingContext {
+    /// The interpolation alpha, in the range specified by the [`OverstepClamp`](crate::OverstepClamp).
+    pub alpha: f32,
+    /// The duration of a fixed timestep in seconds.
+    pub delta_secs: f32,
+}
+
+/// A custom easing backend that replaces the default `lerp` and `slerp` easing
+/// for entities with its marker components.
+///
+/// Register the backend with [`register_easing_backend`]. This adds easing systems to [`TransformEasingSet::Ease`]
+/// and marks entities with the backend's marker components as having nonlinear easing, disabling the default easing.
+///
+/// Each property has its own marker component and query data, so that backends can for example require
+/// velocity only for the properties that they ease. Properties that the backend does not ease can use
+/// `()` as the query data and [`DummyComponent`](crate::DummyComponent) as the marker, which no entity has.
+///
+/// The [`TransformHermiteEasingPlugin`](crate::hermite::TransformHermiteEasingPlugin) is implemented as an easing backend.
+///
+/// # Example
+///
+/// ```
+/// use bevy::{ecs::query::QueryItem, prelude::*};
+/// use bevy_transform_interpolation::{backend::*, DummyComponent, EasingSchedules};
+///
+/// /// Snaps translation to the `end` halfway through the timestep.
+/// struct SnapEasing;
+///
+/// #[derive(Component)]
+/// struct SnapTranslation;
+///
+/// impl EasingBackend for SnapEasing {
+///     type TranslationMarker = SnapTranslation;
+///     type RotationMarker = DummyComponent;
+///     type ScaleMarker = DummyComponent;
+///     type TranslationData = ();
+///     type RotationData = ();
+///     type ScaleData = ();
+///
+///     fn ease_translation(start: Vec3, end: Vec3, _: &QueryItem<()>, context: &EasingContext) -> Vec3 {
+///         if context.alpha < 0.5 { start } else { end }
+///     }
+/// }
+///
+/// fn main() {
+///     let mut app = App::new();
+///     register_easing_backend::<SnapEasing>(&mut app, EasingSchedules::DEFAULT);
+/// }
+/// ```
+pub trait EasingBackend: Send + Sync + 'static {
+    /// The marker component for entities whose translation is eased with this backend.
+    type TranslationMarker: Component;
+    /// The marker component for entities whose rotation is eased with this backend.
+    type RotationMarker: Component;
+    /// The marker component for entities whose scale is eased with this backend.
+    type ScaleMarker: Component;
+
+    /// Additional data queried for easing translation, such as velocity.
+    type TranslationData: ReadOnlyQueryData;
+    /// Additional data queried for easing rotation, such as angular velocity.
+    type RotationData: ReadOnlyQueryData;
+    /// Additional data queried for easing scale.
+    type ScaleData: ReadOnlyQueryData;
+
+    /// Eases the translation from `start` to `end`.
+    ///
+    /// Default: Linear interpolation.
+    fn ease_translation(
+        start: Vec3,
+        end: Vec3,
+        data: &QueryItem<Self::TranslationData>,
+        context: &EasingContext,
+    ) -> Vec3 {
+        let _ = data;
+        start.lerp(end, context.alpha)
+    }
+
+    /// Eases the rotation from `start` to `end`.
+    ///
+    /// Default: Spherical linear interpolation.
+    fn ease_rotation(
+        start: Quat,
+        end: Quat,
+        data: &QueryItem<Self::RotationData>,
+        context: &EasingContext,
+    ) -> Quat {
+        let _ = data;
+        start.slerp(end, context.alpha)
+    }
+
+    /// Eases the scale from `start` to `end`.
+    ///
+    /// Default: Linear interpolation.
+    fn ease_scale(
+        start: Vec3,
+        end: Vec3,
+        data: &QueryItem<Self::ScaleData>,
+        context: &EasingContext,
+    ) -> Vec3 {
+        let _ = data;
+        start.lerp(end, context.alpha)
+    }
+}
+
+/// Registers an [`EasingBackend`], adding its easing systems to [`TransformEasingSet::Ease`]
+/// and disabling the default easing for entities with its marker components.
+///
+/// The easing systems are added to the [`EasingSchedules::fixed_main_loop`] schedule.
+/// The schedules should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+pub fn register_easing_backend<B: EasingBackend>(app: &mut App, schedules: EasingSchedules) {
+    let _ =
+        app.try_register_required_components::<B::TranslationMarker, NonlinearTranslationEasing>();
+    let _ = app.try_register_required_components::<B::RotationMarker, NonlinearRotationEasing>();
+    let _ = app.try_register_required_components::<B::ScaleMarker, NonlinearScaleEasing>();
+
+    app.add_systems(
+        schedules.fixed_main_loop(),
+        (
+            ease_translation_backend::<B>,
+            ease_rotation_backend::<B>,
+            ease_scale_backend::<B>,
+        )
+            .in_set(TransformEasingSet::Ease),
+    );
+}
+
+/// Applies the [`EasingTimeScale`] of an entity to the interpolation alpha of the context.
+fn scaled_context(
+    context: EasingContext,
+    time_scale: Option<&EasingTimeScale>,
+    overstep_clamp: &OverstepClamp,
+) -> EasingContext {
+    match time_scale {
+        Some(time_scale) => EasingContext {
+            alpha: time_scale.apply(context.alpha, overstep_clamp),
+            ..context
+        },
+        None => context,
+    }
+}
+
+/// Eases the translations of entities with the given [`EasingBackend`].
+fn ease_translation_backend<B: EasingBackend>(
+    mut query: Query<
+        (
+            &mut Transform,
+            &TranslationEasingState,
+            B::TranslationData,
+            Option<&TranslationEasingMask>,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            With<B::TranslationMarker>,
+            Without<NoTranslationEasing>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    // Without a fixed timestep, there is nothing to ease.
+    let Some(time) = time else {
+        return;
+    };
+
+    let context = EasingContext {
+        alpha: overstep.0,
+        delta_secs: time.delta_secs(),
+    };
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, interpolation, data, mask, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.translation) {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                let context = scaled_context(context, time_scale, &overstep_clamp);
+                let eased = B::ease_translation(start, end, &data, &context);
+                let translation = match mask {
+                    Some(mask) => Vec3::select(mask.0, eased, transform.translation),
+                    None => eased,
+                };
+
+                // Only write when the value changes to avoid triggering change detection.
+                if transform.translation != translation {
+                    transform.translation = translation;
+                }
+            }
+        },
+    );
+}
+
+/// Eases the rotations of entities with the given [`EasingBackend`].
+fn ease_rotation_backend<B: EasingBackend>(
+    mut query: Query<
+        (
+            &mut Transform,
+            &RotationEasingState,
+            B::RotationData,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            With<B::RotationMarker>,
+            Without<NoRotationEasing>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    // Without a fixed timestep, there is nothing to ease.
+    let Some(time) = time else {
+        return;
+    };
+
+    let context = EasingContext {
+        alpha: overstep.0,
+        delta_secs: time.delta_secs(),
+    };
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, interpolation, data, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.rotation) {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                let context = scaled_context(context, time_scale, &overstep_clamp);
+                let rotation = B::ease_rotation(start, end, &data, &context);
+
+                // Only write when the value changes to avoid triggering change detection.
+                if transform.rotation != rotation {
+                    transform.rotation = rotation;
+                }
+            }
+        },
+    );
+}
+
+/// Eases the scales of entities with the given [`EasingBackend`].
+fn ease_scale_backend<B: EasingBackend>(
+    mut query: Query<
+        (
+            &mut Transform,
+            &ScaleEasingState,
+            B::ScaleData,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            With<B::ScaleMarker>,
+            Without<NoScaleEasing>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    // Without a fixed timestep, there is nothing to ease.
+    let Some(time) = time else {
+        return;
+    };
+
+    let context = EasingContext {
+        alpha: overstep.0,
+        delta_secs: time.delta_secs(),
+    };
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, interpolation, data, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.scale) {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                let context = scaled_context(context, time_scale, &overstep_clamp);
+                let scale = B::ease_scale(start, end, &data, &context);
+
+                // Only write when the value changes to avoid triggering change detection.
+                if transform.scale != scale {
+                    transform.scale = scale;
+                }
+            }
+        },
+    );
+}
@@ -0,0 +1,308 @@
+//! Columnar interpolation for very large numbers of entities, such as particles,
+//! with easing states stored in a single resource instead of per-entity components.
+//!
+//! See the [`BulkEasingPlugin`] for more information.
+
+use crate::{
+    EasingAutoDisabled, EasingOverstep, EasingPaused, EasingSchedules, TransformEasingPlugin,
+    TransformEasingSet,
+};
+use bevy::{
+    ecs::{entity::EntityHashMap, schedule::ScheduleLabel},
+    prelude::*,
+};
+
+/// A plugin for interpolating the [`Transform`] of many entities at once, with the easing states
+/// stored in the [`BulkEasing`] resource.
+///
+/// The per-entity easing components, such as [`TranslationEasingState`] and [`RotationEasingState`],
+/// are convenient and flexible, but for hundreds of thousands of entities that are all simulated
+/// the same way, the per-entity storage and bookkeeping can become significant. [`BulkEasing`] instead
+/// stores the `start` and `end` of each property in contiguous buffers, and all entities are eased
+/// by a single system.
+///
+/// The trade-off is that bulk easing only supports translation and rotation, eased with `lerp` and `slerp`,
+/// and none of the per-entity configuration like [`TransformEasingCurve`] or [`EasingFlags`].
+/// The per-entity API remains the recommended approach for normal use.
+///
+/// [`TranslationEasingState`]: crate::TranslationEasingState
+/// [`RotationEasingState`]: crate::RotationEasingState
+/// [`TransformEasingCurve`]: crate::TransformEasingCurve
+/// [`EasingFlags`]: crate::EasingFlags
+///
+/// # Usage
+///
+/// Entities are added to the [`BulkEasing`] resource with [`BulkEasing::teleport`], and their new positions
+/// are reported in the fixed timestep with [`BulkEasing::set_end`]. The rendered [`Transform`] is eased
+/// from the position at the start of the fixed timestep to the reported `end`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{bulk::*, prelude::*};
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(BulkEasingPlugin::default());
+///
+///     app.add_systems(Startup, spawn_particles);
+///     app.add_systems(FixedUpdate, simulate_particles);
+///
+///     // ...
+/// }
+///
+/// #[derive(Resource, Default)]
+/// struct Particles(Vec<(Entity, Vec3, Vec3)>);
+///
+/// fn spawn_particles(mut commands: Commands, mut bulk: ResMut<BulkEasing>) {
+///     let mut particles = Particles::default();
+///
+///     for i in 0..1000 {
+///         let position = Vec3::new(i as f32, 0.0, 0.0);
+///         let entity = commands.spawn(Transform::from_translation(position)).id();
+///         bulk.teleport(entity, position, Quat::IDENTITY);
+///         particles.0.push((entity, position, Vec3::Y));
+///     }
+///
+///     commands.insert_resource(particles);
+/// }
+///
+/// fn simulate_particles(
+///     mut particles: ResMut<Particles>,
+///     mut bulk: ResMut<BulkEasing>,
+///     time: Res<Time>,
+/// ) {
+///     for (entity, position, velocity) in &mut particles.0 {
+///         *position += *velocity * time.delta_secs();
+///         bulk.set_end(*entity, *position, Quat::IDENTITY);
+///     }
+/// }
+/// ```
+///
+/// The [`Transform`] of bulk eased entities is written by easing every frame, so it does not hold
+/// the non-eased value during the fixed timestep. The simulation should operate on its own data,
+/// like in the example above, and only report the results to [`BulkEasing`].
+///
+/// Entities are not removed from [`BulkEasing`] automatically when they are despawned.
+/// Use [`BulkEasing::remove`] for despawned entities, as missing entities are skipped.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+#[derive(Debug)]
+pub struct BulkEasingPlugin {
+    /// The schedules that the bulk easing systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for BulkEasingPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl BulkEasingPlugin {
+    /// Sets the schedules that the bulk easing systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for BulkEasingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BulkEasing>();
+
+        // Start the next easing from the end of the previous one at the start of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            complete_bulk_easing.before(TransformEasingSet::Reset),
+        );
+
+        // Ease all entities in the buffer after the per-entity easing.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            ease_bulk
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .after(TransformEasingSet::Ease)
+                .before(TransformEasingSet::UpdateEasingTick)
+                .run_if(resource_equals(EasingPaused(false)))
+                .run_if(resource_equals(EasingAutoDisabled(false))),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// A resource storing the translation and rotation easing states of many entities in contiguous buffers.
+///
+/// The buffers are indexed by the position of each entity in [`BulkEasing::entities`].
+/// Removing an entity moves the last entity into its place, so the order is not stable.
+///
+/// See the [`BulkEasingPlugin`] for more information.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct BulkEasing {
+    entities: Vec<Entity>,
+    indices: EntityHashMap<usize>,
+    start_translations: Vec<Vec3>,
+    end_translations: Vec<Vec3>,
+    start_rotations: Vec<Quat>,
+    end_rotations: Vec<Quat>,
+}
+
+impl BulkEasing {
+    /// Returns the number of entities in the buffer.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if the buffer contains no entities.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Returns `true` if the buffer contains the given entity.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.indices.contains_key(&entity)
+    }
+
+    /// Returns the entities in the buffer, in the order of the easing state buffers.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Returns the `start` and `end` translations, in the same order as [`BulkEasing::entities`].
+    pub fn translations(&self) -> (&[Vec3], &[Vec3]) {
+        (&self.start_translations, &self.end_translations)
+    }
+
+    /// Returns the `start` and `end` rotations, in the same order as [`BulkEasing::entities`].
+    pub fn rotations(&self) -> (&[Quat], &[Quat]) {
+        (&self.start_rotations, &self.end_rotations)
+    }
+
+    /// Sets the `end` of the easing for the given entity, typically during the fixed timestep.
+    ///
+    /// The entity is eased from where it was at the start of the fixed timestep to this state.
+    /// If the entity is not in the buffer yet, it is added without easing, like with [`BulkEasing::teleport`].
+    pub fn set_end(&mut self, entity: Entity, translation: Vec3, rotation: Quat) {
+        match self.indices.get(&entity) {
+            Some(&index) => {
+                self.end_translations[index] = translation;
+                self.end_rotations[index] = rotation;
+            }
+            None => self.teleport(entity, translation, rotation),
+        }
+    }
+
+    /// Sets both the `start` and `end` of the easing for the given entity, moving it instantly without easing.
+    ///
+    /// If the entity is not in the buffer yet, it is added.
+    pub fn teleport(&mut self, entity: Entity, translation: Vec3, rotation: Quat) {
+        if let Some(&index) = self.indices.get(&entity) {
+            self.start_translations[index] = translation;
+            self.end_translations[index] = translation;
+            self.start_rotations[index] = rotation;
+            self.end_rotations[index] = rotation;
+            return;
+        }
+
+        self.indices.insert(entity, self.entities.len());
+        self.entities.push(entity);
+        self.start_translations.push(translation);
+        self.end_translations.push(translation);
+        self.start_rotations.push(rotation);
+        self.end_rotations.push(rotation);
+    }
+
+    /// Removes the given entity from the buffer, returning `true` if it was present.
+    ///
+    /// The [`Transform`] of the entity is left at its current eased value.
+    pub fn remove(&mut self, entity: Entity) -> bool {
+        let Some(index) = self.indices.remove(&entity) else {
+            return false;
+        };
+
+        self.entities.swap_remove(index);
+        self.start_translations.swap_remove(index);
+        self.end_translations.swap_remove(index);
+        self.start_rotations.swap_remove(index);
+        self.end_rotations.swap_remove(index);
+
+        // Update the index of the entity that was moved into the removed slot.
+        if let Some(&moved) = self.entities.get(index) {
+            self.indices.insert(moved, index);
+        }
+
+        true
+    }
+
+    /// Removes all entities from the buffer.
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.indices.clear();
+        self.start_translations.clear();
+        self.end_translations.clear();
+        self.start_rotations.clear();
+        self.end_rotations.clear();
+    }
+}
+
+/// Sets the `start` of each bulk easing state to its `end` at the start of the fixed timestep,
+/// so that entities whose `end` is not updated during the timestep stay at rest.
+fn complete_bulk_easing(mut bulk: ResMut<BulkEasing>) {
+    let bulk = &mut *bulk;
+    bulk.start_translations
+        .copy_from_slice(&bulk.end_translations);
+    bulk.start_rotations.copy_from_slice(&bulk.end_rotations);
+}
+
+/// Eases the transforms of all entities in the [`BulkEasing`] buffer.
+///
+/// The buffers are iterated in order, so the cost only depends on the number of bulk eased entities,
+/// not on the number of other entities with a [`Transform`].
+fn ease_bulk(
+    bulk: Res<BulkEasing>,
+    mut query: Query<&mut Transform>,
+    overstep: Res<EasingOverstep>,
+) {
+    let alpha = overstep.0;
+
+    for (index, &entity) in bulk.entities.iter().enumerate() {
+        // Despawned entities are skipped until they are removed from the buffer.
+        let Ok(mut transform) = query.get_mut(entity) else {
+            continue;
+        };
+
+        let translation = bulk.start_translations[index].lerp(bulk.end_translations[index], alpha);
+        let rotation = bulk.start_rotations[index].slerp(bulk.end_rotations[index], alpha);
+
+        // Only write when the values change to avoid triggering change detection.
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! [`Command`]s for controlling transform easing.
+
+use bevy::{ecs::world::Command, prelude::*};
+
+use crate::{
+    extrapolation::{RotationExtrapolation, TransformExtrapolation, TranslationExtrapolation},
+    interpolation::{
+        GlobalSpaceEasingState, GlobalTransformEasingState, RotationInterpolation,
+        ScaleInterpolation, TransformInterpolation, TranslationInterpolation,
+    },
+    EasingResetEvent, EasingResetReason, RotationEasingState, ScaleEasingState,
+    TranslationEasingState,
+};
+
+#[cfg(feature = "2d")]
+use crate::interpolation_2d::{Rotation2dEasingState, Translation2dEasingState};
+
+/// A [`Command`] that resets the easing states of an entity to `None`,
+/// disabling easing for the entity for the remainder of the current fixed timestep.
+///
+/// This does *not* modify the [`Transform`] of the entity. To also move the entity without easing,
+/// use [`TeleportAndReset`] instead.
+///
+/// See also [`EasingCommandsExt::reset_easing`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResetEasing(pub Entity);
+
+impl Command for ResetEasing {
+    fn apply(self, world: &mut World) {
+        reset_easing_states(world, self.0);
+    }
+}
+
+/// A [`Command`] that resets the easing states of *all* entities to `None`,
+/// disabling easing for every entity for the remainder of the current fixed timestep.
+///
+/// This can be useful when entering a new level or moving the whole scene at once.
+/// Like [`ResetEasing`], this does *not* modify the [`Transform`] of any entity.
+///
+/// To reset easing from a system instead, for example on a state transition,
+/// the [`reset_all_easing`] exclusive system can be used.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::commands::{reset_all_easing, ResetAllEasing};
+///
+/// #[derive(Resource, Default)]
+/// struct CurrentLevel(u32);
+///
+/// fn load_next_level(mut commands: Commands) {
+///     // ...
+///     commands.queue(ResetAllEasing);
+/// }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     // Reset all easing whenever the level changes.
+///     app.init_resource::<CurrentLevel>().add_systems(
+///         Update,
+///         reset_all_easing.run_if(resource_changed::<CurrentLevel>),
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResetAllEasing;
+
+impl Command for ResetAllEasing {
+    fn apply(self, world: &mut World) {
+        reset_all_easing(world);
+    }
+}
+
+/// A [`Command`] that sets the [`Transform`] of an entity and resets its easing states to `None`,
+/// teleporting the entity without any easing.
+///
+/// Unlike setting the [`Transform`] and issuing [`ResetEasing`] separately, this is applied
+/// in a single [`World`] access, so the two can not be ordered incorrectly.
+///
+/// See also [`EasingCommandsExt::teleport_no_ease`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TeleportAndReset {
+    /// The entity to teleport.
+    pub entity: Entity,
+    /// The new [`Transform`] of the entity.
+    pub transform: Transform,
+}
+
+impl Command for TeleportAndReset {
+    fn apply(self, world: &mut World) {
+        if let Some(mut transform) = world.get_mut::<Transform>(self.entity) {
+            *transform = self.transform;
+        }
+        reset_easing_states(world, self.entity);
+    }
+}
+
+/// A [`Command`] that sets the `start` and `end` of the translation, rotation, and scale easing states of an entity,
+/// easing it from `start` to `end` over the current fixed timestep.
+///
+/// This does *not* modify the [`Transform`] of the entity. It can be used for example to smoothly
+/// "catch up" to the current transform after an instantaneous warp, or for fine-grained control
+/// in cutscene or camera systems. Only easing states that the entity already has are modified.
+///
+/// Note that easing backends such as interpolation update the states at the start and end of each fixed timestep.
+/// To avoid the values being overwritten, the command should be applied after [`TransformEasingSet::UpdateEnd`]
+/// in [`FixedLast`], or outside of the fixed timestep schedules.
+///
+/// See also [`EasingCommandsExt::set_easing`].
+///
+/// [`TransformEasingSet::UpdateEnd`]: crate::TransformEasingSet::UpdateEnd
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SetEasing {
+    /// The entity whose easing is set.
+    pub entity: Entity,
+    /// The [`Transform`] that easing starts from.
+    pub start: Transform,
+    /// The [`Transform`] that easing ends at.
+    pub end: Transform,
+}
+
+impl Command for SetEasing {
+    fn apply(self, world: &mut World) {
+        if let Some(mut easing) = world.get_mut::<TranslationEasingState>(self.entity) {
+            easing.start = Some(self.start.translation);
+            easing.end = Some(self.end.translation);
+        }
+        if let Some(mut easing) = world.get_mut::<RotationEasingState>(self.entity) {
+            easing.start = Some(self.start.rotation);
+            easing.end = Some(self.end.rotation);
+        }
+        if let Some(mut easing) = world.get_mut::<ScaleEasingState>(self.entity) {
+            easing.start = Some(self.start.scale);
+            easing.end = Some(self.end.scale);
+        }
+    }
+}
+
+/// A [`Command`] that patches the `end` of the easing states of an entity without modifying `start`,
+/// smoothly redirecting the ongoing easing towards a corrected target.
+///
+/// This is useful for example for client-side prediction reconciliation, where the predicted position
+/// is slightly corrected, and the entity should ease towards the corrected position instead of snapping to it.
+/// Unlike [`SetEasing`], only the specified fields are modified, and unlike [`ResetEasing`], easing is not disabled.
+///
+/// Only easing states that the entity already has and that have an `end` are modified.
+/// Like [`SetEasing`], the command should be applied after [`TransformEasingSet::UpdateEnd`]
+/// in [`FixedLast`], or outside of the fixed timestep schedules, to avoid the values being overwritten.
+///
+/// See also [`EasingCommandsExt::adjust_easing_end`].
+///
+/// [`TransformEasingSet::UpdateEnd`]: crate::TransformEasingSet::UpdateEnd
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdjustEasingEnd {
+    /// The entity whose easing is adjusted.
+    pub entity: Entity,
+    /// The new `end` of the translation easing, or `None` to leave it unchanged.
+    pub translation: Option<Vec3>,
+    /// The new `end` of the rotation easing, or `None` to leave it unchanged.
+    pub rotation: Option<Quat>,
+    /// The new `end` of the scale easing, or `None` to leave it unchanged.
+    pub scale: Option<Vec3>,
+}
+
+impl Command for AdjustEasingEnd {
+    fn apply(self, world: &mut World) {
+        if let Some(translation) = self.translation {
+            if let Some(mut easing) = world.get_mut::<TranslationEasingState>(self.entity) {
+                if let Some(end) = easing.end.as_mut() {
+                    *end = translation;
+                }
+            }
+        }
+        if let Some(rotation) = self.rotation {
+            if let Some(mut easing) = world.get_mut::<RotationEasingState>(self.entity) {
+                if let Some(end) = easing.end.as_mut() {
+                    *end = rotation;
+                }
+            }
+        }
+        if let Some(scale) = self.scale {
+            if let Some(mut easing) = world.get_mut::<ScaleEasingState>(self.entity) {
+                if let Some(end) = easing.end.as_mut() {
+                    *end = scale;
+                }
+            }
+        }
+    }
+}
+
+/// The easing method used for an entity, set with [`SetEasingMode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EasingMode {
+    /// Interpolation with [`TransformInterpolation`].
+    Interpolation,
+    /// Extrapolation with [`TransformExtrapolation`].
+    ///
+    /// The [`TransformExtrapolationPlugin`](crate::extrapolation::TransformExtrapolationPlugin)
+    /// must be added to the app with the appropriate velocity sources.
+    Extrapolation,
+}
+
+/// A [`Command`] that switches an entity between interpolation and extrapolation at runtime,
+/// for example to extrapolate fast-moving objects and interpolate slow ones.
+///
+/// The marker components of the previous easing method are removed, and the marker components
+/// of the new method are inserted. Removing the markers restores the [`Transform`] from the last fixed timestep,
+/// and the easing states are reset to `None`, so no stale `start` or `end` is used by the new method.
+/// Easing resumes at the next fixed timestep.
+///
+/// See also [`EasingCommandsExt::set_easing_mode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SetEasingMode {
+    /// The entity whose easing method is set.
+    pub entity: Entity,
+    /// The new easing method.
+    pub mode: EasingMode,
+}
+
+impl Command for SetEasingMode {
+    fn apply(self, world: &mut World) {
+        let Ok(mut entity) = world.get_entity_mut(self.entity) else {
+            return;
+        };
+
+        match self.mode {
+            EasingMode::Interpolation => {
+                entity.remove::<(
+                    TransformExtrapolation,
+                    TranslationExtrapolation,
+                    RotationExtrapolation,
+                )>();
+                entity.insert(TransformInterpolation);
+            }
+            EasingMode::Extrapolation => {
+                entity.remove::<(
+                    TransformInterpolation,
+                    TranslationInterpolation,
+                    RotationInterpolation,
+                    ScaleInterpolation,
+                )>();
+                entity.insert(TransformExtrapolation);
+            }
+        }
+
+        reset_easing_states(world, self.entity);
+    }
+}
+
+/// An extension trait for [`EntityCommands`] with easing-related commands.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{commands::EasingCommandsExt, prelude::*};
+///
+/// fn respawn_player(mut commands: Commands, player: Single<Entity, With<TransformInterpolation>>) {
+///     // Move the player back to the origin without easing.
+///     commands.entity(*player).teleport_no_ease(Transform::IDENTITY);
+/// }
+/// ```
+pub trait EasingCommandsExt {
+    /// Resets the easing states of the entity to `None`, disabling easing for the entity
+    /// for the remainder of the current fixed timestep.
+    ///
+    /// See [`ResetEasing`] for more information.
+    fn reset_easing(&mut self) -> &mut Self;
+
+    /// Sets the [`Transform`] of the entity and resets its easing states to `None`,
+    /// teleporting the entity without any easing.
+    ///
+    /// See [`TeleportAndReset`] for more information.
+    fn teleport_no_ease(&mut self, transform: Transform) -> &mut Self;
+
+    /// Sets the `start` and `end` of the easing states of the entity,
+    /// easing it from `start` to `end` over the current fixed timestep.
+    ///
+    /// See [`SetEasing`] for more information.
+    fn set_easing(&mut self, start: Transform, end: Transform) -> &mut Self;
+
+    /// Patches the `end` of the easing states of the entity without modifying `start`.
+    /// Fields that are `None` are left unchanged.
+    ///
+    /// See [`AdjustEasingEnd`] for more information.
+    fn adjust_easing_end(
+        &mut self,
+        translation: Option<Vec3>,
+        rotation: Option<Quat>,
+        scale: Option<Vec3>,
+    ) -> &mut Self;
+
+    /// Switches the entity between interpolation and extrapolation,
+    /// resetting its easing states.
+    ///
+    /// See [`SetEasingMode`] for more information.
+    fn set_easing_mode(&mut self, mode: EasingMode) -> &mut Self;
+}
+
+impl EasingCommandsExt for EntityCommands<'_> {
+    fn reset_easing(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.commands().queue(ResetEasing(entity));
+        self
+    }
+
+    fn teleport_no_ease(&mut self, transform: Transform) -> &mut Self {
+        let entity = self.id();
+        self.commands()
+            .queue(TeleportAndReset { entity, transform });
+        self
+    }
+
+    fn set_easing(&mut self, start: Transform, end: Transform) -> &mut Self {
+        let entity = self.id();
+        self.commands().queue(SetEasing { entity, start, end });
+        self
+    }
+
+    fn adjust_easing_end(
+        &mut self,
+        translation: Option<Vec3>,
+        rotation: Option<Quat>,
+        scale: Option<Vec3>,
+    ) -> &mut Self {
+        let entity = self.id();
+        self.commands().queue(AdjustEasingEnd {
+            entity,
+            translation,
+            rotation,
+            scale,
+        });
+        self
+    }
+
+    fn set_easing_mode(&mut self, mode: EasingMode) -> &mut Self {
+        let entity = self.id();
+        self.commands().queue(SetEasingMode { entity, mode });
+        self
+    }
+}
+
+/// An exclusive system that resets the easing states of all entities to `None`,
+/// disabling easing for every entity for the remainder of the current fixed timestep.
+///
+/// If reset events are enabled, an [`EasingResetEvent`] is sent for each entity.
+///
+/// See [`ResetAllEasing`] for more information.
+pub fn reset_all_easing(world: &mut World) {
+    let mut entities = Vec::new();
+
+    let mut query = world.query_filtered::<Entity, Or<(
+        With<TranslationEasingState>,
+        With<RotationEasingState>,
+        With<ScaleEasingState>,
+        With<GlobalSpaceEasingState>,
+        With<GlobalTransformEasingState>,
+    )>>();
+    entities.extend(query.iter(world));
+
+    #[cfg(feature = "2d")]
+    {
+        let mut query = world.query_filtered::<Entity, Or<(
+            With<Translation2dEasingState>,
+            With<Rotation2dEasingState>,
+        )>>();
+        entities.extend(query.iter(world));
+
+        // Entities can have both 3D and 2D easing states.
+        entities.sort_unstable();
+        entities.dedup();
+    }
+
+    for &entity in &entities {
+        clear_easing_states(&mut world.entity_mut(entity));
+    }
+
+    if let Some(mut events) = world.get_resource_mut::<Events<EasingResetEvent>>() {
+        events.send_batch(entities.into_iter().map(|entity| EasingResetEvent {
+            entity,
+            reason: EasingResetReason::Command,
+        }));
+    }
+}
+
+/// Resets all easing states of the given entity to `None`.
+///
+/// If reset events are enabled and the entity had any easing state, an [`EasingResetEvent`] is also sent.
+pub(crate) fn reset_easing_states(world: &mut World, entity: Entity) {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if !clear_easing_states(&mut entity_mut) {
+        return;
+    }
+
+    if let Some(mut events) = world.get_resource_mut::<Events<EasingResetEvent>>() {
+        events.send(EasingResetEvent {
+            entity,
+            reason: EasingResetReason::Command,
+        });
+    }
+}
+
+/// Resets the 3D, 2D, and global easing states of the entity to `None`,
+/// returning `true` if the entity has any easing state.
+fn clear_easing_states(entity: &mut EntityWorldMut) -> bool {
+    let mut reset = false;
+
+    if let Some(mut easing) = entity.get_mut::<TranslationEasingState>() {
+        easing.start = None;
+        easing.end = None;
+        reset = true;
+    }
+    if let Some(mut easing) = entity.get_mut::<RotationEasingState>() {
+        easing.start = None;
+        easing.end = None;
+        reset = true;
+    }
+    if let Some(mut easing) = entity.get_mut::<ScaleEasingState>() {
+        easing.start = None;
+        easing.end = None;
+        reset = true;
+    }
+    if let Some(mut easing) = entity.get_mut::<GlobalSpaceEasingState>() {
+        easing.start = None;
+        easing.end = None;
+        reset = true;
+    }
+    if let Some(mut easing) = entity.get_mut::<GlobalTransformEasingState>() {
+        easing.start = None;
+        easing.end = None;
+        reset = true;
+    }
+
+    #[cfg(feature = "2d")]
+    {
+        if let Some(mut easing) = entity.get_mut::<Translation2dEasingState>() {
+            easing.start = None;
+            easing.end = None;
+            reset = true;
+        }
+        if let Some(mut easing) = entity.get_mut::<Rotation2dEasingState>() {
+            easing.start = None;
+            easing.end = None;
+            reset = true;
+        }
+    }
+
+    reset
+}
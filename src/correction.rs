@@ -0,0 +1,323 @@
+//! Smooth blending of authoritative corrections, such as server corrections for networked physics.
+//!
+//! See the [`CorrectionBlendPlugin`] for more information.
+
+use crate::{
+    interpolation::{RotationInterpolation, TranslationInterpolation},
+    EasingSchedules, RotationEasingState, TransformEasingPlugin, TransformEasingSet,
+    TranslationEasingState,
+};
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+/// A plugin for spreading authoritative corrections to interpolated entities over several fixed timesteps,
+/// configured with the [`CorrectionBlend`] component.
+///
+/// In networked physics, the server periodically corrects the predicted state of the client.
+/// With plain interpolation, the whole positional error is eased over a single fixed timestep,
+/// which looks like a sudden jerk when the error is large. With [`CorrectionBlend`], the error is
+/// instead stored as a visual offset that is reduced by [`CorrectionBlend::factor`] every fixed timestep,
+/// so the rendered entity converges smoothly towards the authoritative state.
+///
+/// # Usage
+///
+/// When a correction is applied to the [`Transform`] of an entity in the fixed timestep,
+/// insert the [`Corrected`] component with the predicted [`Transform`] from before the correction:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{correction::*, prelude::*};
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((
+///         TransformInterpolationPlugin::default(),
+///         CorrectionBlendPlugin::default(),
+///     ));
+///
+///     app.add_systems(FixedUpdate, apply_server_corrections);
+///
+///     // ...
+/// }
+///
+/// # #[derive(Component)]
+/// # struct ServerTransform(Option<Transform>);
+/// #
+/// fn setup(mut commands: Commands) {
+///     // Remove 20% of the remaining correction error every fixed timestep.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         CorrectionBlend::new(0.2),
+/// #       ServerTransform(None),
+///     ));
+/// }
+///
+/// fn apply_server_corrections(
+///     mut commands: Commands,
+///     mut query: Query<(Entity, &mut Transform, &mut ServerTransform)>,
+/// ) {
+///     for (entity, mut transform, mut server_transform) in &mut query {
+///         if let Some(corrected) = server_transform.0.take() {
+///             let predicted = *transform;
+///             *transform = corrected;
+///             commands.entity(entity).insert(Corrected(predicted));
+///         }
+///     }
+/// }
+/// ```
+///
+/// The [`Transform`] in the fixed timestep always stays authoritative. The visual offset is only applied
+/// to the `end` of the easing states, and removed from the [`Transform`] again at the start of the next fixed timestep.
+///
+/// Corrections are blended for the translation and rotation of entities with [`TranslationInterpolation`]
+/// and [`RotationInterpolation`]. Scale and extrapolation are not affected.
+///
+/// Because the `end` of easing intentionally differs from the [`Transform`], entities with [`CorrectionBlend`]
+/// are excluded from the automatic teleport detection for [`Transform`] changes outside of the fixed timestep.
+/// To teleport them, use a command such as [`TeleportAndReset`]. When the easing of an entity is reset,
+/// the remaining correction offset is discarded.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+///
+/// [`TeleportAndReset`]: crate::commands::TeleportAndReset
+#[derive(Debug)]
+pub struct CorrectionBlendPlugin {
+    /// The schedules that the correction systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for CorrectionBlendPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl CorrectionBlendPlugin {
+    /// Sets the schedules that the correction systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for CorrectionBlendPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<(CorrectionBlend, Corrected)>();
+
+        // Discard the offsets of entities whose easing was reset, since the offset `end` was not applied.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            discard_reset_correction_offsets.before(TransformEasingSet::Reset),
+        );
+
+        // Remove the visual offset from the transform after the start of the easing has been updated,
+        // so that the fixed timestep operates on the authoritative transform.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            remove_correction_offset.after(TransformEasingSet::UpdateStart),
+        );
+
+        // Reduce the correction error and apply the remaining offset to the end of the easing.
+        app.add_systems(
+            self.schedules.fixed_last(),
+            blend_corrections.after(TransformEasingSet::UpdateEnd),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Spreads authoritative corrections to the [`Transform`] of an interpolated entity over several fixed timesteps.
+///
+/// Corrections are reported with the [`Corrected`] component.
+///
+/// See the [`CorrectionBlendPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct CorrectionBlend {
+    /// The fraction of the remaining correction error that is removed every fixed timestep, between `0.0` and `1.0`.
+    ///
+    /// A value of `1.0` removes the whole error at once, like plain interpolation.
+    /// Smaller values spread the error over more fixed timesteps.
+    ///
+    /// Default: `0.25`
+    pub factor: f32,
+    /// The remaining translation offset between the rendered and the authoritative translation.
+    pub translation_offset: Vec3,
+    /// The remaining rotation offset between the rendered and the authoritative rotation.
+    pub rotation_offset: Quat,
+}
+
+impl Default for CorrectionBlend {
+    fn default() -> Self {
+        Self::new(0.25)
+    }
+}
+
+impl CorrectionBlend {
+    /// Creates a new [`CorrectionBlend`] with the given factor.
+    pub const fn new(factor: f32) -> Self {
+        Self {
+            factor,
+            translation_offset: Vec3::ZERO,
+            rotation_offset: Quat::IDENTITY,
+        }
+    }
+
+    /// Returns `true` if there is no remaining correction offset.
+    pub fn is_settled(&self) -> bool {
+        self.translation_offset == Vec3::ZERO && self.rotation_offset == Quat::IDENTITY
+    }
+}
+
+/// A component that reports that the [`Transform`] of an entity with [`CorrectionBlend`]
+/// was corrected during the current fixed timestep, storing the predicted [`Transform`] from before the correction.
+///
+/// The error between the predicted and the corrected [`Transform`] is added to the offset of the [`CorrectionBlend`],
+/// and the component is removed automatically at the end of the fixed timestep.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct Corrected(pub Transform);
+
+/// The offset below which the remaining correction error is discarded.
+const CORRECTION_EPSILON: f32 = 1e-4;
+
+/// Discards the correction offsets of entities whose easing was reset since the previous fixed timestep,
+/// for example by a teleport.
+///
+/// The offset is normally applied to the [`Transform`] before the fixed timestep, when the `end` of easing is restored.
+/// Without an `end`, the [`Transform`] is already authoritative, and the offset must not be removed from it.
+fn discard_reset_correction_offsets(
+    mut query: Query<(
+        &mut CorrectionBlend,
+        Option<&TranslationEasingState>,
+        Option<&RotationEasingState>,
+    )>,
+) {
+    for (mut blend, translation_easing, rotation_easing) in &mut query {
+        if blend.is_settled() {
+            continue;
+        }
+
+        if translation_easing.is_none_or(|easing| easing.end.is_none()) {
+            blend.translation_offset = Vec3::ZERO;
+        }
+        if rotation_easing.is_none_or(|easing| easing.end.is_none()) {
+            blend.rotation_offset = Quat::IDENTITY;
+        }
+    }
+}
+
+/// Removes the remaining correction offset from the [`Transform`] at the start of the fixed timestep.
+fn remove_correction_offset(
+    mut query: Query<(
+        &mut Transform,
+        &CorrectionBlend,
+        Has<TranslationInterpolation>,
+        Has<RotationInterpolation>,
+    )>,
+) {
+    for (mut transform, blend, has_translation, has_rotation) in &mut query {
+        if blend.is_settled() {
+            continue;
+        }
+
+        // The transform was set to the offset `end` of the previous easing.
+        if has_translation {
+            transform.translation -= blend.translation_offset;
+        }
+        if has_rotation {
+            transform.rotation = (blend.rotation_offset.inverse() * transform.rotation).normalize();
+        }
+    }
+}
+
+/// Accumulates new corrections into the [`CorrectionBlend`] offset, decays it, and applies it to the `end` of easing.
+fn blend_corrections(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &mut CorrectionBlend,
+        Option<&Corrected>,
+        Option<&mut TranslationEasingState>,
+        Option<&mut RotationEasingState>,
+        Has<TranslationInterpolation>,
+        Has<RotationInterpolation>,
+    )>,
+) {
+    for (
+        entity,
+        transform,
+        mut blend,
+        corrected,
+        translation_easing,
+        rotation_easing,
+        has_translation,
+        has_rotation,
+    ) in &mut query
+    {
+        if let Some(corrected) = corrected {
+            // Keep rendering from where the entity was predicted to be.
+            if has_translation {
+                blend.translation_offset += corrected.translation - transform.translation;
+            }
+            if has_rotation {
+                blend.rotation_offset =
+                    (blend.rotation_offset * corrected.rotation * transform.rotation.inverse())
+                        .normalize();
+            }
+            commands.entity(entity).remove::<Corrected>();
+        } else if blend.is_settled() {
+            continue;
+        }
+
+        // Remove a fraction of the remaining error.
+        let factor = blend.factor.clamp(0.0, 1.0);
+        blend.translation_offset *= 1.0 - factor;
+        blend.rotation_offset = blend.rotation_offset.slerp(Quat::IDENTITY, factor);
+
+        if blend.translation_offset.length() < CORRECTION_EPSILON {
+            blend.translation_offset = Vec3::ZERO;
+        }
+        if blend.rotation_offset.angle_between(Quat::IDENTITY) < CORRECTION_EPSILON {
+            blend.rotation_offset = Quat::IDENTITY;
+        }
+
+        // Ease towards the authoritative transform with the remaining offset applied.
+        if let Some(mut easing) = translation_easing.filter(|_| has_translation) {
+            if let Some(end) = easing.end.as_mut() {
+                *end += blend.translation_offset;
+            }
+        }
+        if let Some(mut easing) = rotation_easing.filter(|_| has_rotation) {
+            if let Some(end) = easing.end.as_mut() {
+                *end = (blend.rotation_offset * *end).normalize();
+            }
+        }
+    }
+}
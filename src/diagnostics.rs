@@ -0,0 +1,182 @@
+//! Diagnostics for measuring the magnitude of transform easing and extrapolation errors.
+//!
+//! See the [`TransformInterpolationDiagnosticsPlugin`] for more information.
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    ecs::{entity::EntityHashMap, schedule::ScheduleLabel},
+    prelude::*,
+};
+
+use crate::{
+    extrapolation::TranslationExtrapolation, EasingSchedules, TransformEasingSet,
+    TranslationEasingState,
+};
+
+/// A plugin that registers [`Diagnostic`]s for transform easing.
+///
+/// The following diagnostics are measured:
+///
+/// - [`EASING_DISTANCE`](Self::EASING_DISTANCE): The average distance between the `start` and `end`
+///   of translation easing across all entities, measured every frame.
+/// - [`MAX_EASING_DISTANCE`](Self::MAX_EASING_DISTANCE): The largest distance between the `start` and `end`
+///   of translation easing across all entities, measured every frame.
+/// - [`EXTRAPOLATION_ERROR`](Self::EXTRAPOLATION_ERROR): The average distance between the predicted `end`
+///   of translation extrapolation and the actual translation at the end of the next fixed timestep,
+///   measured every fixed timestep.
+///
+/// A large extrapolation error relative to the easing distance means that predictions are often wrong,
+/// and interpolation may be a better fit.
+///
+/// The diagnostics can be viewed with Bevy's diagnostics tools, such as the `LogDiagnosticsPlugin`.
+///
+/// # Usage
+///
+/// ```no_run
+/// use bevy::{diagnostic::LogDiagnosticsPlugin, prelude::*};
+/// use bevy_transform_interpolation::{diagnostics::*, prelude::*};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             TransformInterpolationPlugin::default(),
+///             TransformInterpolationDiagnosticsPlugin::default(),
+///             LogDiagnosticsPlugin::default(),
+///         ))
+///         // ...
+///         .run();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TransformInterpolationDiagnosticsPlugin {
+    /// The schedules that the diagnostics systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for TransformInterpolationDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl TransformInterpolationDiagnosticsPlugin {
+    /// The average distance between the `start` and `end` of translation easing.
+    pub const EASING_DISTANCE: DiagnosticPath =
+        DiagnosticPath::const_new("transform_interpolation/easing_distance");
+
+    /// The largest distance between the `start` and `end` of translation easing.
+    pub const MAX_EASING_DISTANCE: DiagnosticPath =
+        DiagnosticPath::const_new("transform_interpolation/max_easing_distance");
+
+    /// The average distance between the predicted `end` of translation extrapolation
+    /// and the actual translation at the end of the next fixed timestep.
+    pub const EXTRAPOLATION_ERROR: DiagnosticPath =
+        DiagnosticPath::const_new("transform_interpolation/extrapolation_error");
+
+    /// Sets the schedules that the diagnostics systems run in.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for TransformInterpolationDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::EASING_DISTANCE))
+            .register_diagnostic(Diagnostic::new(Self::MAX_EASING_DISTANCE))
+            .register_diagnostic(Diagnostic::new(Self::EXTRAPOLATION_ERROR));
+
+        app.init_resource::<PredictedTranslations>();
+
+        // Store the predicted translations before the easing states are reset.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            store_predicted_translations.before(TransformEasingSet::Reset),
+        );
+
+        // Compare the predictions against the actual translations before the new predictions are made.
+        app.add_systems(
+            self.schedules.fixed_last(),
+            measure_extrapolation_error.before(TransformEasingSet::UpdateEnd),
+        );
+
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            measure_easing_distance
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformEasingSet::UpdateEasingTick),
+        );
+    }
+}
+
+/// The predicted `end` translations of extrapolated entities from the previous fixed timestep.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct PredictedTranslations(EntityHashMap<Vec3>);
+
+fn measure_easing_distance(query: Query<&TranslationEasingState>, mut diagnostics: Diagnostics) {
+    let (total, max, count) = query
+        .iter()
+        .filter_map(|easing| Some(easing.start?.distance(easing.end?)))
+        .fold((0.0, 0.0_f32, 0), |(total, max, count), distance| {
+            (total + distance, max.max(distance), count + 1)
+        });
+
+    if count > 0 {
+        diagnostics.add_measurement(
+            &TransformInterpolationDiagnosticsPlugin::EASING_DISTANCE,
+            || total as f64 / count as f64,
+        );
+        diagnostics.add_measurement(
+            &TransformInterpolationDiagnosticsPlugin::MAX_EASING_DISTANCE,
+            || max as f64,
+        );
+    }
+}
+
+fn store_predicted_translations(
+    query: Query<(Entity, &TranslationEasingState), With<TranslationExtrapolation>>,
+    mut predicted: ResMut<PredictedTranslations>,
+) {
+    predicted.clear();
+    for (entity, easing) in &query {
+        if let (Some(_), Some(end)) = (easing.start, easing.end) {
+            predicted.insert(entity, end);
+        }
+    }
+}
+
+fn measure_extrapolation_error(
+    query: Query<(Entity, &Transform), With<TranslationExtrapolation>>,
+    mut predicted: ResMut<PredictedTranslations>,
+    mut diagnostics: Diagnostics,
+) {
+    let mut total = 0.0;
+    let mut count = 0;
+
+    for (entity, transform) in &query {
+        if let Some(prediction) = predicted.remove(&entity) {
+            total += transform.translation.distance(prediction);
+            count += 1;
+        }
+    }
+
+    if count > 0 {
+        diagnostics.add_measurement(
+            &TransformInterpolationDiagnosticsPlugin::EXTRAPOLATION_ERROR,
+            || total as f64 / count as f64,
+        );
+    }
+}
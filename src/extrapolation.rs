@@ -3,13 +3,25 @@
 //!
 //! See the [`TransformExtrapolationPlugin`] for more information.
 
-use std::marker::PhantomData;
+use core::{any::type_name, marker::PhantomData};
 
 use crate::{
-    NoRotationEasing, NoTranslationEasing, RotationEasingState, TransformEasingPlugin,
-    TransformEasingSet, TranslationEasingState, VelocitySource, VelocitySourceItem,
+    ease_rotation_slerp, for_each_mut, has_velocity_components, is_user_change,
+    reset_easing_states_on_transform_change, restore_and_reset_rotation,
+    restore_and_reset_translation, target::CustomEaseTarget, EasingCulled, EasingFlags,
+    EasingOverstep, EasingPaused, EasingSchedules, EasingTick, EasingTimeScale, FixedState,
+    KeepEasingOnChange, LastEasingTick, NoRotationEasing, NoTranslationEasing,
+    NonlinearRotationEasing, OverstepClamp, RotationEasingState, SkipEasingOnce, TeleportEpsilon,
+    TransformEasingCurve, TransformEasingPlugin, TransformEasingSet, TranslationEasingMask,
+    TranslationEasingState, VelocitySource, VelocitySourceItem,
+};
+use bevy::{
+    ecs::{
+        component::ComponentId, schedule::ScheduleLabel, system::SystemChangeTick,
+        world::DeferredWorld,
+    },
+    prelude::*,
 };
-use bevy::prelude::*;
 
 /// A plugin for [`Transform`] extrapolation, making movement in [`FixedUpdate`] appear smooth.
 ///
@@ -190,6 +202,7 @@ use bevy::prelude::*;
 ///           // Extrapolate translation by default, but not rotation.
 ///           extrapolate_translation_all: true,
 ///           extrapolate_rotation_all: false,
+///           ..default()
 ///       })
 ///       // ...
 ///       .run();
@@ -200,7 +213,10 @@ use bevy::prelude::*;
 /// by adding the [`NoTransformEasing`] component, or the individual [`NoTranslationEasing`] and [`NoRotationEasing`] components.
 ///
 /// Note that changing [`Transform`] manually in any schedule that *doesn't* use a fixed timestep is also supported,
-/// but it is equivalent to teleporting, and disables extrapolation for the entity for the remainder of that fixed timestep.
+/// and is treated as a teleport. Instead of snapping back to the old position at the next fixed timestep,
+/// the extrapolation is re-anchored around the new [`Transform`], and continues from there using the current velocity.
+/// To disable easing for the remainder of the fixed timestep instead, use the [`TeleportAndReset`] command
+/// or [`EasingCommandsExt::teleport_no_ease`].
 ///
 /// [`QueryData`]: bevy::ecs::query::QueryData
 /// [`TransformExtrapolationPlugin::extrapolate_all()`]: TransformExtrapolationPlugin::extrapolate_all
@@ -209,6 +225,8 @@ use bevy::prelude::*;
 /// [`NoTransformEasing`]: crate::NoTransformEasing
 /// [`NoTranslationEasing`]: crate::NoTranslationEasing
 /// [`NoRotationEasing`]: crate::NoRotationEasing
+/// [`TeleportAndReset`]: crate::commands::TeleportAndReset
+/// [`EasingCommandsExt::teleport_no_ease`]: crate::commands::EasingCommandsExt::teleport_no_ease
 ///
 /// # Alternatives
 ///
@@ -244,6 +262,23 @@ pub struct TransformExtrapolationPlugin<LinVel: VelocitySource, AngVel: Velocity
     ///
     /// [`NoTransformEasing`]: crate::NoTransformEasing
     pub extrapolate_rotation_all: bool,
+    /// If `true`, extrapolated entities are always snapped to their true [`Transform`] from the end
+    /// of the previous fixed timestep when the next fixed timestep starts, even while [`EasingPaused`] is set.
+    ///
+    /// This matches interpolation, which always completes the previous easing before the next fixed timestep.
+    /// The extrapolation states are then also updated while paused, and resuming easing continues
+    /// extrapolating from the true [`Transform`].
+    ///
+    /// By default, the extrapolation systems are skipped while easing is paused, so pausing easing
+    /// in between fixed timesteps keeps the partially extrapolated [`Transform`] for the simulation.
+    ///
+    /// Default: `false`
+    pub complete_extrapolation: bool,
+    /// The schedules that the extrapolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
     /// Phantom data use the type parameters.
     #[doc(hidden)]
     pub _phantom: PhantomData<(LinVel, AngVel)>,
@@ -256,6 +291,8 @@ impl<LinVel: VelocitySource, AngVel: VelocitySource> Default
         Self {
             extrapolate_translation_all: false,
             extrapolate_rotation_all: false,
+            complete_extrapolation: false,
+            schedules: EasingSchedules::DEFAULT,
             _phantom: PhantomData,
         }
     }
@@ -273,41 +310,110 @@ impl<LinVel: VelocitySource, AngVel: VelocitySource> TransformExtrapolationPlugi
         Self {
             extrapolate_translation_all: true,
             extrapolate_rotation_all: true,
+            complete_extrapolation: false,
+            schedules: EasingSchedules::DEFAULT,
             _phantom: PhantomData,
         }
     }
+
+    /// Sets the schedules that the extrapolation systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
 }
 
 impl<LinVel: VelocitySource, AngVel: VelocitySource> Plugin
     for TransformExtrapolationPlugin<LinVel, AngVel>
 {
     fn build(&self, app: &mut App) {
+        let fixed_first = self.schedules.fixed_first();
+        let fixed_last = self.schedules.fixed_last();
+
         //Register components.
         app.register_type::<(
             TransformExtrapolation,
             TranslationExtrapolation,
             RotationExtrapolation,
+            ExtrapolationLimit,
+            FastSpin,
         )>();
 
         // Reset the transform to the start of the extrapolation at the beginning of the fixed timestep
         // to match the true position from the end of the previous fixed tick.
+        let reset_systems = (
+            reset_translation_extrapolation,
+            reset_rotation_extrapolation,
+        )
+            .before(TransformEasingSet::Reset);
+
+        // Update the start and end state of the extrapolation at the end of the fixed timestep.
+        let update_systems = (
+            update_translation_extrapolation_states::<LinVel>,
+            update_rotation_extrapolation_states::<AngVel>,
+        )
+            .in_set(TransformEasingSet::UpdateEnd);
+
+        if self.complete_extrapolation {
+            // Always complete the previous extrapolation, like interpolation does.
+            // The states are also updated while paused, so that the true transform can be restored
+            // at the start of the next fixed timestep.
+            app.init_resource::<CompleteExtrapolation>();
+            app.add_systems(fixed_first, reset_systems);
+            app.add_systems(fixed_last, update_systems);
+        } else {
+            app.add_systems(
+                fixed_first,
+                reset_systems.run_if(resource_equals(EasingPaused(false))),
+            );
+            app.add_systems(
+                fixed_last,
+                update_systems.run_if(resource_equals(EasingPaused(false))),
+            );
+        }
+
+        // Re-anchor the extrapolation around the new transform when it is changed outside of the fixed timestep.
+        // This runs before the generic reset, so that the extrapolation is not reset to `None`.
         app.add_systems(
-            FixedFirst,
+            self.schedules.fixed_main_loop(),
             (
-                reset_translation_extrapolation,
-                reset_rotation_extrapolation,
+                reanchor_translation_extrapolation::<LinVel>,
+                reanchor_rotation_extrapolation::<AngVel>,
             )
-                .before(TransformEasingSet::Reset),
+                .before(reset_easing_states_on_transform_change)
+                .before(TransformEasingSet::Ease),
         );
 
-        // Update the start and end state of the extrapolation at the end of the fixed timestep.
+        // Warn about extrapolated entities that are missing the velocity components needed for extrapolation.
+        // Sources without velocity components, like `()`, are intentionally not extrapolated.
+        if has_velocity_components::<LinVel>() {
+            app.add_systems(
+                self.schedules.fixed_main_loop(),
+                validate_translation_extrapolation::<LinVel>.before(TransformEasingSet::Ease),
+            );
+        }
+        if has_velocity_components::<AngVel>() {
+            app.add_systems(
+                self.schedules.fixed_main_loop(),
+                validate_rotation_extrapolation::<AngVel>.before(TransformEasingSet::Ease),
+            );
+        }
+
+        // Overwrite the shortest path rotation for fast spinning entities,
+        // following the full predicted rotation instead.
         app.add_systems(
-            FixedLast,
-            (
-                update_translation_extrapolation_states::<LinVel>,
-                update_rotation_extrapolation_states::<AngVel>,
-            )
-                .in_set(TransformEasingSet::UpdateEnd),
+            self.schedules.fixed_main_loop(),
+            ease_fast_spin_extrapolation
+                .after(ease_rotation_slerp::<Transform, Without<CustomEaseTarget>>)
+                .in_set(TransformEasingSet::Ease),
         );
 
         // Insert extrapolation components automatically for all entities with a `Transform`
@@ -324,11 +430,20 @@ impl<LinVel: VelocitySource, AngVel: VelocitySource> Plugin
         // Add the `TransformEasingPlugin` if it hasn't been added yet.
         // It performs the actual easing based on the start and end states set by the extrapolation.
         if !app.is_plugin_added::<TransformEasingPlugin>() {
-            app.add_plugins(TransformEasingPlugin);
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
         }
     }
 }
 
+/// A marker resource inserted when [`TransformExtrapolationPlugin::complete_extrapolation`] is enabled.
+///
+/// The `start` of extrapolated properties is then the true [`Transform`], so it is not rebased when easing is resumed.
+#[derive(Resource, Default)]
+pub(crate) struct CompleteExtrapolation;
+
 /// Enables [`Transform`] extrapolation for an entity, making changes to translation
 /// and rotation in [`FixedUpdate`] appear smooth.
 ///
@@ -355,6 +470,7 @@ pub struct TransformExtrapolation;
 ///
 /// See the [`TransformExtrapolationPlugin`] for more information.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_remove = on_remove_translation_extrapolation)]
 #[reflect(Component, Debug, Default)]
 #[require(TranslationEasingState)]
 pub struct TranslationExtrapolation;
@@ -367,21 +483,128 @@ pub struct TranslationExtrapolation;
 ///
 /// See the [`TransformExtrapolationPlugin`] for more information.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_remove = on_remove_rotation_extrapolation)]
 #[reflect(Component, Debug, Default)]
 #[require(RotationEasingState)]
 pub struct RotationExtrapolation;
 
+/// Limits how far ahead extrapolation can predict for an entity.
+///
+/// The time used for predicting the `end` of extrapolation from velocity is clamped to [`max_secs`],
+/// which limits overshoot and large visual jumps when the fixed timestep is large.
+///
+/// If an entity has no [`ExtrapolationLimit`], the prediction is unlimited.
+///
+/// [`max_secs`]: ExtrapolationLimit::max_secs
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::extrapolation::{ExtrapolationLimit, TransformExtrapolation};
+///
+/// fn setup(mut commands: Commands) {
+///     // Never predict more than 50 milliseconds ahead.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformExtrapolation,
+///         ExtrapolationLimit { max_secs: 0.05 },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct ExtrapolationLimit {
+    /// The maximum time in seconds that extrapolation can predict ahead.
+    ///
+    /// Default: `f32::INFINITY`
+    pub max_secs: f32,
+}
+
+impl Default for ExtrapolationLimit {
+    fn default() -> Self {
+        Self {
+            max_secs: f32::INFINITY,
+        }
+    }
+}
+
+impl ExtrapolationLimit {
+    /// Returns the given prediction time clamped to the limit.
+    pub fn clamp(&self, delta_secs: f32) -> f32 {
+        delta_secs.min(self.max_secs)
+    }
+}
+
+/// Enables correct rotation extrapolation for entities that rotate more than half a turn per fixed timestep.
+///
+/// By default, the predicted `end` of rotation extrapolation is eased towards with [`Quat::slerp`],
+/// which always takes the shortest path. If the angular velocity exceeds π radians per fixed timestep,
+/// the shortest path is in the wrong direction, and the rendered rotation appears to spin backwards
+/// or to not rotate at all.
+///
+/// With [`FastSpin`], the full rotation predicted from the angular velocity is stored, and the rotation
+/// is eased along it instead, which also supports multiple full revolutions per fixed timestep.
+///
+/// This only affects rotation extrapolation. For interpolation, consider using
+/// [`RotationEasingMode::RespectWinding`](crate::RotationEasingMode::RespectWinding) instead.
+/// If an extrapolated entity has both, [`FastSpin`] takes precedence.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::extrapolation::{FastSpin, TransformExtrapolation};
+///
+/// fn setup(mut commands: Commands) {
+///     // A fan blade that spins several times per fixed timestep.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformExtrapolation,
+///         FastSpin::default(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct FastSpin {
+    /// The rotation predicted for the current fixed timestep as a scaled axis in local space,
+    /// such that `end = start * Quat::from_scaled_axis(predicted_rotation)`.
+    ///
+    /// This is updated automatically whenever the extrapolation is updated.
+    pub predicted_rotation: Vec3,
+}
+
+/// Applies the `start` of the translation easing and resets the easing state when [`TranslationExtrapolation`]
+/// is removed, so that switching to another easing method at runtime doesn't leave stale state behind.
+fn on_remove_translation_extrapolation(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    restore_and_reset_translation(&mut world, entity, FixedState::Start);
+}
+
+/// Applies the `start` of the rotation easing and resets the easing state when [`RotationExtrapolation`]
+/// is removed, so that switching to another easing method at runtime doesn't leave stale state behind.
+fn on_remove_rotation_extrapolation(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    restore_and_reset_rotation(&mut world, entity, FixedState::Start);
+}
+
 /// Resets the translation to the start of the extrapolation at the beginning of the fixed timestep
 /// to match the true position from the end of the previous fixed tick.
 fn reset_translation_extrapolation(
     mut query: Query<
-        (&mut Transform, &TranslationEasingState),
+        (
+            &mut Transform,
+            &TranslationEasingState,
+            Option<&TranslationEasingMask>,
+        ),
         (With<TranslationExtrapolation>, Without<NoTranslationEasing>),
     >,
 ) {
-    for (mut transform, translation_easing) in &mut query {
+    for (mut transform, translation_easing, mask) in &mut query {
         if let Some(start) = translation_easing.start {
-            transform.translation = start;
+            transform.translation = match mask {
+                Some(mask) => Vec3::select(mask.0, start, transform.translation),
+                None => start,
+            };
         }
     }
 }
@@ -404,15 +627,27 @@ fn reset_rotation_extrapolation(
 /// Updates the start and end states of the extrapolation for the next fixed timestep.
 fn update_translation_extrapolation_states<V: VelocitySource>(
     mut query: Query<
-        (&Transform, &mut TranslationEasingState, &V::Current),
+        (
+            &Transform,
+            &mut TranslationEasingState,
+            &V::Current,
+            Option<&ExtrapolationLimit>,
+        ),
         (With<TranslationExtrapolation>, Without<NoTranslationEasing>),
     >,
-    time: Res<Time>,
+    time: Option<Res<Time<Fixed>>>,
 ) {
+    let Some(time) = time else {
+        return;
+    };
+
+    // Predict one fixed timestep ahead. `Time<Fixed>` is used explicitly instead of the generic `Time`,
+    // which only refers to the fixed clock when the schedule is run as a part of `FixedMain`.
     let delta_secs = time.delta_secs();
 
-    for (transform, mut translation_easing, end_vel) in &mut query {
+    for (transform, mut translation_easing, end_vel, limit) in &mut query {
         translation_easing.start = Some(transform.translation);
+        let delta_secs = limit.map_or(delta_secs, |limit| limit.clamp(delta_secs));
 
         // Extrapolate the next state based on the current state and velocities.
         let lin_vel = <V::Item<'static> as VelocitySourceItem<V>>::current(end_vel);
@@ -423,19 +658,250 @@ fn update_translation_extrapolation_states<V: VelocitySource>(
 /// Updates the start and end states of the extrapolation for the next fixed timestep.
 fn update_rotation_extrapolation_states<V: VelocitySource>(
     mut query: Query<
-        (&Transform, &mut RotationEasingState, &V::Current),
+        (
+            &Transform,
+            &mut RotationEasingState,
+            &V::Current,
+            Option<&ExtrapolationLimit>,
+            Option<&mut FastSpin>,
+        ),
         (With<RotationExtrapolation>, Without<NoRotationEasing>),
     >,
-    time: Res<Time>,
+    time: Option<Res<Time<Fixed>>>,
 ) {
+    let Some(time) = time else {
+        return;
+    };
+
+    // Predict one fixed timestep ahead. `Time<Fixed>` is used explicitly instead of the generic `Time`,
+    // which only refers to the fixed clock when the schedule is run as a part of `FixedMain`.
     let delta_secs = time.delta_secs();
 
-    for (transform, mut rotation_easing, end_vel) in &mut query {
+    for (transform, mut rotation_easing, end_vel, limit, fast_spin) in &mut query {
         rotation_easing.start = Some(transform.rotation);
+        let delta_secs = limit.map_or(delta_secs, |limit| limit.clamp(delta_secs));
 
         // Extrapolate the next state based on the current state and velocities.
-        let ang_vel = <V::Item<'static> as VelocitySourceItem<V>>::current(end_vel);
+        let ang_vel = <V::Item<'static> as VelocitySourceItem<V>>::current_angular(
+            end_vel,
+            transform.rotation,
+        );
         let scaled_axis = ang_vel * delta_secs;
         rotation_easing.end = Some(transform.rotation * Quat::from_scaled_axis(scaled_axis));
+
+        if let Some(mut fast_spin) = fast_spin {
+            fast_spin.predicted_rotation = scaled_axis;
+        }
+    }
+}
+
+/// Re-anchors the translation extrapolation around the current translation when [`Transform`]
+/// is changed outside of the fixed timestep, extrapolating the new `end` using the current velocity.
+///
+/// Without this, the translation would be reset to the old `start` at the beginning of the next fixed timestep.
+#[allow(clippy::type_complexity)]
+fn reanchor_translation_extrapolation<V: VelocitySource>(
+    mut query: Query<
+        (
+            Ref<Transform>,
+            &mut TranslationEasingState,
+            &V::Current,
+            Option<&TranslationEasingMask>,
+            Option<&ExtrapolationLimit>,
+            Option<&EasingTick>,
+        ),
+        (
+            Changed<Transform>,
+            With<TranslationExtrapolation>,
+            Without<NoTranslationEasing>,
+            Without<KeepEasingOnChange>,
+        ),
+    >,
+    last_easing_tick: Res<LastEasingTick>,
+    epsilon: Res<TeleportEpsilon>,
+    system_change_tick: SystemChangeTick,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    let this_run = system_change_tick.this_run();
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut translation_easing, end_vel, mask, limit, easing_tick) in &mut query {
+        if !is_user_change(&transform, easing_tick, last_easing_tick.0, this_run) {
+            continue;
+        }
+
+        let (Some(start), Some(end)) = (translation_easing.start, translation_easing.end) else {
+            continue;
+        };
+
+        // Only consider axes that are eased. Other axes can be freely modified.
+        let mask = mask.map_or(BVec3::TRUE, |mask| mask.0);
+        let differs = |value: Vec3| epsilon.translation_differs(transform.translation, value, mask);
+
+        if differs(start) && differs(end) {
+            let lin_vel = <V::Item<'static> as VelocitySourceItem<V>>::current(end_vel);
+            let delta_secs = limit.map_or(delta_secs, |limit| limit.clamp(delta_secs));
+            translation_easing.start = Some(transform.translation);
+            translation_easing.end = Some(transform.translation + lin_vel * delta_secs);
+        }
+    }
+}
+
+/// Re-anchors the rotation extrapolation around the current rotation when [`Transform`]
+/// is changed outside of the fixed timestep, extrapolating the new `end` using the current velocity.
+///
+/// Without this, the rotation would be reset to the old `start` at the beginning of the next fixed timestep.
+#[allow(clippy::type_complexity)]
+fn reanchor_rotation_extrapolation<V: VelocitySource>(
+    mut query: Query<
+        (
+            Ref<Transform>,
+            &mut RotationEasingState,
+            &V::Current,
+            Option<&ExtrapolationLimit>,
+            Option<&EasingTick>,
+            Option<&mut FastSpin>,
+        ),
+        (
+            Changed<Transform>,
+            With<RotationExtrapolation>,
+            Without<NoRotationEasing>,
+            Without<KeepEasingOnChange>,
+        ),
+    >,
+    last_easing_tick: Res<LastEasingTick>,
+    epsilon: Res<TeleportEpsilon>,
+    system_change_tick: SystemChangeTick,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    let this_run = system_change_tick.this_run();
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut rotation_easing, end_vel, limit, easing_tick, fast_spin) in &mut query {
+        if !is_user_change(&transform, easing_tick, last_easing_tick.0, this_run) {
+            continue;
+        }
+
+        let (Some(start), Some(end)) = (rotation_easing.start, rotation_easing.end) else {
+            continue;
+        };
+
+        let differs = |value: Quat| epsilon.rotation_differs(transform.rotation, value);
+
+        if differs(start) && differs(end) {
+            let ang_vel = <V::Item<'static> as VelocitySourceItem<V>>::current_angular(
+                end_vel,
+                transform.rotation,
+            );
+            let delta_secs = limit.map_or(delta_secs, |limit| limit.clamp(delta_secs));
+            let scaled_axis = ang_vel * delta_secs;
+            rotation_easing.start = Some(transform.rotation);
+            rotation_easing.end = Some(transform.rotation * Quat::from_scaled_axis(scaled_axis));
+
+            if let Some(mut fast_spin) = fast_spin {
+                fast_spin.predicted_rotation = scaled_axis;
+            }
+        }
+    }
+}
+
+/// Eases the rotations of extrapolated entities with [`FastSpin`] along the full predicted rotation,
+/// overwriting the shortest path rotation from [`Quat::slerp`].
+#[allow(clippy::type_complexity)]
+fn ease_fast_spin_extrapolation(
+    mut query: Query<
+        (
+            &mut Transform,
+            &RotationEasingState,
+            &FastSpin,
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            With<RotationExtrapolation>,
+            Without<NonlinearRotationEasing>,
+            Without<NoRotationEasing>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, easing, fast_spin, curve, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.rotation) {
+                return;
+            }
+            if let (Some(start), Some(_)) = (easing.start, easing.end) {
+                let overstep = time_scale.map_or(overstep, |time_scale| {
+                    time_scale.apply(overstep, &overstep_clamp)
+                });
+                let alpha = curve.map_or(overstep, |curve| curve.sample(overstep));
+                let rotation = (start
+                    * Quat::from_scaled_axis(fast_spin.predicted_rotation * alpha))
+                .normalize();
+
+                // Only write when the value changes to avoid triggering change detection.
+                if transform.rotation != rotation {
+                    transform.rotation = rotation;
+                }
+            }
+        },
+    );
+}
+
+/// Logs a warning for newly extrapolated entities that are missing the linear velocity component
+/// required by the [`VelocitySource`], as they would otherwise silently not be extrapolated.
+fn validate_translation_extrapolation<V: VelocitySource>(
+    query: Query<
+        Entity,
+        (
+            Added<TranslationExtrapolation>,
+            Without<NoTranslationEasing>,
+            Without<V::Current>,
+        ),
+    >,
+) {
+    let entities: Vec<Entity> = query.iter().collect();
+    if !entities.is_empty() {
+        warn!(
+            "entities {entities:?} have `TranslationExtrapolation`, but no `{}` component to read the linear velocity from, so their translation is not extrapolated",
+            type_name::<V::Current>()
+        );
+    }
+}
+
+/// Logs a warning for newly extrapolated entities that are missing the angular velocity component
+/// required by the [`VelocitySource`], as they would otherwise silently not be extrapolated.
+fn validate_rotation_extrapolation<V: VelocitySource>(
+    query: Query<
+        Entity,
+        (
+            Added<RotationExtrapolation>,
+            Without<NoRotationEasing>,
+            Without<V::Current>,
+        ),
+    >,
+) {
+    let entities: Vec<Entity> = query.iter().collect();
+    if !entities.is_empty() {
+        warn!(
+            "entities {entities:?} have `RotationExtrapolation`, but no `{}` component to read the angular velocity from, so their rotation is not extrapolated",
+            type_name::<V::Current>()
+        );
     }
 }
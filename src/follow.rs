@@ -0,0 +1,197 @@
+//! Smooth following of eased entities, such as for cameras following an interpolated player.
+//!
+//! See the [`CameraFollowPlugin`] for more information.
+
+use crate::{EasingSchedules, TransformEasingPlugin, TransformEasingSet};
+use bevy::{
+    ecs::schedule::ScheduleLabel, math::StableInterpolate, prelude::*, transform::TransformSystem,
+};
+
+/// A plugin for making entities follow the eased [`Transform`] of a target entity,
+/// configured with the [`CameraFollowEasing`] component.
+///
+/// A common mistake is to make a camera follow an interpolated target in [`Update`] with its own smoothing.
+/// The [`Transform`] of the target is already eased, so the camera smooths an already smoothed value,
+/// and the lag compounds. Depending on system ordering, the camera can also read the eased value
+/// from the previous frame, adding another frame of lag.
+///
+/// This plugin instead moves followers right after easing in [`RunFixedMainLoop`], reading the eased
+/// [`Transform`] of the target for the current frame. By default, the follower is attached rigidly to the target
+/// with an offset, so it moves exactly as smoothly as the target without any additional lag.
+/// Optional smoothing can be configured with [`CameraFollowEasing::decay_rate`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{follow::*, prelude::*};
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((
+///         TransformInterpolationPlugin::default(),
+///         CameraFollowPlugin::default(),
+///     ));
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let player = commands
+///         .spawn((Transform::default(), TransformInterpolation))
+///         .id();
+///
+///     // Follow the player from above without any easing of its own.
+///     commands.spawn((
+///         Camera2d,
+///         CameraFollowEasing::new(player).with_offset(Vec3::new(0.0, 0.0, 10.0)),
+///     ));
+/// }
+/// ```
+///
+/// Custom camera systems that need more control can read the `start` and `end` of the easing states
+/// of the target and the [`EasingOverstep`](crate::EasingOverstep) to compute their own consistent interpolation.
+/// They should run after [`TransformEasingSet::Ease`] in the same schedule.
+///
+/// The follower should not have easing components of its own, and both the follower and the target
+/// should be in the same space, as the local [`Transform`] of the target is used.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+#[derive(Debug)]
+pub struct CameraFollowPlugin {
+    /// The schedules that the easing systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for CameraFollowPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl CameraFollowPlugin {
+    /// Sets the schedules that the easing systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for CameraFollowPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<CameraFollowEasing>();
+
+        // Move followers after the targets have been eased, before transform propagation.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            follow_eased_targets
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .after(TransformEasingSet::UpdateEasingTick)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Makes an entity follow the eased translation of a target entity.
+///
+/// See the [`CameraFollowPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug)]
+pub struct CameraFollowEasing {
+    /// The entity to follow.
+    pub target: Entity,
+    /// The offset from the translation of the target.
+    ///
+    /// Default: `Vec3::ZERO`
+    pub offset: Vec3,
+    /// The decay rate used for smoothing the movement of the follower towards the target,
+    /// with exponential decay.
+    ///
+    /// Higher values make the follower catch up faster. An infinite decay rate disables smoothing,
+    /// attaching the follower rigidly to the target.
+    ///
+    /// Default: `f32::INFINITY`
+    pub decay_rate: f32,
+}
+
+impl CameraFollowEasing {
+    /// Creates a new [`CameraFollowEasing`] that rigidly follows the given target entity.
+    pub const fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            decay_rate: f32::INFINITY,
+        }
+    }
+
+    /// Sets the offset from the translation of the target.
+    pub const fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the decay rate used for smoothing the movement of the follower towards the target.
+    pub const fn with_decay_rate(mut self, decay_rate: f32) -> Self {
+        self.decay_rate = decay_rate;
+        self
+    }
+}
+
+/// Moves entities with [`CameraFollowEasing`] towards the eased [`Transform`] of their target.
+fn follow_eased_targets(
+    followers: Query<(Entity, &CameraFollowEasing)>,
+    mut transforms: Query<&mut Transform>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (entity, follow) in &followers {
+        let Ok(target) = transforms
+            .get(follow.target)
+            .map(|transform| transform.translation)
+        else {
+            continue;
+        };
+        let Ok(mut transform) = transforms.get_mut(entity) else {
+            continue;
+        };
+
+        let goal = target + follow.offset;
+        let translation = if follow.decay_rate.is_finite() {
+            let mut translation = transform.translation;
+            translation.smooth_nudge(&goal, follow.decay_rate, delta_secs);
+            translation
+        } else {
+            goal
+        };
+
+        // Only write when the value changes to avoid triggering change detection.
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Gizmo visualization of transform easing states for debugging.
+//!
+//! See the [`TransformEasingGizmosPlugin`] for more information.
+
+use bevy::{
+    color::palettes::css::{LIME, ORANGE, SKY_BLUE},
+    prelude::*,
+};
+
+use crate::{RotationEasingState, ScaleEasingState, TranslationEasingState};
+
+/// A debugging plugin that draws the `start` and `end` states of transform easing using [`Gizmos`].
+///
+/// For each entity with easing states:
+///
+/// - A line is drawn from the `start` to the `end` of the [`TranslationEasingState`].
+/// - An arc is drawn for the rotation from the `start` to the `end` of the [`RotationEasingState`].
+///   This makes issues such as `slerp` taking the other way around immediately visible.
+/// - Cuboids are drawn for the `start` and `end` of the [`ScaleEasingState`].
+///
+/// The states are drawn in the local space of the parent of each entity,
+/// so they are only accurate for entities without a [`Parent`].
+///
+/// What is drawn can be configured with the [`EasingGizmoConfig`] resource.
+///
+/// Requires the `gizmos` feature.
+///
+/// # Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{gizmos::*, prelude::*};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             TransformInterpolationPlugin::default(),
+///             TransformEasingGizmosPlugin,
+///         ))
+///         // Only draw translation and rotation easing.
+///         .insert_resource(EasingGizmoConfig {
+///             draw_scale: false,
+///             ..default()
+///         })
+///         // ...
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct TransformEasingGizmosPlugin;
+
+impl Plugin for TransformEasingGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EasingGizmoConfig>();
+        app.init_resource::<EasingGizmoConfig>();
+
+        app.add_systems(
+            PostUpdate,
+            (
+                draw_translation_easing
+                    .run_if(|config: Res<EasingGizmoConfig>| config.draw_translation),
+                draw_rotation_easing.run_if(|config: Res<EasingGizmoConfig>| config.draw_rotation),
+                draw_scale_easing.run_if(|config: Res<EasingGizmoConfig>| config.draw_scale),
+            ),
+        );
+    }
+}
+
+/// A resource for configuring which easing states are drawn by the [`TransformEasingGizmosPlugin`].
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct EasingGizmoConfig {
+    /// If `true`, a line is drawn from the `start` to the `end` of translation easing.
+    ///
+    /// Default: `true`
+    pub draw_translation: bool,
+    /// If `true`, an arc is drawn from the `start` to the `end` of rotation easing.
+    ///
+    /// Default: `true`
+    pub draw_rotation: bool,
+    /// If `true`, cuboids are drawn for the `start` and `end` of scale easing.
+    ///
+    /// Default: `true`
+    pub draw_scale: bool,
+    /// The radius of the arcs drawn for rotation easing.
+    ///
+    /// Default: `1.0`
+    pub rotation_arc_radius: f32,
+}
+
+impl Default for EasingGizmoConfig {
+    fn default() -> Self {
+        Self {
+            draw_translation: true,
+            draw_rotation: true,
+            draw_scale: true,
+            rotation_arc_radius: 1.0,
+        }
+    }
+}
+
+fn draw_translation_easing(query: Query<&TranslationEasingState>, mut gizmos: Gizmos) {
+    for easing in &query {
+        if let (Some(start), Some(end)) = (easing.start, easing.end) {
+            gizmos.line(start, end, LIME);
+        }
+    }
+}
+
+fn draw_rotation_easing(
+    query: Query<(&Transform, &RotationEasingState)>,
+    config: Res<EasingGizmoConfig>,
+    mut gizmos: Gizmos,
+) {
+    for (transform, easing) in &query {
+        let (Some(start), Some(end)) = (easing.start, easing.end) else {
+            continue;
+        };
+
+        // The rotation that `slerp` performs from `start` to `end`.
+        let (axis, angle) = (end * start.inverse()).to_axis_angle();
+        if angle.abs() < f32::EPSILON {
+            continue;
+        }
+
+        // `arc_3d` draws an arc in the XZ plane starting from the X axis, around the Y axis.
+        let x_axis = axis.any_orthonormal_vector();
+        let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, axis, x_axis.cross(axis)));
+
+        gizmos.arc_3d(
+            angle,
+            config.rotation_arc_radius,
+            Isometry3d::new(transform.translation, rotation),
+            ORANGE,
+        );
+    }
+}
+
+fn draw_scale_easing(query: Query<(&Transform, &ScaleEasingState)>, mut gizmos: Gizmos) {
+    for (transform, easing) in &query {
+        if let (Some(start), Some(end)) = (easing.start, easing.end) {
+            let transform = transform.with_scale(start);
+            gizmos.cuboid(transform, SKY_BLUE.with_alpha(0.5));
+            gizmos.cuboid(transform.with_scale(end), SKY_BLUE);
+        }
+    }
+}
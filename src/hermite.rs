@@ -1,14 +1,21 @@
 //! Hermite interpolation for [`Transform`] easing.
 
-use std::{f32::consts::TAU, marker::PhantomData};
+use core::{f32::consts::TAU, marker::PhantomData};
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::{query::QueryItem, schedule::ScheduleLabel},
+    prelude::*,
+};
 use ops::FloatPow;
 
+#[cfg(feature = "f64")]
+use bevy::math::{DQuat, DVec3};
+
 use crate::{
-    NoRotationEasing, NoTranslationEasing, NonlinearRotationEasing, NonlinearTranslationEasing,
-    RotationEasingState, TransformEasingSet, TranslationEasingState, VelocitySource,
-    VelocitySourceItem,
+    backend::{register_easing_backend, EasingBackend, EasingContext},
+    ease_or, has_velocity_components, velocity_component_names, DummyComponent, EasingSchedules,
+    NoRotationEasing, NoTranslationEasing, RotationEasingState, ScaleEasingState,
+    TransformEasingSet, TranslationEasingState, VelocitySource, VelocitySourceItem,
 };
 
 /// A Hermite interpolation plugin for [`Transform`] easing.
@@ -160,6 +167,13 @@ use crate::{
 /// }
 /// ```
 ///
+/// The previous velocity components must store the velocity from the start of the current fixed timestep.
+/// If the physics engine doesn't store it, the [`TrackPreviousPlugin`] can be used to maintain
+/// [`Previous`] components automatically, instead of updating them manually.
+///
+/// [`TrackPreviousPlugin`]: crate::velocity::TrackPreviousPlugin
+/// [`Previous`]: crate::velocity::Previous
+///
 /// Hermite interpolation can now be used for any interpolated or extrapolated entity
 /// that has the velocity components by adding the [`TransformHermiteEasing`] component:
 ///
@@ -202,16 +216,42 @@ use crate::{
 ///
 /// [`QueryData`]: bevy::ecs::query::QueryData
 #[derive(Debug)]
-pub struct TransformHermiteEasingPlugin<LinVel: VelocitySource, AngVel: VelocitySource>(
-    PhantomData<LinVel>,
-    PhantomData<AngVel>,
-);
+pub struct TransformHermiteEasingPlugin<LinVel: VelocitySource, AngVel: VelocitySource> {
+    /// The schedules that the Hermite interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    ///
+    /// [`TransformEasingPlugin`]: crate::TransformEasingPlugin
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<(LinVel, AngVel)>,
+}
 
 impl<LinVel: VelocitySource, AngVel: VelocitySource> Default
     for TransformHermiteEasingPlugin<LinVel, AngVel>
 {
     fn default() -> Self {
-        Self(PhantomData, PhantomData)
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<LinVel: VelocitySource, AngVel: VelocitySource> TransformHermiteEasingPlugin<LinVel, AngVel> {
+    /// Sets the schedules that the Hermite interpolation systems run in.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
     }
 }
 
@@ -224,22 +264,68 @@ impl<LinVel: VelocitySource, AngVel: VelocitySource> Plugin
             TransformHermiteEasing,
             TranslationHermiteEasing,
             RotationHermiteEasing,
+            HermiteRotationConfig,
         )>();
 
-        // Mark entities with Hermite interpolation as having nonlinear easing to disable linear easing.
-        let _ = app
-            .try_register_required_components::<TranslationHermiteEasing, NonlinearTranslationEasing>();
-        let _ = app
-            .try_register_required_components::<RotationHermiteEasing, NonlinearRotationEasing>();
-
-        // Perform easing.
-        app.add_systems(
-            RunFixedMainLoop,
-            (
-                ease_translation_hermite::<LinVel>,
-                ease_rotation_hermite::<AngVel>,
-            )
-                .in_set(TransformEasingSet::Ease),
+        // Register Hermite interpolation as an easing backend. This marks entities with Hermite interpolation
+        // as having nonlinear easing to disable linear easing, and adds the easing systems.
+        register_easing_backend::<HermiteEasing<LinVel, AngVel>>(app, self.schedules);
+
+        // Warn about entities that are missing the velocity components needed for Hermite interpolation.
+        // Sources without velocity components, like `()`, are intentionally not eased with Hermite interpolation.
+        if has_velocity_components::<LinVel>() {
+            app.add_systems(
+                self.schedules.fixed_main_loop(),
+                validate_translation_hermite_easing::<LinVel>.before(TransformEasingSet::Ease),
+            );
+        }
+        if has_velocity_components::<AngVel>() {
+            app.add_systems(
+                self.schedules.fixed_main_loop(),
+                validate_rotation_hermite_easing::<AngVel>.before(TransformEasingSet::Ease),
+            );
+        }
+    }
+}
+
+/// Logs a warning for new entities with [`TranslationHermiteEasing`] that are missing the velocity components
+/// required by the [`VelocitySource`]. Without them, the translation of the entity is not eased at all.
+fn validate_translation_hermite_easing<V: VelocitySource>(
+    query: Query<
+        Entity,
+        (
+            Added<TranslationHermiteEasing>,
+            Without<NoTranslationEasing>,
+            Or<(Without<V::Previous>, Without<V::Current>)>,
+        ),
+    >,
+) {
+    let entities: Vec<Entity> = query.iter().collect();
+    if !entities.is_empty() {
+        warn!(
+            "entities {entities:?} have `TranslationHermiteEasing`, but are missing {} to read the linear velocity from, so their translation is not eased",
+            velocity_component_names::<V>()
+        );
+    }
+}
+
+/// Logs a warning for new entities with [`RotationHermiteEasing`] that are missing the velocity components
+/// required by the [`VelocitySource`]. Without them, the rotation of the entity is not eased at all.
+fn validate_rotation_hermite_easing<V: VelocitySource>(
+    query: Query<
+        Entity,
+        (
+            Added<RotationHermiteEasing>,
+            Without<NoRotationEasing>,
+            Or<(Without<V::Previous>, Without<V::Current>)>,
+        ),
+    >,
+) {
+    let entities: Vec<Entity> = query.iter().collect();
+    if !entities.is_empty() {
+        warn!(
+            "entities {entities:?} have `RotationHermiteEasing`, but are missing {} to read the angular velocity from, so their rotation is not eased",
+            velocity_component_names::<V>()
         );
     }
 }
@@ -287,66 +373,190 @@ pub struct TranslationHermiteEasing;
 #[reflect(Component, Debug, Default)]
 pub struct RotationHermiteEasing;
 
-/// Eases the translations of entities with Hermite interpolation.
-fn ease_translation_hermite<V: VelocitySource>(
-    mut query: Query<
-        (
-            &mut Transform,
-            &TranslationEasingState,
-            &V::Previous,
-            &V::Current,
-        ),
-        Without<NoTranslationEasing>,
-    >,
-    time: Res<Time<Fixed>>,
-) {
-    let overstep = time.overstep_fraction();
-    let delta_secs = time.delta_secs();
-
-    query
-        .par_iter_mut()
-        .for_each(|(mut transform, interpolation, start_vel, end_vel)| {
-            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
-                let vel0 = <V::Item<'static> as VelocitySourceItem<V>>::previous(start_vel);
-                let vel1 = <V::Item<'static> as VelocitySourceItem<V>>::current(end_vel);
-                transform.translation =
-                    hermite_vec3(start, end, delta_secs * vel0, delta_secs * vel1, overstep);
-            }
-        });
+/// Configures [Hermite interpolation](TransformHermiteEasingPlugin) for the rotation of an entity.
+///
+/// If an entity has no [`HermiteRotationConfig`], the default configuration is used.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{hermite::HermiteRotationConfig, prelude::*};
+///
+/// fn setup(mut commands: Commands) {
+///     // This entity rotates slowly, so unwrapping can be skipped.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         TransformHermiteEasing,
+///         HermiteRotationConfig { unwrap: false },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct HermiteRotationConfig {
+    /// If `true`, the interpolation works for arbitrarily large angular velocities,
+    /// and handles multiple full revolutions in a single fixed timestep correctly.
+    ///
+    /// Disabling this is slightly cheaper, and is fine for entities that are known
+    /// to rotate by less than half a revolution per fixed timestep.
+    ///
+    /// See [`hermite_quat`] for more information.
+    ///
+    /// Default: `true`
+    pub unwrap: bool,
 }
 
-/// Eases the rotations of entities with Hermite interpolation.
-fn ease_rotation_hermite<V: VelocitySource>(
-    mut query: Query<
-        (
-            &mut Transform,
-            &RotationEasingState,
-            &V::Previous,
-            &V::Current,
+impl Default for HermiteRotationConfig {
+    fn default() -> Self {
+        Self { unwrap: true }
+    }
+}
+
+/// The [`EasingBackend`] for Hermite interpolation, used by the [`TransformHermiteEasingPlugin`].
+pub struct HermiteEasing<LinVel: VelocitySource, AngVel: VelocitySource>(
+    PhantomData<LinVel>,
+    PhantomData<AngVel>,
+);
+
+impl<LinVel: VelocitySource, AngVel: VelocitySource> EasingBackend
+    for HermiteEasing<LinVel, AngVel>
+{
+    type TranslationMarker = TranslationHermiteEasing;
+    type RotationMarker = RotationHermiteEasing;
+    type ScaleMarker = DummyComponent;
+
+    type TranslationData = (&'static LinVel::Previous, &'static LinVel::Current);
+    type RotationData = (
+        &'static AngVel::Previous,
+        &'static AngVel::Current,
+        Option<&'static HermiteRotationConfig>,
+    );
+    type ScaleData = ();
+
+    fn ease_translation(
+        start: Vec3,
+        end: Vec3,
+        (start_vel, end_vel): &QueryItem<Self::TranslationData>,
+        context: &EasingContext,
+    ) -> Vec3 {
+        let vel0 = <LinVel::Item<'static> as VelocitySourceItem<LinVel>>::previous(start_vel);
+        let vel1 = <LinVel::Item<'static> as VelocitySourceItem<LinVel>>::current(end_vel);
+        hermite_vec3(
+            start,
+            end,
+            context.delta_secs * vel0,
+            context.delta_secs * vel1,
+            context.alpha,
+        )
+    }
+
+    fn ease_rotation(
+        start: Quat,
+        end: Quat,
+        (start_vel, end_vel, config): &QueryItem<Self::RotationData>,
+        context: &EasingContext,
+    ) -> Quat {
+        let vel0 = <AngVel::Item<'static> as VelocitySourceItem<AngVel>>::previous_angular(
+            start_vel, start,
+        );
+        let vel1 =
+            <AngVel::Item<'static> as VelocitySourceItem<AngVel>>::current_angular(end_vel, end);
+        hermite_quat(
+            start,
+            end,
+            context.delta_secs * vel0,
+            context.delta_secs * vel1,
+            context.alpha,
+            config.is_none_or(|config| config.unwrap),
+        )
+    }
+}
+
+/// Computes the [`Transform`] that the given easing states would be eased to at the given `alpha`,
+/// using Hermite interpolation for translation and rotation, and linear interpolation for scale.
+///
+/// The linear and angular velocities are given as `(start, end)` pairs, in units per second,
+/// and are scaled by `delta_secs`, the duration of the fixed timestep. Rotations are unwrapped,
+/// like with the default [`HermiteRotationConfig`].
+///
+/// This computes the same result as the easing systems of the [`TransformHermiteEasingPlugin`]
+/// without mutating any components. See [`eased_transform`](crate::eased_transform) for more information
+/// on how states with a missing `start` or `end` are handled.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{
+///     hermite::hermite_eased_transform, RotationEasingState, ScaleEasingState,
+///     TranslationEasingState,
+/// };
+///
+/// let translation = TranslationEasingState {
+///     start: Some(Vec3::ZERO),
+///     end: Some(Vec3::new(1.0, 0.0, 0.0)),
+/// };
+/// let rotation = RotationEasingState::default();
+/// let scale = ScaleEasingState::default();
+///
+/// // With a constant velocity that matches the movement, the result is the same as with `lerp`.
+/// let velocity = Vec3::new(10.0, 0.0, 0.0);
+/// let transform = hermite_eased_transform(
+///     &translation,
+///     &rotation,
+///     &scale,
+///     (velocity, velocity),
+///     (Vec3::ZERO, Vec3::ZERO),
+///     0.1,
+///     0.5,
+/// );
+/// assert!(transform.translation.abs_diff_eq(Vec3::new(0.5, 0.0, 0.0), 1e-5));
+/// ```
+pub fn hermite_eased_transform(
+    translation: &TranslationEasingState,
+    rotation: &RotationEasingState,
+    scale: &ScaleEasingState,
+    (linear_start, linear_end): (Vec3, Vec3),
+    (angular_start, angular_end): (Vec3, Vec3),
+    delta_secs: f32,
+    alpha: f32,
+) -> Transform {
+    Transform {
+        translation: ease_or(
+            translation.start,
+            translation.end,
+            Vec3::ZERO,
+            |start, end| {
+                hermite_vec3(
+                    start,
+                    end,
+                    delta_secs * linear_start,
+                    delta_secs * linear_end,
+                    alpha,
+                )
+            },
         ),
-        Without<NoRotationEasing>,
-    >,
-    time: Res<Time<Fixed>>,
-) {
-    let overstep = time.overstep_fraction();
-    let delta_secs = time.delta_secs();
-
-    query
-        .par_iter_mut()
-        .for_each(|(mut transform, interpolation, start_vel, end_vel)| {
-            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
-                let vel0 = <V::Item<'static> as VelocitySourceItem<V>>::previous(start_vel);
-                let vel1 = <V::Item<'static> as VelocitySourceItem<V>>::current(end_vel);
-                transform.rotation = hermite_quat(
+        rotation: ease_or(
+            rotation.start,
+            rotation.end,
+            Quat::IDENTITY,
+            |start, end| {
+                hermite_quat(
                     start,
                     end,
-                    delta_secs * vel0,
-                    delta_secs * vel1,
-                    overstep,
+                    delta_secs * angular_start,
+                    delta_secs * angular_end,
+                    alpha,
                     true,
-                );
-            }
-        });
+                )
+            },
+        ),
+        scale: ease_or(scale.start, scale.end, Vec3::ONE, |start, end| {
+            start.lerp(end, alpha)
+        }),
+    }
 }
 
 /// Performs a cubic Hermite interpolation between two vectors `p0` and `p1` with velocities `v0` and `v1`
@@ -440,3 +650,68 @@ pub fn hermite_quat(qa: Quat, qb: Quat, w0: Vec3, w1: Vec3, t: f32, unwrap: bool
         * Quat::from_scaled_axis(b1 * w0_div_3)
         * qa
 }
+
+/// Performs a cubic Hermite interpolation between two double-precision vectors `p0` and `p1`
+/// with velocities `v0` and `v1` based on the value at `t`.
+///
+/// This is the same as [`hermite_vec3`], but for large worlds that need double precision.
+///
+/// When `t` is `0.0`, the result will be equal to `p0`. When `t` is `1.0`, the result will be equal to `p1`.
+#[cfg(feature = "f64")]
+pub fn hermite_dvec3(p0: DVec3, p1: DVec3, v0: DVec3, v1: DVec3, t: f64) -> DVec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // Polynomial coefficients
+    let b0 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let b1 = 3.0 * t2 - 2.0 * t3;
+    let b2 = t3 - 2.0 * t2 + t;
+    let b3 = t3 - t2;
+
+    b0 * p0 + b1 * p1 + b2 * v0 + b3 * v1
+}
+
+/// Performs a cubic Hermite interpolation between double-precision quaternions `q0` and `q1`
+/// with angular velocities `w0` and `w1` based on the value at `t`.
+///
+/// This is the same as [`hermite_quat`], but for large worlds that need double precision.
+///
+/// When `t` is `0.0`, the result will be equal to `q0`. When `t` is `1.0`, the result will be equal to `q1`.
+#[cfg(feature = "f64")]
+pub fn hermite_dquat(qa: DQuat, qb: DQuat, w0: DVec3, w1: DVec3, t: f64, unwrap: bool) -> DQuat {
+    use core::f64::consts::TAU;
+
+    let t2 = t * t;
+    let t3 = t * t2;
+
+    // Cumulative Bernstein basis polynomials
+    let b1 = 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t);
+    let b2 = 3.0 * t2 - 2.0 * t3;
+    let b3 = t3;
+
+    let w0_div_3 = w0 / 3.0;
+    let w1_div_3 = w1 / 3.0;
+
+    // Advance by a third from initial rotation, with initial velocity.
+    let q1 = DQuat::from_scaled_axis(w0_div_3) * qa;
+
+    // Back off by a third from final rotation, with final velocity.
+    let q2 = DQuat::from_scaled_axis(-w1_div_3) * qb;
+
+    // Calculate fractional rotation needed to go from q0 to q1.
+    let mut w01_div_3 = (q2 * q1.inverse()).to_scaled_axis();
+
+    // Add multiples of 2π to the magnitude of w01 / 3 to minimize
+    // its distance to the average of w0 / 3 and w1 / 3.
+    if unwrap {
+        let average_w_div_3 = w0_div_3.midpoint(w1_div_3);
+        let w01_direction = w01_div_3.normalize_or_zero();
+        let extra_angle = w01_direction.dot(average_w_div_3 - w01_div_3);
+        w01_div_3 += (extra_angle / TAU).round() * TAU * w01_direction;
+    }
+
+    DQuat::from_scaled_axis(b3 * w1_div_3)
+        * DQuat::from_scaled_axis(b2 * w01_div_3)
+        * DQuat::from_scaled_axis(b1 * w0_div_3)
+        * qa
+}
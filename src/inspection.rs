@@ -0,0 +1,101 @@
+//! Read-only access to the easing progress of entities, for example for editor tooling and inspectors.
+//!
+//! See [`EasingInfo`] and [`easing_progress`] for more information.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{EasingOverstep, RotationEasingState, ScaleEasingState, TranslationEasingState};
+
+/// A snapshot of the easing progress of an entity.
+///
+/// Returned by [`EasingInfo::get`] and [`easing_progress`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EasingSnapshot {
+    /// The translation easing state of the entity, if it has one.
+    pub translation: Option<TranslationEasingState>,
+    /// The rotation easing state of the entity, if it has one.
+    pub rotation: Option<RotationEasingState>,
+    /// The scale easing state of the entity, if it has one.
+    pub scale: Option<ScaleEasingState>,
+    /// The interpolation alpha used for easing during the current frame.
+    ///
+    /// This is the same value as the [`EasingOverstep`] resource.
+    pub alpha: f32,
+}
+
+/// A [`SystemParam`] for reading the easing progress of entities.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{inspection::EasingInfo, prelude::*};
+///
+/// fn inspect_easing(easing_info: EasingInfo, query: Query<Entity, With<TransformInterpolation>>) {
+///     for entity in &query {
+///         if let Some(snapshot) = easing_info.get(entity) {
+///             info!("{entity}: alpha {}, translation {:?}", snapshot.alpha, snapshot.translation);
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct EasingInfo<'w, 's> {
+    states: Query<
+        'w,
+        's,
+        (
+            Option<&'static TranslationEasingState>,
+            Option<&'static RotationEasingState>,
+            Option<&'static ScaleEasingState>,
+        ),
+    >,
+    overstep: Res<'w, EasingOverstep>,
+}
+
+impl EasingInfo<'_, '_> {
+    /// Returns the easing progress of the given entity, or `None` if it has no easing states.
+    pub fn get(&self, entity: Entity) -> Option<EasingSnapshot> {
+        let (translation, rotation, scale) = self.states.get(entity).ok()?;
+        snapshot(
+            translation.copied(),
+            rotation.copied(),
+            scale.copied(),
+            self.overstep.0,
+        )
+    }
+}
+
+/// Returns the easing progress of the given entity, or `None` if it has no easing states.
+///
+/// See [`EasingInfo`] for a [`SystemParam`] version of this.
+pub fn easing_progress(entity: Entity, world: &World) -> Option<EasingSnapshot> {
+    let entity = world.get_entity(entity).ok()?;
+    let alpha = world
+        .get_resource::<EasingOverstep>()
+        .map_or(0.0, |overstep| overstep.0);
+    snapshot(
+        entity.get::<TranslationEasingState>().copied(),
+        entity.get::<RotationEasingState>().copied(),
+        entity.get::<ScaleEasingState>().copied(),
+        alpha,
+    )
+}
+
+fn snapshot(
+    translation: Option<TranslationEasingState>,
+    rotation: Option<RotationEasingState>,
+    scale: Option<ScaleEasingState>,
+    alpha: f32,
+) -> Option<EasingSnapshot> {
+    if translation.is_none() && rotation.is_none() && scale.is_none() {
+        return None;
+    }
+
+    Some(EasingSnapshot {
+        translation,
+        rotation,
+        scale,
+        alpha,
+    })
+}
@@ -6,9 +6,23 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    prelude::*, RotationEasingState, ScaleEasingState, TransformEasingSet, TranslationEasingState,
+    ease_rotation, ease_scale, ease_translation, easing_alpha, for_each_mut, has_easing_entities,
+    prelude::*,
+    restore_and_reset_rotation, restore_and_reset_scale, restore_and_reset_translation,
+    target::{CustomEaseTarget, EaseTarget},
+    EasingSchedules, FixedState, RotationEasingState, ScaleEasingState, TransformEasingSet,
+    TranslationEasingState,
+};
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        query::QueryFilter,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        world::DeferredWorld,
+    },
+    prelude::*,
+    transform::TransformSystem,
 };
-use bevy::prelude::*;
 
 /// A plugin for [`Transform`] interpolation, making movement in [`FixedUpdate`] appear smooth.
 ///
@@ -80,6 +94,7 @@ use bevy::prelude::*;
 ///           interpolate_translation_all: true,
 ///           interpolate_rotation_all: true,
 ///           interpolate_scale_all: false,
+///           ..default()
 ///       })
 ///       // ...
 ///       .run();
@@ -90,13 +105,63 @@ use bevy::prelude::*;
 /// by adding the [`NoTransformEasing`] component, or the individual [`NoTranslationEasing`], [`NoRotationEasing`],
 /// and [`NoScaleEasing`] components.
 ///
+/// Adding these to every camera or UI node can be tedious. Instead, whole groups of entities can be excluded
+/// with [`TransformInterpolationPlugin::exclude`], which inserts [`NoTransformEasing`] for interpolated entities
+/// that match the given [`QueryFilter`]. Removing [`NoTransformEasing`] from such an entity has no lasting effect,
+/// as it is inserted again as long as the entity matches the filter.
+///
+/// Entities that match the filter when interpolation is added, such as cameras spawned with [`interpolate_all`],
+/// are excluded immediately, so they are never eased. Entities that only start matching the filter later
+/// are excluded right before easing in the same frame.
+///
+/// [`interpolate_all`]: TransformInterpolationPlugin::interpolate_all
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_transform_interpolation::prelude::*;
+/// #
+/// fn main() {
+///    App::new()
+///       .add_plugins(
+///           TransformInterpolationPlugin::interpolate_all()
+///               .exclude::<With<Camera>>()
+///               .exclude::<With<Node>>(),
+///       )
+///       // ...
+///       .run();
+/// }
+/// ```
+///
 /// Note that changing [`Transform`] manually in any schedule that *doesn't* use a fixed timestep is also supported,
 /// but it is equivalent to teleporting, and disables interpolation for the entity for the remainder of that fixed timestep.
+/// To teleport an entity explicitly, use the [`TeleportAndReset`] command or [`EasingCommandsExt::teleport_no_ease`].
+/// To instead ease towards the new [`Transform`], add [`TransformEasingMode::EaseToTarget`] to the entity.
 ///
+/// The easing states inserted for entities are initialized lazily when they are first observed
+/// at the start of a fixed timestep, not when the components are inserted. Entities can therefore be spawned
+/// and positioned freely before the first fixed timestep, such as in [`Startup`], without being eased
+/// from their initial [`Transform`] or triggering a reset.
+///
+/// [`TransformEasingMode::EaseToTarget`]: crate::TransformEasingMode::EaseToTarget
 /// [`interpolate_translation_all`]: TransformInterpolationPlugin::interpolate_translation_all
 /// [`interpolate_rotation_all`]: TransformInterpolationPlugin::interpolate_rotation_all
 /// [`interpolate_scale_all`]: TransformInterpolationPlugin::interpolate_scale_all
 ///
+/// # Hierarchies
+///
+/// By default, interpolation is performed in the local space of each entity. When both a parent
+/// and its child move in [`FixedUpdate`], the eased local transforms are combined through the hierarchy,
+/// which can make the motion of the child look wrong, for example when the parent is rotating.
+///
+/// If [`interpolate_in_global_space`] is enabled, the `start` and `end` of interpolation for child entities
+/// are instead stored in world space in a [`GlobalSpaceEasingState`], and the local [`Transform`] is reconstructed
+/// after easing relative to the eased transform of the parent. Changing the parent of an entity resets its easing.
+///
+/// The space can also be chosen explicitly for individual entities with the [`EasingSpace`] component,
+/// which overrides [`interpolate_in_global_space`].
+///
+/// [`interpolate_in_global_space`]: TransformInterpolationPlugin::interpolate_in_global_space
+///
 /// # Alternatives
 ///
 /// For games where low latency is crucial for gameplay, such as in some first-person shooters
@@ -129,8 +194,27 @@ pub struct TransformInterpolationPlugin {
     ///
     /// This can be overridden for individual entities by adding the [`NoScaleEasing`] or [`NoTransformEasing`] component.
     pub interpolate_scale_all: bool,
+    /// If `true`, interpolation for entities with a [`Parent`] is performed in world space
+    /// instead of local space. See the [Hierarchies](TransformInterpolationPlugin#hierarchies) section
+    /// for more information.
+    ///
+    /// Default: `false`
+    pub interpolate_in_global_space: bool,
+    /// The schedules that the interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// The filters for excluding entities from interpolation, added with [`TransformInterpolationPlugin::exclude`].
+    ///
+    /// Default: empty
+    pub exclusion_filters: ExclusionFilters,
 }
 
+/// The [`QueryFilter`]s for excluding entities from interpolation, added with [`TransformInterpolationPlugin::exclude`].
+#[derive(Clone, Debug, Default)]
+pub struct ExclusionFilters(Vec<fn(&mut App, InternedScheduleLabel)>);
+
 impl TransformInterpolationPlugin {
     /// Enables interpolation for translation, rotation, and scale for all entities with the [`Transform`] component.
     ///
@@ -141,25 +225,78 @@ impl TransformInterpolationPlugin {
             interpolate_translation_all: true,
             interpolate_rotation_all: true,
             interpolate_scale_all: true,
+            interpolate_in_global_space: false,
+            schedules: EasingSchedules::DEFAULT,
+            exclusion_filters: ExclusionFilters(Vec::new()),
+        }
+    }
+
+    /// Enables interpolation for translation and rotation for all entities with the [`Transform`] component,
+    /// but not for scale. Scale is rarely changed in [`FixedUpdate`], so this avoids unnecessary work.
+    ///
+    /// This can be overridden for individual entities by adding the [`NoTransformEasing`] component,
+    /// or the individual [`NoTranslationEasing`] and [`NoRotationEasing`] components.
+    /// Scale interpolation can still be enabled for individual entities with [`ScaleInterpolation`].
+    pub const fn interpolate_all_but_scale() -> Self {
+        Self {
+            interpolate_translation_all: true,
+            interpolate_rotation_all: true,
+            interpolate_scale_all: false,
+            interpolate_in_global_space: false,
+            schedules: EasingSchedules::DEFAULT,
+            exclusion_filters: ExclusionFilters(Vec::new()),
         }
     }
+
+    /// Sets the schedules that the interpolation systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+
+    /// Excludes entities matching the given [`QueryFilter`] from interpolation by inserting
+    /// the [`NoTransformEasing`] component for them.
+    ///
+    /// This is mainly useful together with global interpolation, such as [`interpolate_all`],
+    /// to exclude entities like cameras and UI nodes that should never be interpolated.
+    ///
+    /// See the [`TransformInterpolationPlugin`] for more information.
+    ///
+    /// [`interpolate_all`]: TransformInterpolationPlugin::interpolate_all
+    pub fn exclude<F: QueryFilter + 'static>(mut self) -> Self {
+        self.exclusion_filters.0.push(add_exclusion_filter::<F>);
+        self
+    }
 }
 
 impl Plugin for TransformInterpolationPlugin {
     fn build(&self, app: &mut App) {
+        let fixed_first = self.schedules.fixed_first();
+        let fixed_last = self.schedules.fixed_last();
+        let fixed_main_loop = self.schedules.fixed_main_loop();
+
         // Register components.
         app.register_type::<(
+            TransformInterpolation,
             TranslationInterpolation,
             RotationInterpolation,
             ScaleInterpolation,
         )>();
 
         app.add_systems(
-            FixedFirst,
+            fixed_first,
             (
-                complete_translation_easing,
-                complete_rotation_easing,
-                complete_scale_easing,
+                complete_translation_easing::<Transform, Without<CustomEaseTarget>>,
+                complete_rotation_easing::<Transform, Without<CustomEaseTarget>>,
+                complete_scale_easing::<Transform, Without<CustomEaseTarget>>,
             )
                 .chain()
                 .before(TransformEasingSet::Reset),
@@ -167,11 +304,11 @@ impl Plugin for TransformInterpolationPlugin {
 
         // Update the start state of the interpolation at the start of the fixed timestep.
         app.add_systems(
-            FixedFirst,
+            fixed_first,
             (
-                update_translation_interpolation_start,
-                update_rotation_interpolation_start,
-                update_scale_interpolation_start,
+                update_translation_interpolation_start::<Transform, Without<CustomEaseTarget>>,
+                update_rotation_interpolation_start::<Transform, Without<CustomEaseTarget>>,
+                update_scale_interpolation_start::<Transform, Without<CustomEaseTarget>>,
             )
                 .chain()
                 .in_set(TransformEasingSet::UpdateStart),
@@ -179,11 +316,11 @@ impl Plugin for TransformInterpolationPlugin {
 
         // Update the end state of the interpolation at the end of the fixed timestep.
         app.add_systems(
-            FixedLast,
+            fixed_last,
             (
-                update_translation_interpolation_end,
-                update_rotation_interpolation_end,
-                update_scale_interpolation_end,
+                update_translation_interpolation_end::<Transform, Without<CustomEaseTarget>>,
+                update_rotation_interpolation_end::<Transform, Without<CustomEaseTarget>>,
+                update_scale_interpolation_end::<Transform, Without<CustomEaseTarget>>,
             )
                 .chain()
                 .in_set(TransformEasingSet::UpdateEnd),
@@ -200,16 +337,145 @@ impl Plugin for TransformInterpolationPlugin {
         if self.interpolate_scale_all {
             let _ = app.try_register_required_components::<Transform, ScaleInterpolation>();
         }
+
+        for add_filter in &self.exclusion_filters.0 {
+            add_filter(app, fixed_main_loop);
+        }
+
+        // Interpolate `GlobalTransform` directly for entities with `GlobalTransformInterpolation`.
+        app.register_type::<(GlobalTransformInterpolation, GlobalTransformEasingState)>();
+        app.add_systems(
+            fixed_first,
+            (
+                complete_global_transform_easing.before(TransformEasingSet::Reset),
+                update_global_transform_interpolation_start.in_set(TransformEasingSet::UpdateStart),
+            ),
+        );
+        app.add_systems(
+            fixed_last,
+            update_global_transform_interpolation_end.in_set(TransformEasingSet::UpdateEnd),
+        );
+        // Transform propagation runs after the default easing schedule, so global transforms
+        // are eased in `PostUpdate` unless easing runs in a custom schedule.
+        let global_transform_schedule = if fixed_main_loop == RunFixedMainLoop.intern() {
+            PostUpdate.intern()
+        } else {
+            fixed_main_loop
+        };
+        app.add_systems(
+            global_transform_schedule,
+            ease_global_transform_interpolation
+                .after(TransformSystem::TransformPropagate)
+                .run_if(resource_equals(EasingPaused(false)))
+                .run_if(resource_equals(EasingAutoDisabled(false)))
+                .run_if(has_easing_entities),
+        );
+
+        // Interpolate child entities in world space if enabled globally or with `EasingSpace::World`.
+        app.register_type::<(GlobalSpaceEasingState, EasingSpace)>();
+
+        if self.interpolate_in_global_space {
+            let _ = app.try_register_required_components::<TranslationInterpolation, GlobalSpaceEasingState>();
+            let _ = app
+                .try_register_required_components::<RotationInterpolation, GlobalSpaceEasingState>(
+                );
+            let _ = app
+                .try_register_required_components::<ScaleInterpolation, GlobalSpaceEasingState>();
+        }
+
+        app.add_systems(
+            fixed_first,
+            reset_global_space_easing.in_set(TransformEasingSet::Reset),
+        );
+        app.add_systems(
+            fixed_first,
+            update_global_space_interpolation_start.in_set(TransformEasingSet::UpdateStart),
+        );
+        app.add_systems(
+            fixed_last,
+            update_global_space_interpolation_end.in_set(TransformEasingSet::UpdateEnd),
+        );
+
+        // Reset easing when the parent changes, and reconstruct the local transforms
+        // of child entities after easing.
+        app.add_systems(
+            fixed_main_loop,
+            (
+                reset_easing_on_parent_change.before(TransformEasingSet::Ease),
+                ease_global_space_interpolation
+                    .after(TransformEasingSet::Ease)
+                    .before(TransformEasingSet::UpdateEasingTick)
+                    .run_if(resource_equals(EasingPaused(false)))
+                    .run_if(resource_equals(EasingAutoDisabled(false))),
+            )
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+        );
     }
 
     fn finish(&self, app: &mut App) {
         // Add the `TransformEasingPlugin` if it hasn't been added yet.
         if !app.is_plugin_added::<TransformEasingPlugin>() {
-            app.add_plugins(TransformEasingPlugin);
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
         }
     }
 }
 
+/// Adds the observer and system for excluding entities matching the given [`QueryFilter`] from interpolation.
+fn add_exclusion_filter<F: QueryFilter + 'static>(
+    app: &mut App,
+    fixed_main_loop: InternedScheduleLabel,
+) {
+    app.add_observer(exclude_on_interpolation_added::<F>);
+    app.add_systems(
+        fixed_main_loop,
+        exclude_from_interpolation::<F>.before(TransformEasingSet::Ease),
+    );
+}
+
+/// Inserts [`NoTransformEasing`] for entities that match the given [`QueryFilter`] as soon as interpolation is added,
+/// so that they are never eased.
+fn exclude_on_interpolation_added<F: QueryFilter + 'static>(
+    trigger: Trigger<
+        OnAdd,
+        (
+            TranslationInterpolation,
+            RotationInterpolation,
+            ScaleInterpolation,
+        ),
+    >,
+    mut commands: Commands,
+    query: Query<(), (F, Without<NoTransformEasing>)>,
+) {
+    let entity = trigger.entity();
+    if query.contains(entity) {
+        commands.entity(entity).insert(NoTransformEasing);
+    }
+}
+
+/// Inserts [`NoTransformEasing`] for interpolated entities that match the given [`QueryFilter`].
+fn exclude_from_interpolation<F: QueryFilter + 'static>(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            F,
+            Without<NoTransformEasing>,
+            Or<(
+                With<TranslationInterpolation>,
+                With<RotationInterpolation>,
+                With<ScaleInterpolation>,
+            )>,
+        ),
+    >,
+) {
+    for entity in &query {
+        commands.entity(entity).insert(NoTransformEasing);
+    }
+}
+
 /// Enables full [`Transform`] interpolation for an entity, making changes to translation,
 /// rotation, and scale in [`FixedUpdate`] appear smooth.
 ///
@@ -224,6 +490,7 @@ pub struct TransformInterpolation;
 ///
 /// See the [`TransformInterpolationPlugin`] for more information.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_remove = on_remove_translation_interpolation)]
 #[reflect(Component, Debug, Default)]
 #[require(TranslationEasingState)]
 pub struct TranslationInterpolation;
@@ -233,6 +500,7 @@ pub struct TranslationInterpolation;
 ///
 /// See the [`TransformInterpolationPlugin`] for more information.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_remove = on_remove_rotation_interpolation)]
 #[reflect(Component, Debug, Default)]
 #[require(RotationEasingState)]
 pub struct RotationInterpolation;
@@ -242,117 +510,750 @@ pub struct RotationInterpolation;
 ///
 /// See the [`TransformInterpolationPlugin`] for more information.
 #[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_remove = on_remove_scale_interpolation)]
 #[reflect(Component, Debug, Default)]
 #[require(ScaleEasingState)]
 pub struct ScaleInterpolation;
 
-/// Makes sure the previous translation easing is fully applied before the next easing starts.
-fn complete_translation_easing(
+/// Stores the world space start and end states used for interpolating child entities
+/// when [`TransformInterpolationPlugin::interpolate_in_global_space`] is enabled.
+///
+/// This is inserted automatically for interpolated entities when global space interpolation is enabled,
+/// and for entities with an [`EasingSpace`], but it is only used for entities with a [`Parent`]
+/// that are interpolated in [`EasingSpace::World`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct GlobalSpaceEasingState {
+    /// The start transform for the interpolation in world space.
+    pub start: Option<GlobalTransform>,
+    /// The end transform for the interpolation in world space.
+    pub end: Option<GlobalTransform>,
+}
+
+/// Controls the space in which the `start` and `end` of interpolation are recorded for an entity,
+/// and how the eased [`Transform`] is reconstructed.
+///
+/// If an entity has no [`EasingSpace`], it is interpolated in [`EasingSpace::World`] if
+/// [`TransformInterpolationPlugin::interpolate_in_global_space`] is enabled, and in [`EasingSpace::Local`] otherwise.
+/// The space only matters for entities with a [`Parent`].
+///
+/// See the [Hierarchies](TransformInterpolationPlugin#hierarchies) section for more information.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let platform = commands
+///         .spawn((Transform::default(), TransformInterpolation))
+///         .id();
+///
+///     // The turret aims relative to the platform, so it is eased in the frame of the platform.
+///     commands
+///         .spawn((
+///             Transform::from_xyz(0.0, 1.0, 0.0),
+///             TransformInterpolation,
+///             EasingSpace::Parent,
+///         ))
+///         .set_parent(platform);
+/// }
+/// ```
+///
+/// # Ordering
+///
+/// For [`EasingSpace::World`], the world space transforms are computed from the local [`Transform`]s
+/// of the entity and its ancestors, not from the [`GlobalTransform`], so they don't depend on
+/// transform propagation. The local [`Transform`] is reconstructed after [`TransformEasingSet::Ease`],
+/// from the root of the hierarchy downwards, relative to the already eased transforms of the ancestors.
+///
+/// Systems that move ancestors after easing, such as camera follow systems, are not taken into account
+/// for the current frame. They should run before [`TransformEasingSet::Ease`] or only move entities
+/// without interpolated descendants in world space. Bevy's [`TransformSystem::TransformPropagate`]
+/// runs after all easing, so the eased transforms are propagated normally.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+#[require(GlobalSpaceEasingState)]
+pub enum EasingSpace {
+    /// The local [`Transform`] is eased directly, ignoring the hierarchy.
+    ///
+    /// This is the cheapest option. Changing the [`Parent`] does not reset easing,
+    /// so the entity may briefly ease between the old and new local transforms.
+    #[default]
+    Local,
+    /// The local [`Transform`] is eased in the frame of the [`Parent`], like [`EasingSpace::Local`],
+    /// but easing is reset when the [`Parent`] is changed or removed, since the previous
+    /// local transforms were relative to the old parent.
+    ///
+    /// This is useful for entities attached to a moving parent, such as a turret on a platform,
+    /// whose own movement should stay relative to the parent.
+    Parent,
+    /// The `start` and `end` are recorded in world space, and the local [`Transform`] is reconstructed
+    /// relative to the eased transform of the [`Parent`] after easing.
+    ///
+    /// This prevents the motion of the parent from distorting the eased motion of the child,
+    /// for example when the parent is rotating. Changing the [`Parent`] resets easing.
+    World,
+}
+
+/// Returns `true` if the entity should be interpolated in world space.
+///
+/// Entities with a [`GlobalSpaceEasingState`] but no [`EasingSpace`] use the global space interpolation
+/// enabled for the plugin.
+fn is_world_space(space: Option<&EasingSpace>) -> bool {
+    space.is_none_or(|space| *space == EasingSpace::World)
+}
+
+/// Enables interpolation of the [`GlobalTransform`] of an entity directly, making changes
+/// to it in [`FixedUpdate`] appear smooth.
+///
+/// This is intended for entities whose [`GlobalTransform`] is set manually, such as for instanced rendering
+/// or custom transform propagation, and that have no meaningful local [`Transform`]. For other entities,
+/// use [`TransformInterpolation`] instead.
+///
+/// The `start` and `end` of interpolation are read from the [`GlobalTransform`] at the start and end
+/// of the fixed timestep, and stored in the [`GlobalTransformEasingState`]. Translation and scale
+/// are eased with `lerp`, and rotation with `slerp`.
+///
+/// # Ordering
+///
+/// Unlike other easing, the [`GlobalTransform`] is eased in [`PostUpdate`], right after [`TransformSystem::TransformPropagate`].
+/// This way, Bevy's transform propagation does not overwrite the eased value, in case the entity also
+/// has a [`Transform`] that changed during the frame. Systems that read the [`GlobalTransform`] in [`PostUpdate`],
+/// such as visibility and bounds calculations, should run after this to see the eased value.
+///
+/// If easing is configured to run in a custom schedule with [`EasingSchedules`], the [`GlobalTransform`]
+/// is eased in that schedule instead, still ordered after [`TransformSystem::TransformPropagate`].
+///
+/// Before the next fixed timestep, the [`GlobalTransform`] is reset to the `end` of the previous easing,
+/// so that systems in [`FixedUpdate`] see the non-eased value.
+///
+/// Like other easing, the [`EasingFlags`] of the entity control which properties of the [`GlobalTransform`]
+/// are eased. Properties with easing disabled keep the value computed by transform propagation.
+///
+/// See the [`TransformInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(GlobalTransformEasingState)]
+pub struct GlobalTransformInterpolation;
+
+/// Stores the start and end states used for interpolating the [`GlobalTransform`] of entities
+/// with [`GlobalTransformInterpolation`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct GlobalTransformEasingState {
+    /// The start global transform for the interpolation.
+    pub start: Option<GlobalTransform>,
+    /// The end global transform for the interpolation.
+    pub end: Option<GlobalTransform>,
+}
+
+/// Applies the `end` of the translation easing and resets the easing state when [`TranslationInterpolation`]
+/// is removed, so that switching to another easing method at runtime doesn't leave stale state behind.
+fn on_remove_translation_interpolation(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    restore_and_reset_translation(&mut world, entity, FixedState::End);
+}
+
+/// Applies the `end` of the rotation easing and resets the easing state when [`RotationInterpolation`]
+/// is removed, so that switching to another easing method at runtime doesn't leave stale state behind.
+fn on_remove_rotation_interpolation(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    restore_and_reset_rotation(&mut world, entity, FixedState::End);
+}
+
+/// Applies the `end` of the scale easing and resets the easing state when [`ScaleInterpolation`]
+/// is removed, so that switching to another easing method at runtime doesn't leave stale state behind.
+fn on_remove_scale_interpolation(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    restore_and_reset_scale(&mut world, entity, FixedState::End);
+}
+
+/// Makes sure the previous translation easing of the [`EaseTarget`] `T` is fully applied before the next easing starts.
+pub(crate) fn complete_translation_easing<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&mut Transform, &TranslationEasingState),
-        (With<TranslationInterpolation>, Without<NoTranslationEasing>),
+        (
+            &mut T,
+            &TranslationEasingState,
+            Option<&TranslationEasingMask>,
+        ),
+        (
+            F,
+            With<TranslationInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
     >,
 ) {
-    for (mut transform, easing) in &mut query {
+    for (mut target, easing, mask) in &mut query {
         // Make sure the previous easing is fully applied.
         if let Some(end) = easing.end {
-            transform.translation = end;
+            let translation = match mask {
+                Some(mask) => Vec3::select(mask.0, end, target.translation()),
+                None => end,
+            };
+            target.set_translation(translation);
         }
     }
 }
 
-/// Makes sure the previous rotation easing is fully applied before the next easing starts.
-fn complete_rotation_easing(
+/// Makes sure the previous rotation easing of the [`EaseTarget`] `T` is fully applied before the next easing starts.
+pub(crate) fn complete_rotation_easing<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&mut Transform, &RotationEasingState),
-        (With<RotationInterpolation>, Without<NoRotationEasing>),
+        (&mut T, &RotationEasingState),
+        (F, With<RotationInterpolation>, Without<NoRotationEasing>),
     >,
 ) {
-    for (mut transform, easing) in &mut query {
+    for (mut target, easing) in &mut query {
         // Make sure the previous easing is fully applied.
         if let Some(end) = easing.end {
-            transform.rotation = end;
+            target.set_rotation(end);
         }
     }
 }
 
-/// Makes sure the previous scale easing is fully applied before the next easing starts.
-fn complete_scale_easing(
+/// Makes sure the previous scale easing of the [`EaseTarget`] `T` is fully applied before the next easing starts.
+pub(crate) fn complete_scale_easing<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&mut Transform, &ScaleEasingState),
-        (With<ScaleInterpolation>, Without<NoScaleEasing>),
+        (&mut T, &ScaleEasingState),
+        (F, With<ScaleInterpolation>, Without<NoScaleEasing>),
     >,
 ) {
-    for (mut transform, easing) in &mut query {
+    for (mut target, easing) in &mut query {
         // Make sure the previous easing is fully applied.
         if let Some(end) = easing.end {
-            transform.scale = end;
+            target.set_scale(end);
         }
     }
 }
 
-fn update_translation_interpolation_start(
+pub(crate) fn update_translation_interpolation_start<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut TranslationEasingState),
-        (With<TranslationInterpolation>, Without<NoTranslationEasing>),
+        (&T, &mut TranslationEasingState),
+        (
+            F,
+            With<TranslationInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.start = Some(transform.translation);
+    for (target, mut easing) in &mut query {
+        easing.start = Some(target.translation());
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(target.translation());
+        }
     }
 }
 
-fn update_translation_interpolation_end(
+pub(crate) fn update_translation_interpolation_end<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut TranslationEasingState),
-        (With<TranslationInterpolation>, Without<NoTranslationEasing>),
+        (&T, &mut TranslationEasingState),
+        (
+            F,
+            With<TranslationInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.end = Some(transform.translation);
+    for (target, mut easing) in &mut query {
+        easing.end = Some(target.translation());
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(target.translation());
     }
 }
 
-fn update_rotation_interpolation_start(
+pub(crate) fn update_rotation_interpolation_start<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut RotationEasingState),
-        (With<RotationInterpolation>, Without<NoRotationEasing>),
+        (&T, &mut RotationEasingState),
+        (F, With<RotationInterpolation>, Without<NoRotationEasing>),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.start = Some(transform.rotation);
+    for (target, mut easing) in &mut query {
+        easing.start = Some(target.rotation());
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(target.rotation());
+        }
     }
 }
 
-fn update_rotation_interpolation_end(
+pub(crate) fn update_rotation_interpolation_end<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut RotationEasingState),
-        (With<RotationInterpolation>, Without<NoRotationEasing>),
+        (&T, &mut RotationEasingState),
+        (F, With<RotationInterpolation>, Without<NoRotationEasing>),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.end = Some(transform.rotation);
+    for (target, mut easing) in &mut query {
+        easing.end = Some(target.rotation());
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(target.rotation());
     }
 }
 
-fn update_scale_interpolation_start(
+pub(crate) fn update_scale_interpolation_start<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut ScaleEasingState),
-        (With<ScaleInterpolation>, Without<NoScaleEasing>),
+        (&T, &mut ScaleEasingState),
+        (F, With<ScaleInterpolation>, Without<NoScaleEasing>),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.start = Some(transform.scale);
+    for (target, mut easing) in &mut query {
+        easing.start = Some(target.scale());
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(target.scale());
+        }
     }
 }
 
-fn update_scale_interpolation_end(
+pub(crate) fn update_scale_interpolation_end<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&Transform, &mut ScaleEasingState),
-        (With<ScaleInterpolation>, Without<NoScaleEasing>),
+        (&T, &mut ScaleEasingState),
+        (F, With<ScaleInterpolation>, Without<NoScaleEasing>),
     >,
 ) {
-    for (transform, mut easing) in &mut query {
-        easing.end = Some(transform.scale);
+    for (target, mut easing) in &mut query {
+        easing.end = Some(target.scale());
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(target.scale());
+    }
+}
+
+/// Computes the world space transform of an entity by combining the [`Transform`]s of its ancestors.
+///
+/// Unlike [`GlobalTransform`], this is always up to date, including in the fixed timestep schedules
+/// and right after easing.
+fn compute_global_transform(
+    entity: Entity,
+    get: impl Fn(Entity) -> Option<(Transform, Option<Entity>)>,
+) -> GlobalTransform {
+    let Some((transform, mut parent)) = get(entity) else {
+        return GlobalTransform::IDENTITY;
+    };
+
+    let mut global = GlobalTransform::from(transform);
+    while let Some((parent_transform, grandparent)) = parent.and_then(&get) {
+        global = GlobalTransform::from(parent_transform) * global;
+        parent = grandparent;
+    }
+    global
+}
+
+fn reset_global_space_easing(mut query: Query<&mut GlobalSpaceEasingState>) {
+    for mut easing in &mut query {
+        easing.start = None;
+        easing.end = None;
+    }
+}
+
+fn update_global_space_interpolation_start(
+    mut query: Query<(Entity, &mut GlobalSpaceEasingState, Option<&EasingSpace>), With<Parent>>,
+    transforms: Query<(&Transform, Option<&Parent>)>,
+) {
+    for (entity, mut easing, space) in &mut query {
+        if !is_world_space(space) {
+            continue;
+        }
+
+        easing.start = Some(compute_global_transform(entity, |entity| {
+            let (transform, parent) = transforms.get(entity).ok()?;
+            Some((*transform, parent.map(|parent| parent.get())))
+        }));
+    }
+}
+
+fn update_global_space_interpolation_end(
+    mut query: Query<(Entity, &mut GlobalSpaceEasingState, Option<&EasingSpace>), With<Parent>>,
+    transforms: Query<(&Transform, Option<&Parent>)>,
+) {
+    for (entity, mut easing, space) in &mut query {
+        if !is_world_space(space) {
+            continue;
+        }
+
+        let end = compute_global_transform(entity, |entity| {
+            let (transform, parent) = transforms.get(entity).ok()?;
+            Some((*transform, parent.map(|parent| parent.get())))
+        });
+        easing.end = Some(end);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(end);
+    }
+}
+
+/// Resets the easing states of entities whose [`Parent`] was changed or removed,
+/// since the previous states are relative to the old parent.
+///
+/// Entities with [`EasingSpace::Local`] and easing states that were added along with the parent are not reset.
+fn reset_easing_on_parent_change(
+    mut query: Query<(
+        &mut GlobalSpaceEasingState,
+        Option<&mut TranslationEasingState>,
+        Option<&mut RotationEasingState>,
+        Option<&mut ScaleEasingState>,
+        Option<&EasingSpace>,
+    )>,
+    changed: Query<Entity, (Changed<Parent>, With<GlobalSpaceEasingState>)>,
+    mut removed: RemovedComponents<Parent>,
+) {
+    for entity in changed.iter().chain(removed.read()) {
+        let Ok((mut global_easing, translation_easing, rotation_easing, scale_easing, space)) =
+            query.get_mut(entity)
+        else {
+            continue;
+        };
+        // Easing states that were inserted along with the parent, such as for newly spawned children,
+        // are already relative to the new parent.
+        if space == Some(&EasingSpace::Local) || global_easing.is_added() {
+            continue;
+        }
+
+        *global_easing = GlobalSpaceEasingState::default();
+        if let Some(mut translation_easing) = translation_easing {
+            *translation_easing = TranslationEasingState::default();
+        }
+        if let Some(mut rotation_easing) = rotation_easing {
+            *rotation_easing = RotationEasingState::default();
+        }
+        if let Some(mut scale_easing) = scale_easing {
+            *scale_easing = ScaleEasingState::default();
+        }
     }
 }
+
+/// Eases child entities with [`EasingSpace::World`] in world space, and reconstructs their local [`Transform`]
+/// relative to the eased transform of the parent.
+///
+/// Entities are processed from the root of the hierarchy downwards, so that the eased transforms
+/// of ancestors are always applied before their descendants. The order is cached, and only recomputed
+/// when the hierarchy changes.
+#[allow(clippy::too_many_arguments)]
+fn ease_global_space_interpolation(
+    query: Query<
+        (
+            Entity,
+            &GlobalSpaceEasingState,
+            (
+                Option<&TranslationEasingState>,
+                Option<&RotationEasingState>,
+                Option<&ScaleEasingState>,
+            ),
+            (
+                Has<TranslationInterpolation>,
+                Has<RotationInterpolation>,
+                Has<ScaleInterpolation>,
+            ),
+            (Option<&EasingFlags>, Option<&EasingSpace>),
+            (
+                Option<&TransformEasingCurve>,
+                Option<&EasingTimeScale>,
+                Option<&EasingStrength>,
+            ),
+            (
+                Option<&RotationEasingMode>,
+                Option<&CustomRotationInterpolator>,
+            ),
+            (
+                Option<&TranslationEasingMask>,
+                Option<&WrappingTranslation>,
+                Has<ScaleSignSnap>,
+            ),
+        ),
+        (
+            With<Parent>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    mut transforms: Query<(&mut Transform, Option<&Parent>)>,
+    hierarchy: Query<Entity, (With<GlobalSpaceEasingState>, With<Parent>)>,
+    added_states: Query<(), (Added<GlobalSpaceEasingState>, With<Parent>)>,
+    changed_parents: Query<(), Changed<Parent>>,
+    mut removed_parents: RemovedComponents<Parent>,
+    mut removed_states: RemovedComponents<GlobalSpaceEasingState>,
+    mut order: Local<Vec<Entity>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    let get = |transforms: &Query<(&mut Transform, Option<&Parent>)>, entity: Entity| {
+        let (transform, parent) = transforms.get(entity).ok()?;
+        Some((*transform, parent.map(|parent| parent.get())))
+    };
+
+    // Sort entities by their depth in the hierarchy when the hierarchy changes.
+    // The removed components are always read, so that the same removals are not seen again on the next run.
+    let removed_parent = removed_parents.read().count() > 0;
+    let removed_state = removed_states.read().count() > 0;
+    let hierarchy_changed =
+        removed_parent || removed_state || !added_states.is_empty() || !changed_parents.is_empty();
+
+    if hierarchy_changed {
+        let mut entities: Vec<(usize, Entity)> = hierarchy
+            .iter()
+            .map(|entity| {
+                let mut depth = 0;
+                let mut current = get(&transforms, entity).and_then(|(_, parent)| parent);
+                while let Some(parent) = current {
+                    depth += 1;
+                    current = get(&transforms, parent).and_then(|(_, parent)| parent);
+                }
+                (depth, entity)
+            })
+            .collect();
+        entities.sort_unstable_by_key(|(depth, _)| *depth);
+
+        order.clear();
+        order.extend(entities.into_iter().map(|(_, entity)| entity));
+    }
+
+    for &entity in order.iter() {
+        let Ok((
+            _,
+            global_easing,
+            (translation_easing, rotation_easing, scale_easing),
+            (has_translation, has_rotation, has_scale),
+            (flags, space),
+            (curve, time_scale, strength),
+            (mode, interpolator),
+            (mask, wrapping, sign_snap),
+        )) = query.get(entity)
+        else {
+            continue;
+        };
+        if !is_world_space(space) {
+            continue;
+        }
+        let (Some(start), Some(end)) = (global_easing.start, global_easing.end) else {
+            continue;
+        };
+        let Some((_, Some(parent))) = get(&transforms, entity) else {
+            continue;
+        };
+
+        // Only ease properties that are being interpolated and enabled, and that haven't been reset
+        // due to a teleport or opting out of easing.
+        let flags = flags.copied().unwrap_or_default();
+        let ease_translation = has_translation
+            && flags.translation
+            && translation_easing
+                .is_some_and(|easing| easing.start.is_some() && easing.end.is_some());
+        let ease_rotation = has_rotation
+            && flags.rotation
+            && rotation_easing.is_some_and(|easing| easing.start.is_some() && easing.end.is_some());
+        let ease_scale = has_scale
+            && flags.scale
+            && scale_easing.is_some_and(|easing| easing.start.is_some() && easing.end.is_some());
+
+        if !ease_translation && !ease_rotation && !ease_scale {
+            continue;
+        }
+
+        let (start_scale, start_rotation, start_translation) =
+            start.to_scale_rotation_translation();
+        let (end_scale, end_rotation, end_translation) = end.to_scale_rotation_translation();
+
+        // Use the same easing math as for local space interpolation.
+        let alpha = crate::easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+        let eased_global = GlobalTransform::from(Transform {
+            translation: crate::ease_translation(
+                start_translation,
+                end_translation,
+                alpha,
+                wrapping,
+                strength,
+            ),
+            rotation: crate::ease_rotation(
+                start_rotation,
+                end_rotation,
+                alpha,
+                mode,
+                interpolator,
+                strength,
+            ),
+            scale: crate::ease_scale(start_scale, end_scale, alpha, strength, sign_snap),
+        });
+
+        // The parent has already been eased at this point.
+        let parent_global = compute_global_transform(parent, |entity| get(&transforms, entity));
+        let local = eased_global.reparented_to(&parent_global);
+
+        let Ok((mut transform, _)) = transforms.get_mut(entity) else {
+            continue;
+        };
+
+        // Only ease the masked axes of the local translation.
+        let translation = match mask {
+            Some(mask) => Vec3::select(mask.0, local.translation, transform.translation),
+            None => local.translation,
+        };
+
+        // Only write when the values change to avoid triggering change detection.
+        if ease_translation && transform.translation != translation {
+            transform.translation = translation;
+        }
+        if ease_rotation && transform.rotation != local.rotation {
+            transform.rotation = local.rotation;
+        }
+        if ease_scale && transform.scale != local.scale {
+            transform.scale = local.scale;
+        }
+    }
+}
+
+/// Makes sure the previous global transform easing is fully applied before the next easing starts.
+fn complete_global_transform_easing(
+    mut query: Query<
+        (&mut GlobalTransform, &GlobalTransformEasingState),
+        (
+            With<GlobalTransformInterpolation>,
+            Without<NoTransformEasing>,
+        ),
+    >,
+) {
+    for (mut global_transform, easing) in &mut query {
+        if let Some(end) = easing.end {
+            global_transform.set_if_neq(end);
+        }
+    }
+}
+
+fn update_global_transform_interpolation_start(
+    mut query: Query<
+        (&GlobalTransform, &mut GlobalTransformEasingState),
+        (
+            With<GlobalTransformInterpolation>,
+            Without<NoTransformEasing>,
+        ),
+    >,
+) {
+    for (global_transform, mut easing) in &mut query {
+        easing.start = Some(*global_transform);
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(*global_transform);
+        }
+    }
+}
+
+fn update_global_transform_interpolation_end(
+    mut query: Query<
+        (&GlobalTransform, &mut GlobalTransformEasingState),
+        (
+            With<GlobalTransformInterpolation>,
+            Without<NoTransformEasing>,
+        ),
+    >,
+) {
+    for (global_transform, mut easing) in &mut query {
+        easing.end = Some(*global_transform);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(*global_transform);
+    }
+}
+
+/// Eases the [`GlobalTransform`] of entities with [`GlobalTransformInterpolation`].
+///
+/// Like the [`Transform`] easing, this takes the [`TransformEasingCurve`], [`EasingTimeScale`],
+/// and [`EasingStrength`] of an entity into account, along with its rotation easing configuration.
+fn ease_global_transform_interpolation(
+    mut query: Query<
+        (
+            &mut GlobalTransform,
+            &GlobalTransformEasingState,
+            (
+                Option<&RotationEasingMode>,
+                Option<&CustomRotationInterpolator>,
+            ),
+            (
+                Option<&TransformEasingCurve>,
+                Option<&EasingTimeScale>,
+                Option<&EasingStrength>,
+                Option<&EasingFlags>,
+            ),
+        ),
+        (
+            With<GlobalTransformInterpolation>,
+            Without<NoTransformEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    for_each_mut(
+        &mut query,
+        |(
+            mut global_transform,
+            easing,
+            (mode, interpolator),
+            (curve, time_scale, strength, flags),
+        )| {
+            let (Some(start), Some(end)) = (easing.start, easing.end) else {
+                return;
+            };
+
+            let flags = flags.copied().unwrap_or_default();
+            if !flags.translation && !flags.rotation && !flags.scale {
+                return;
+            }
+
+            let (start_scale, start_rotation, start_translation) =
+                start.to_scale_rotation_translation();
+            let (end_scale, end_rotation, end_translation) = end.to_scale_rotation_translation();
+            let alpha = easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+
+            // Properties with easing disabled keep their propagated value.
+            let current = global_transform.compute_transform();
+            let eased = GlobalTransform::from(Transform {
+                translation: if flags.translation {
+                    ease_translation(start_translation, end_translation, alpha, None, strength)
+                } else {
+                    current.translation
+                },
+                rotation: if flags.rotation {
+                    ease_rotation(
+                        start_rotation,
+                        end_rotation,
+                        alpha,
+                        mode,
+                        interpolator,
+                        strength,
+                    )
+                } else {
+                    current.rotation
+                },
+                scale: if flags.scale {
+                    ease_scale(start_scale, end_scale, alpha, strength, false)
+                } else {
+                    current.scale
+                },
+            });
+
+            // Only write when the value changes to avoid triggering change detection.
+            global_transform.set_if_neq(eased);
+        },
+    );
+}
@@ -0,0 +1,548 @@
+//! Memory-efficient [`Transform`] interpolation for 2D entities, storing only the X and Y
+//! coordinates of the translation and the rotation angle around the Z axis.
+//!
+//! See the [`Transform2dInterpolationPlugin`] for more information.
+
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    correction::CorrectionBlend, for_each_mut, is_user_change, EasingCulled, EasingFlags,
+    EasingOverstep, EasingResetEvent, EasingResetReason, EasingSchedules, EasingTick,
+    EasingTimeScale, KeepEasingOnChange, LastEasingTick, NoRotationEasing, NoTranslationEasing,
+    OverstepClamp, SkipEasingOnce, TeleportEpsilon, TransformEasingCurve, TransformEasingPlugin,
+    TransformEasingSet,
+};
+use bevy::{
+    ecs::{schedule::ScheduleLabel, system::SystemChangeTick},
+    prelude::*,
+};
+use core::f32::consts::{PI, TAU};
+
+/// A plugin for [`Transform`] interpolation of 2D entities, making movement in [`FixedUpdate`] appear smooth
+/// with smaller easing states than the [`TransformInterpolationPlugin`](crate::interpolation::TransformInterpolationPlugin).
+///
+/// Interpolation can be enabled for an entity with the [`Transform2dInterpolation`] component,
+/// or the individual [`Translation2dInterpolation`] and [`Rotation2dInterpolation`] components.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::interpolation_2d::*;
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(Transform2dInterpolationPlugin::default());
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Transform::default(), Transform2dInterpolation));
+/// }
+/// ```
+///
+/// # Memory Usage
+///
+/// The regular easing states store a full [`Vec3`] and [`Quat`] for both the `start` and `end` of easing.
+/// For 2D entities, only the X and Y coordinates and the angle around the Z axis matter, so
+/// [`Translation2dEasingState`] stores [`Vec2`] values and [`Rotation2dEasingState`] stores `f32` angles.
+///
+/// This reduces the size of the translation state from 32 to 24 bytes and the size of the rotation state
+/// from up to 64 bytes (with SIMD-aligned quaternions) to 16 bytes. For large 2D scenes with tens of thousands
+/// of interpolated entities, this reduces memory usage and improves cache efficiency for the easing systems.
+///
+/// # Rotation
+///
+/// Rotation is eased by interpolating the angle directly. When the states are updated from [`Transform`],
+/// the `end` angle is unwrapped relative to the `start` angle, so easing takes the shortest path,
+/// like `slerp` for 3D rotations.
+///
+/// Unlike quaternions, angles can also represent rotations of more than half a revolution.
+/// If the `start` and `end` are instead written directly with unwrapped angles, for example from a physics engine
+/// that stores the total angle, easing follows the full rotation instead of flipping to the other direction.
+///
+/// # Caveats
+///
+/// The Z coordinate of the translation and the rotation around the X and Y axes are not eased,
+/// and keep the current value of the [`Transform`]. Scale is also not eased by this plugin.
+///
+/// The 2D easing states are separate from [`TranslationEasingState`](crate::TranslationEasingState) and
+/// [`RotationEasingState`](crate::RotationEasingState), so an entity should not use both 2D and 3D interpolation
+/// for the same property.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+#[derive(Debug)]
+pub struct Transform2dInterpolationPlugin {
+    /// The schedules that the interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for Transform2dInterpolationPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl Transform2dInterpolationPlugin {
+    /// Sets the schedules that the interpolation systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for Transform2dInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        let fixed_first = self.schedules.fixed_first();
+        let fixed_last = self.schedules.fixed_last();
+        let fixed_main_loop = self.schedules.fixed_main_loop();
+
+        // Register components.
+        app.register_type::<(
+            Translation2dEasingState,
+            Rotation2dEasingState,
+            Transform2dInterpolation,
+            Translation2dInterpolation,
+            Rotation2dInterpolation,
+        )>();
+
+        // Make sure the previous easing is fully applied before the next easing starts.
+        app.add_systems(
+            fixed_first,
+            (complete_translation_2d_easing, complete_rotation_2d_easing)
+                .chain()
+                .before(TransformEasingSet::Reset),
+        );
+
+        // Reset the easing states.
+        app.add_systems(
+            fixed_first,
+            (reset_translation_2d_easing, reset_rotation_2d_easing)
+                .chain()
+                .in_set(TransformEasingSet::Reset),
+        );
+
+        // Update the start state of the interpolation at the start of the fixed timestep.
+        app.add_systems(
+            fixed_first,
+            (
+                update_translation_2d_interpolation_start,
+                update_rotation_2d_interpolation_start,
+            )
+                .chain()
+                .in_set(TransformEasingSet::UpdateStart),
+        );
+
+        // Update the end state of the interpolation at the end of the fixed timestep.
+        app.add_systems(
+            fixed_last,
+            (
+                update_translation_2d_interpolation_end,
+                update_rotation_2d_interpolation_end,
+            )
+                .chain()
+                .in_set(TransformEasingSet::UpdateEnd),
+        );
+
+        // Reset easing when the transform is changed outside of the fixed timestep, and perform easing.
+        app.add_systems(
+            fixed_main_loop,
+            reset_2d_easing_on_transform_change.before(TransformEasingSet::Ease),
+        );
+        app.add_systems(
+            fixed_main_loop,
+            (ease_translation_2d_lerp, ease_rotation_2d_lerp).in_set(TransformEasingSet::Ease),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Enables interpolation of the X and Y coordinates of the translation
+/// and the rotation around the Z axis for this entity.
+///
+/// See the [`Transform2dInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(Translation2dInterpolation, Rotation2dInterpolation)]
+pub struct Transform2dInterpolation;
+
+/// Enables interpolation of the X and Y coordinates of the translation for this entity.
+///
+/// See the [`Transform2dInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(Translation2dEasingState)]
+pub struct Translation2dInterpolation;
+
+/// Enables interpolation of the rotation around the Z axis for this entity.
+///
+/// See the [`Transform2dInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(Rotation2dEasingState)]
+pub struct Rotation2dInterpolation;
+
+/// Stores the start and end states used for interpolating the X and Y coordinates of the translation of an entity.
+///
+/// The 2D counterpart of [`TranslationEasingState`](crate::TranslationEasingState).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(EasingTick)]
+pub struct Translation2dEasingState {
+    /// The start translation for the interpolation.
+    pub start: Option<Vec2>,
+    /// The end translation for the interpolation.
+    pub end: Option<Vec2>,
+}
+
+/// Stores the start and end angles in radians used for interpolating the rotation around the Z axis of an entity.
+///
+/// The 2D counterpart of [`RotationEasingState`](crate::RotationEasingState).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(EasingTick)]
+pub struct Rotation2dEasingState {
+    /// The start angle for the interpolation.
+    pub start: Option<f32>,
+    /// The end angle for the interpolation.
+    pub end: Option<f32>,
+}
+
+/// Returns the rotation angle of the given rotation around the Z axis.
+fn rotation_angle(rotation: Quat) -> f32 {
+    rotation.to_euler(EulerRot::ZYX).0
+}
+
+/// Returns the given angle unwrapped relative to `reference`,
+/// such that the difference between the two is in the range `[-PI, PI]`.
+fn unwrap_angle(angle: f32, reference: f32) -> f32 {
+    reference + (angle - reference + PI).rem_euclid(TAU) - PI
+}
+
+/// Returns the given rotation with its rotation around the Z axis replaced by the given angle.
+fn with_rotation_angle(rotation: Quat, angle: f32) -> Quat {
+    let (_, y, x) = rotation.to_euler(EulerRot::ZYX);
+    Quat::from_euler(EulerRot::ZYX, angle, y, x)
+}
+
+/// Returns the angle of the [`Transform`] unwrapped relative to the given reference angle, if any.
+fn transform_angle(transform: &Transform, reference: Option<f32>) -> f32 {
+    let angle = rotation_angle(transform.rotation);
+    reference.map_or(angle, |reference| unwrap_angle(angle, reference))
+}
+
+/// Makes sure the previous translation easing is fully applied before the next easing starts.
+fn complete_translation_2d_easing(
+    mut query: Query<
+        (&mut Transform, &Translation2dEasingState),
+        (
+            With<Translation2dInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
+    >,
+) {
+    for (mut transform, easing) in &mut query {
+        if let Some(end) = easing.end {
+            transform.translation = end.extend(transform.translation.z);
+        }
+    }
+}
+
+/// Makes sure the previous rotation easing is fully applied before the next easing starts.
+fn complete_rotation_2d_easing(
+    mut query: Query<
+        (&mut Transform, &Rotation2dEasingState),
+        (With<Rotation2dInterpolation>, Without<NoRotationEasing>),
+    >,
+) {
+    for (mut transform, easing) in &mut query {
+        if let Some(end) = easing.end {
+            transform.rotation = with_rotation_angle(transform.rotation, end);
+        }
+    }
+}
+
+/// Resets the `start` and `end` states for 2D translation interpolation.
+fn reset_translation_2d_easing(mut query: Query<&mut Translation2dEasingState>) {
+    for mut easing in &mut query {
+        easing.start = None;
+        easing.end = None;
+    }
+}
+
+/// Resets the `start` and `end` states for 2D rotation interpolation.
+fn reset_rotation_2d_easing(mut query: Query<&mut Rotation2dEasingState>) {
+    for mut easing in &mut query {
+        easing.start = None;
+        easing.end = None;
+    }
+}
+
+fn update_translation_2d_interpolation_start(
+    mut query: Query<
+        (&Transform, &mut Translation2dEasingState),
+        (
+            With<Translation2dInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
+    >,
+) {
+    for (transform, mut easing) in &mut query {
+        easing.start = Some(transform.translation.truncate());
+    }
+}
+
+fn update_translation_2d_interpolation_end(
+    mut query: Query<
+        (&Transform, &mut Translation2dEasingState),
+        (
+            With<Translation2dInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
+    >,
+) {
+    for (transform, mut easing) in &mut query {
+        let translation = transform.translation.truncate();
+        easing.end = Some(translation);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(translation);
+    }
+}
+
+fn update_rotation_2d_interpolation_start(
+    mut query: Query<
+        (&Transform, &mut Rotation2dEasingState),
+        (With<Rotation2dInterpolation>, Without<NoRotationEasing>),
+    >,
+) {
+    for (transform, mut easing) in &mut query {
+        easing.start = Some(rotation_angle(transform.rotation));
+    }
+}
+
+fn update_rotation_2d_interpolation_end(
+    mut query: Query<
+        (&Transform, &mut Rotation2dEasingState),
+        (With<Rotation2dInterpolation>, Without<NoRotationEasing>),
+    >,
+) {
+    for (transform, mut easing) in &mut query {
+        // Unwrap the angle relative to the start to take the shortest path.
+        let angle = transform_angle(transform, easing.start);
+        easing.end = Some(angle);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(angle);
+    }
+}
+
+/// Resets the 2D easing states of entities whose [`Transform`] was changed outside of the fixed timestep,
+/// treating the change as a teleport.
+///
+/// The 2D counterpart of [`reset_easing_states_on_transform_change`](crate::reset_easing_states_on_transform_change).
+/// Changes are detected relative to the [`EasingTick`] of each entity, and an [`EasingResetEvent`] is sent
+/// for each reset entity if [`TransformEasingPlugin::emit_reset_events`] is enabled.
+fn reset_2d_easing_on_transform_change(
+    mut query: Query<
+        (
+            Entity,
+            Ref<Transform>,
+            Option<&mut Translation2dEasingState>,
+            Option<&mut Rotation2dEasingState>,
+            Option<&EasingTick>,
+        ),
+        (
+            Changed<Transform>,
+            Or<(With<Translation2dEasingState>, With<Rotation2dEasingState>)>,
+            Without<KeepEasingOnChange>,
+            // The `end` of easing for corrected entities is intentionally offset from the `Transform`.
+            Without<CorrectionBlend>,
+        ),
+    >,
+    last_easing_tick: Res<LastEasingTick>,
+    epsilon: Res<TeleportEpsilon>,
+    system_change_tick: SystemChangeTick,
+    mut reset_events: Option<ResMut<Events<EasingResetEvent>>>,
+) {
+    let this_run = system_change_tick.this_run();
+
+    for (entity, transform, translation_easing, rotation_easing, easing_tick) in &mut query {
+        if !is_user_change(&transform, easing_tick, last_easing_tick.0, this_run) {
+            continue;
+        }
+
+        let mut was_reset = false;
+
+        // Easing states that were inserted along with the transform, for example when a scene is loaded,
+        // already match the new transform, so the change is not a teleport.
+        let translation_easing = translation_easing.filter(|easing| !easing.is_added());
+        let rotation_easing = rotation_easing.filter(|easing| !easing.is_added());
+
+        if let Some(mut easing) = translation_easing {
+            if let (Some(start), Some(end)) = (easing.start, easing.end) {
+                let translation = transform.translation;
+                let differs = |value: Vec2| {
+                    epsilon.translation_differs(
+                        translation,
+                        value.extend(translation.z),
+                        BVec3::TRUE,
+                    )
+                };
+                if differs(start) && differs(end) {
+                    easing.start = None;
+                    easing.end = None;
+                    was_reset = true;
+                }
+            }
+        }
+        if let Some(mut easing) = rotation_easing {
+            if let (Some(start), Some(end)) = (easing.start, easing.end) {
+                let angle = rotation_angle(transform.rotation);
+                let differs =
+                    |value: f32| (unwrap_angle(angle, value) - value).abs() > epsilon.rotation;
+                if differs(start) && differs(end) {
+                    easing.start = None;
+                    easing.end = None;
+                    was_reset = true;
+                }
+            }
+        }
+
+        if let (true, Some(reset_events)) = (was_reset, reset_events.as_mut()) {
+            reset_events.send(EasingResetEvent {
+                entity,
+                reason: EasingResetReason::UserTransformChange,
+            });
+        }
+    }
+}
+
+/// Eases the X and Y coordinates of the translations of entities with linear interpolation.
+fn ease_translation_2d_lerp(
+    mut query: Query<
+        (
+            &mut Transform,
+            &Translation2dEasingState,
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            Without<NoTranslationEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, easing, curve, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.translation) {
+                return;
+            }
+            let (Some(start), Some(end)) = (easing.start, easing.end) else {
+                return;
+            };
+            if start == end {
+                // Avoid dirtying `Transform` for stationary entities.
+                return;
+            }
+
+            let overstep = time_scale.map_or(overstep, |time_scale| {
+                time_scale.apply(overstep, &overstep_clamp)
+            });
+            let alpha = curve.map_or(overstep, |curve| curve.sample(overstep));
+            let translation = start.lerp(end, alpha).extend(transform.translation.z);
+
+            // Only write when the value changes to avoid triggering change detection.
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        },
+    );
+}
+
+/// Eases the rotation angles of entities around the Z axis with linear interpolation.
+fn ease_rotation_2d_lerp(
+    mut query: Query<
+        (
+            &mut Transform,
+            &Rotation2dEasingState,
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            Without<NoRotationEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, easing, curve, time_scale, flags)| {
+            if flags.is_some_and(|flags| !flags.rotation) {
+                return;
+            }
+            let (Some(start), Some(end)) = (easing.start, easing.end) else {
+                return;
+            };
+            if start == end {
+                // Avoid dirtying `Transform` for stationary entities.
+                return;
+            }
+
+            let overstep = time_scale.map_or(overstep, |time_scale| {
+                time_scale.apply(overstep, &overstep_clamp)
+            });
+            let alpha = curve.map_or(overstep, |curve| curve.sample(overstep));
+            let angle = start + (end - start) * alpha;
+            let rotation = if transform.rotation.x == 0.0 && transform.rotation.y == 0.0 {
+                // Fast path for rotations purely around the Z axis.
+                Quat::from_rotation_z(angle)
+            } else {
+                with_rotation_angle(transform.rotation, angle)
+            };
+
+            // Only write when the value changes to avoid triggering change detection.
+            if transform.rotation != rotation {
+                transform.rotation = rotation;
+            }
+        },
+    );
+}
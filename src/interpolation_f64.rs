@@ -0,0 +1,529 @@
+//! Double-precision interpolation for large worlds, storing the easing states as [`DVec3`] and [`DQuat`]
+//! and easing into a high-precision transform component.
+//!
+//! See the [`TransformF64InterpolationPlugin`] for more information.
+
+#![allow(clippy::type_complexity)]
+
+use core::marker::PhantomData;
+
+use crate::{
+    easing_alpha, for_each_mut, EasingCulled, EasingFlags, EasingOverstep, EasingSchedules,
+    EasingTimeScale, NoRotationEasing, NoScaleEasing, NoTranslationEasing, OverstepClamp,
+    SkipEasingOnce, TransformEasingCurve, TransformEasingPlugin, TransformEasingSet,
+};
+use bevy::{
+    ecs::schedule::ScheduleLabel,
+    math::{DQuat, DVec3},
+    prelude::*,
+};
+
+/// A component that stores a double-precision translation, rotation, and scale that easing can be applied to.
+///
+/// This is the double-precision counterpart of [`EaseTarget`](crate::target::EaseTarget),
+/// used by the [`TransformF64InterpolationPlugin`].
+///
+/// Components without a scale can rely on the default implementations of [`scale`](EaseTargetF64::scale)
+/// and [`set_scale`](EaseTargetF64::set_scale), which treat the scale as always being [`DVec3::ONE`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::{
+///     math::{DQuat, DVec3},
+///     prelude::*,
+/// };
+/// use bevy_transform_interpolation::interpolation_f64::EaseTargetF64;
+///
+/// #[derive(Component)]
+/// struct DoubleTransform {
+///     translation: DVec3,
+///     rotation: DQuat,
+/// }
+///
+/// impl EaseTargetF64 for DoubleTransform {
+///     fn translation(&self) -> DVec3 {
+///         self.translation
+///     }
+///
+///     fn set_translation(&mut self, translation: DVec3) {
+///         self.translation = translation;
+///     }
+///
+///     fn rotation(&self) -> DQuat {
+///         self.rotation
+///     }
+///
+///     fn set_rotation(&mut self, rotation: DQuat) {
+///         self.rotation = rotation;
+///     }
+/// }
+/// ```
+pub trait EaseTargetF64: Component {
+    /// Returns the translation.
+    fn translation(&self) -> DVec3;
+
+    /// Sets the translation.
+    fn set_translation(&mut self, translation: DVec3);
+
+    /// Returns the rotation.
+    fn rotation(&self) -> DQuat;
+
+    /// Sets the rotation.
+    fn set_rotation(&mut self, rotation: DQuat);
+
+    /// Returns the scale.
+    ///
+    /// Defaults to [`DVec3::ONE`].
+    fn scale(&self) -> DVec3 {
+        DVec3::ONE
+    }
+
+    /// Sets the scale.
+    ///
+    /// Does nothing by default.
+    fn set_scale(&mut self, _scale: DVec3) {}
+}
+
+/// A plugin for interpolating a double-precision transform component `T` that implements [`EaseTargetF64`],
+/// making movement in [`FixedUpdate`] appear smooth even far away from the origin.
+///
+/// The `start` and `end` of interpolation are read from `T` and stored in the [`TranslationF64EasingState`],
+/// [`RotationF64EasingState`], and [`ScaleF64EasingState`] components, and the eased result is written back to `T`.
+/// Translation and scale are eased with `lerp`, and rotation with `slerp`, all in double precision.
+///
+/// Interpolation is enabled for an entity with the [`TransformF64Interpolation`] component, or the individual
+/// [`TranslationF64Interpolation`], [`RotationF64Interpolation`], and [`ScaleF64Interpolation`] components.
+///
+/// ```
+/// use bevy::{
+///     math::{DQuat, DVec3},
+///     prelude::*,
+/// };
+/// use bevy_transform_interpolation::interpolation_f64::*;
+/// #
+/// # #[derive(Component, Default)]
+/// # struct DoubleTransform {
+/// #     translation: DVec3,
+/// #     rotation: DQuat,
+/// # }
+/// #
+/// # impl EaseTargetF64 for DoubleTransform {
+/// #     fn translation(&self) -> DVec3 {
+/// #         self.translation
+/// #     }
+/// #
+/// #     fn set_translation(&mut self, translation: DVec3) {
+/// #         self.translation = translation;
+/// #     }
+/// #
+/// #     fn rotation(&self) -> DQuat {
+/// #         self.rotation
+/// #     }
+/// #
+/// #     fn set_rotation(&mut self, rotation: DQuat) {
+/// #         self.rotation = rotation;
+/// #     }
+/// # }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(TransformF64InterpolationPlugin::<DoubleTransform>::default());
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((DoubleTransform::default(), TransformF64Interpolation));
+/// }
+/// ```
+///
+/// # Large Worlds
+///
+/// The [`Transform`] itself is single-precision, so the target `T` is typically the authoritative transform
+/// of the simulation, such as the position of a physics engine running in double precision. The eased value
+/// is then converted to a [`Transform`] relative to the camera or a floating origin by a system of the app
+/// that runs after [`TransformEasingSet::Ease`].
+///
+/// For grid-based large worlds such as [`big_space`], where each entity has a grid cell and a [`Transform`]
+/// relative to that cell, `T` can also be implemented for a query-friendly component that stores the absolute
+/// position. [`EaseTargetF64::set_translation`] can then compute the cell and the cell-relative translation
+/// from the eased absolute position. Alternatively, the cell-relative [`Transform`] can be eased normally
+/// with the [`TransformInterpolationPlugin`], treating cell changes as teleports.
+///
+/// [`big_space`]: https://github.com/aevyrie/big_space
+/// [`TransformInterpolationPlugin`]: crate::interpolation::TransformInterpolationPlugin
+///
+/// # Caveats
+///
+/// Only the core interpolation is supported, including the [`TransformEasingCurve`], [`EasingTimeScale`],
+/// and [`EasingFlags`] of an entity. Extrapolation, Hermite interpolation, and other features that operate
+/// on [`Transform`] are not applied. For custom double-precision easing backends, the [`hermite_dvec3`]
+/// and [`hermite_dquat`] functions can be used.
+///
+/// Changes to `T` outside of the fixed timestep are not detected as teleports,
+/// so the easing should be reset manually, such as with [`ResetEasing`].
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+///
+/// [`hermite_dvec3`]: crate::hermite::hermite_dvec3
+/// [`hermite_dquat`]: crate::hermite::hermite_dquat
+/// [`ResetEasing`]: crate::commands::ResetEasing
+#[derive(Debug)]
+pub struct TransformF64InterpolationPlugin<T: EaseTargetF64> {
+    /// The schedules that the interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<T>,
+}
+
+impl<T: EaseTargetF64> Default for TransformF64InterpolationPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: EaseTargetF64> TransformF64InterpolationPlugin<T> {
+    /// Sets the schedules that the interpolation systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<T: EaseTargetF64> Plugin for TransformF64InterpolationPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let fixed_first = self.schedules.fixed_first();
+        let fixed_last = self.schedules.fixed_last();
+        let fixed_main_loop = self.schedules.fixed_main_loop();
+
+        // Register components.
+        app.register_type::<(
+            TranslationF64EasingState,
+            RotationF64EasingState,
+            ScaleF64EasingState,
+            TransformF64Interpolation,
+            TranslationF64Interpolation,
+            RotationF64Interpolation,
+            ScaleF64Interpolation,
+        )>();
+
+        // Make sure the previous easing is fully applied before the next easing starts.
+        app.add_systems(
+            fixed_first,
+            complete_f64_easing::<T>.before(TransformEasingSet::Reset),
+        );
+
+        // Reset the easing states.
+        app.add_systems(
+            fixed_first,
+            reset_f64_easing::<T>.in_set(TransformEasingSet::Reset),
+        );
+
+        // Update the start state of the interpolation at the start of the fixed timestep.
+        app.add_systems(
+            fixed_first,
+            update_f64_interpolation_start::<T>.in_set(TransformEasingSet::UpdateStart),
+        );
+
+        // Update the end state of the interpolation at the end of the fixed timestep.
+        app.add_systems(
+            fixed_last,
+            update_f64_interpolation_end::<T>.in_set(TransformEasingSet::UpdateEnd),
+        );
+
+        // Perform easing.
+        app.add_systems(
+            fixed_main_loop,
+            ease_f64::<T>.in_set(TransformEasingSet::Ease),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Enables double-precision interpolation of the translation, rotation, and scale for this entity.
+///
+/// See the [`TransformF64InterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(
+    TranslationF64Interpolation,
+    RotationF64Interpolation,
+    ScaleF64Interpolation
+)]
+pub struct TransformF64Interpolation;
+
+/// Enables double-precision interpolation of the translation for this entity.
+///
+/// See the [`TransformF64InterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationF64EasingState)]
+pub struct TranslationF64Interpolation;
+
+/// Enables double-precision interpolation of the rotation for this entity.
+///
+/// See the [`TransformF64InterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(RotationF64EasingState)]
+pub struct RotationF64Interpolation;
+
+/// Enables double-precision interpolation of the scale for this entity.
+///
+/// See the [`TransformF64InterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(ScaleF64EasingState)]
+pub struct ScaleF64Interpolation;
+
+/// Stores the start and end states used for interpolating the translation of an entity in double precision.
+///
+/// The double-precision counterpart of [`TranslationEasingState`](crate::TranslationEasingState).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct TranslationF64EasingState {
+    /// The start translation for the interpolation.
+    pub start: Option<DVec3>,
+    /// The end translation for the interpolation.
+    pub end: Option<DVec3>,
+}
+
+/// Stores the start and end states used for interpolating the rotation of an entity in double precision.
+///
+/// The double-precision counterpart of [`RotationEasingState`](crate::RotationEasingState).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct RotationF64EasingState {
+    /// The start rotation for the interpolation.
+    pub start: Option<DQuat>,
+    /// The end rotation for the interpolation.
+    pub end: Option<DQuat>,
+}
+
+/// Stores the start and end states used for interpolating the scale of an entity in double precision.
+///
+/// The double-precision counterpart of [`ScaleEasingState`](crate::ScaleEasingState).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct ScaleF64EasingState {
+    /// The start scale for the interpolation.
+    pub start: Option<DVec3>,
+    /// The end scale for the interpolation.
+    pub end: Option<DVec3>,
+}
+
+/// Makes sure the previous easing of the target `T` is fully applied before the next easing starts.
+fn complete_f64_easing<T: EaseTargetF64>(
+    mut query: Query<(
+        &mut T,
+        Option<&TranslationF64EasingState>,
+        Option<&RotationF64EasingState>,
+        Option<&ScaleF64EasingState>,
+    )>,
+) {
+    for (mut target, translation_easing, rotation_easing, scale_easing) in &mut query {
+        if let Some(end) = translation_easing.and_then(|easing| easing.end) {
+            target.set_translation(end);
+        }
+        if let Some(end) = rotation_easing.and_then(|easing| easing.end) {
+            target.set_rotation(end);
+        }
+        if let Some(end) = scale_easing.and_then(|easing| easing.end) {
+            target.set_scale(end);
+        }
+    }
+}
+
+/// Resets the `start` and `end` states for double-precision interpolation of the target `T`.
+fn reset_f64_easing<T: EaseTargetF64>(
+    mut query: Query<
+        (
+            Option<&mut TranslationF64EasingState>,
+            Option<&mut RotationF64EasingState>,
+            Option<&mut ScaleF64EasingState>,
+        ),
+        With<T>,
+    >,
+) {
+    for (translation_easing, rotation_easing, scale_easing) in &mut query {
+        if let Some(mut easing) = translation_easing {
+            easing.start = None;
+            easing.end = None;
+        }
+        if let Some(mut easing) = rotation_easing {
+            easing.start = None;
+            easing.end = None;
+        }
+        if let Some(mut easing) = scale_easing {
+            easing.start = None;
+            easing.end = None;
+        }
+    }
+}
+
+fn update_f64_interpolation_start<T: EaseTargetF64>(
+    mut query: Query<(
+        &T,
+        Option<&mut TranslationF64EasingState>,
+        Option<&mut RotationF64EasingState>,
+        Option<&mut ScaleF64EasingState>,
+        (
+            Has<NoTranslationEasing>,
+            Has<NoRotationEasing>,
+            Has<NoScaleEasing>,
+        ),
+    )>,
+) {
+    for (target, translation_easing, rotation_easing, scale_easing, disabled) in &mut query {
+        let (no_translation, no_rotation, no_scale) = disabled;
+
+        if let Some(mut easing) = translation_easing.filter(|_| !no_translation) {
+            easing.start = Some(target.translation());
+        }
+        if let Some(mut easing) = rotation_easing.filter(|_| !no_rotation) {
+            easing.start = Some(target.rotation());
+        }
+        if let Some(mut easing) = scale_easing.filter(|_| !no_scale) {
+            easing.start = Some(target.scale());
+        }
+    }
+}
+
+fn update_f64_interpolation_end<T: EaseTargetF64>(
+    mut query: Query<(
+        &T,
+        Option<&mut TranslationF64EasingState>,
+        Option<&mut RotationF64EasingState>,
+        Option<&mut ScaleF64EasingState>,
+        (
+            Has<NoTranslationEasing>,
+            Has<NoRotationEasing>,
+            Has<NoScaleEasing>,
+        ),
+    )>,
+) {
+    for (target, translation_easing, rotation_easing, scale_easing, disabled) in &mut query {
+        let (no_translation, no_rotation, no_scale) = disabled;
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        if let Some(mut easing) = translation_easing.filter(|_| !no_translation) {
+            let translation = target.translation();
+            easing.end = Some(translation);
+            easing.start.get_or_insert(translation);
+        }
+        if let Some(mut easing) = rotation_easing.filter(|_| !no_rotation) {
+            let rotation = target.rotation();
+            easing.end = Some(rotation);
+            easing.start.get_or_insert(rotation);
+        }
+        if let Some(mut easing) = scale_easing.filter(|_| !no_scale) {
+            let scale = target.scale();
+            easing.end = Some(scale);
+            easing.start.get_or_insert(scale);
+        }
+    }
+}
+
+/// Eases the translation, rotation, and scale of the target `T` in double precision,
+/// with `lerp` for translation and scale and `slerp` for rotation.
+fn ease_f64<T: EaseTargetF64>(
+    mut query: Query<
+        (
+            &mut T,
+            (
+                Option<&TranslationF64EasingState>,
+                Option<&RotationF64EasingState>,
+                Option<&ScaleF64EasingState>,
+            ),
+            (
+                Option<&TransformEasingCurve>,
+                Option<&EasingTimeScale>,
+                Option<&EasingFlags>,
+            ),
+            (
+                Has<NoTranslationEasing>,
+                Has<NoRotationEasing>,
+                Has<NoScaleEasing>,
+            ),
+        ),
+        (Without<SkipEasingOnce>, Without<EasingCulled>),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    let overstep = overstep.0;
+
+    for_each_mut(
+        &mut query,
+        |(mut target, states, (curve, time_scale, flags), disabled)| {
+            let (translation_easing, rotation_easing, scale_easing) = states;
+            let (no_translation, no_rotation, no_scale) = disabled;
+            let flags = flags.copied().unwrap_or_default();
+            let alpha = f64::from(easing_alpha(overstep, time_scale, curve, &overstep_clamp));
+
+            if let Some((start, end)) = translation_easing
+                .filter(|_| flags.translation && !no_translation)
+                .and_then(|easing| easing.start.zip(easing.end))
+            {
+                let translation = start.lerp(end, alpha);
+
+                // Only write when the value changes to avoid triggering change detection.
+                if target.translation() != translation {
+                    target.set_translation(translation);
+                }
+            }
+            if let Some((start, end)) = rotation_easing
+                .filter(|_| flags.rotation && !no_rotation)
+                .and_then(|easing| easing.start.zip(easing.end))
+            {
+                let rotation = start.slerp(end, alpha);
+                if target.rotation() != rotation {
+                    target.set_rotation(rotation);
+                }
+            }
+            if let Some((start, end)) = scale_easing
+                .filter(|_| flags.scale && !no_scale)
+                .and_then(|easing| easing.start.zip(easing.end))
+            {
+                let scale = start.lerp(end, alpha);
+                if target.scale() != scale {
+                    target.set_scale(scale);
+                }
+            }
+        },
+    );
+}
@@ -77,9 +77,149 @@
 //!
 //! - Granularly ease individual properties of the transform with [`TranslationInterpolation`], [`RotationInterpolation`], and [`ScaleInterpolation`].
 //! - Opt out of transform easing for individual entities with [`NoTranslationEasing`], [`NoRotationEasing`], and [`NoScaleEasing`].
+//! - Toggle easing at runtime without archetype changes with the [`EasingFlags`] component.
+//! - Teleport entities or control their easing with [commands](commands) such as [`TeleportAndReset`], [`ResetEasing`], [`ResetAllEasing`], [`SetEasing`], [`AdjustEasingEnd`], and [`SetEasingMode`].
+//! - Read the non-eased transform from the last fixed timestep in [`Update`] with the [`FixedTransform`] component.
+//! - Read eased and non-eased transforms together, and compute rendered velocities for motion blur, with the [`EasedTransform`] query data.
+//! - Compute several intermediate eased samples per frame for accumulation-style motion blur with the [`MotionSampleCount`] component.
+//! - Choose whether child entities are eased in local, parent, or world space with the [`EasingSpace`] component.
+//! - Snap the Z sort layer of 2D entities instead of easing it with the [`SnapZLayer`] component.
+//! - Ease across the boundaries of wrapping worlds with the [`WrappingTranslation`] component.
+//! - Flip sprites by negating their scale without squishing them with the [`ScaleSignSnap`] component.
+//! - Offset the rendered transform without affecting gameplay with the [`EasingRenderOffset`] component.
+//! - Ignore tiny floating-point differences when detecting teleports with the [`TeleportEpsilon`] resource.
+//! - Dial the intensity of smoothing between the eased and true transform with the [`EasingStrength`] component.
+//! - Skip easing automatically while the fixed timestep is faster than the frame rate with `TransformEasingPlugin::auto_disable_when_fast`.
+//! - Query entities that are currently being eased with the [`IsEasing`] marker component.
+//! - Pause and resume easing globally without removing components with the [`EasingPaused`] resource.
+//! - Plug in custom rotation interpolation, such as log-quaternion blending, with the [`RotationInterpolator`] trait.
 //! - Use extrapolation instead of interpolation with the [`TransformExtrapolationPlugin`] and its related components.
+//! - Extrapolate entities spinning more than half a turn per fixed timestep with the [`FastSpin`] component.
+//! - Interpolate 2D entities with smaller, angle-based easing states using the `Transform2dInterpolationPlugin` (requires the `2d` feature).
+//! - Interpolate double-precision transforms of large worlds using the `TransformF64InterpolationPlugin` (requires the `f64` feature).
+//! - Use acceleration-aware prediction with the [`TransformPredictionPlugin`] and its related components.
+//! - Interpolate positions stored in components other than [`Transform`] with the [`SourcePositionInterpolationPlugin`].
+//! - Ease into custom transform components of your own or of third-party crates with the [`EaseTarget`] trait and the [`EaseTargetPlugin`].
+//! - Interpolate hundreds of thousands of entities, such as particles, with columnar easing states using the [`BulkEasingPlugin`].
+//! - Ease towards targets that arrive asynchronously with uncertain timing using the [`AsyncTransformEasingPlugin`].
+//! - Interpolate between buffered snapshots with a render delay, such as for networking, using the [`SnapshotInterpolationPlugin`].
+//! - Blend authoritative corrections over several fixed timesteps instead of snapping with the [`CorrectionBlendPlugin`].
+//! - Move entities along paths of waypoints for cutscenes and patrol routes with the [`TransformPathPlugin`].
+//! - Make cameras follow eased entities without compounding lag with the [`CameraFollowPlugin`].
+//! - Smooth transforms towards targets every frame with frame-rate independent exponential smoothing using the [`TransformSmoothingPlugin`].
 //! - Use Hermite interpolation for more natural and accurate movement with the [`TransformHermiteEasingPlugin`].
-//! - Implement custom easing backends for your specific needs.
+//! - Implement custom easing backends for your specific needs with the [`EasingBackend`] trait.
+//! - Record eased transforms for debugging with the [`EasingRecorderPlugin`].
+//! - Inspect the easing progress of entities for editor tooling with the [`EasingInfo`] system parameter.
+//! - Compute eased transforms without mutating components with [`eased_transform`] and [`hermite_eased_transform`].
+//! - Measure easing distances and extrapolation errors with the [`TransformInterpolationDiagnosticsPlugin`].
+//! - Skip easing for entities that are not visible with `TransformEasingPlugin::skip_invisible` (requires the `visibility` feature).
+//! - Visualize easing states with gizmos using the `TransformEasingGizmosPlugin` (requires the `gizmos` feature).
+//! - Test easing behavior headlessly with manually stepped time using `EasingTestApp` (requires the `test-support` feature).
+//! - Interpolate transforms animated by an `AnimationPlayer` in the fixed timestep with the `FixedAnimationPlugin` (requires the `animation` feature).
+//! - Set up easing for the Avian physics engine with the `AvianInterpolationPlugin` of the `avian2d` or `avian3d` module (requires the feature of the same name).
+//! - Set up easing for the Rapier physics engine with the `RapierInterpolationPlugin` of the `rapier2d` or `rapier3d` module (requires the feature of the same name).
+//!
+//! ## Large Worlds
+//!
+//! The easing states store single-precision [`Vec3`] and [`Quat`] values, matching [`Transform`].
+//! For large worlds using a floating origin or grid cells, such as with [`big_space`], the [`Transform`]
+//! is relative to the cell of the entity and therefore already precise. Moving an entity to another cell
+//! changes its [`Transform`] outside of the easing flow, so it should be treated as a teleport,
+//! for example with the [`TeleportAndReset`] command.
+//!
+//! For worlds where the authoritative transform is stored in double precision, such as the position
+//! of a physics engine using `f64`, the `f64` feature adds the `TransformF64InterpolationPlugin`.
+//! It stores the easing states as `DVec3` and `DQuat` values, and writes the eased result back to
+//! any component implementing the `EaseTargetF64` trait. The `hermite` module also provides
+//! the double-precision `hermite_dvec3` and `hermite_dquat` functions for custom easing backends.
+//!
+//! [`big_space`]: https://github.com/aevyrie/big_space
+//!
+//! ## Scenes
+//!
+//! The easing states, such as [`TranslationEasingState`], and the marker and configuration components,
+//! such as [`TransformInterpolation`] and [`EasingStrength`], are registered for reflection, and the easing states
+//! can also be serialized with the `serialize` feature. This makes it possible to save and load scenes
+//! in the middle of easing.
+//!
+//! The [`Transform`] of a loaded entity is the eased transform at the time the scene was saved, and easing
+//! continues from the loaded easing states without being reset, even though the [`Transform`] was changed
+//! outside of the fixed timestep.
+//!
+//! [`TransformEasingCurve`] and [`CustomRotationInterpolator`] are not reflected, because they can store
+//! function pointers and trait objects. They are not saved in scenes, and must be re-inserted after loading.
+//!
+//! ```
+//! # #[cfg(feature = "serialize")]
+//! # {
+//! use bevy::{
+//!     ecs::entity::EntityHashMap,
+//!     prelude::*,
+//!     scene::{serde::SceneDeserializer, DynamicScene},
+//! };
+//! use bevy_transform_interpolation::{prelude::*, TranslationEasingState};
+//! use serde::de::DeserializeSeed;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((TransformEasingPlugin::default(), TransformInterpolationPlugin::default()));
+//! app.register_type::<Transform>();
+//!
+//! // Spawn an entity halfway through easing.
+//! let entity = app
+//!     .world_mut()
+//!     .spawn((
+//!         Transform::from_xyz(0.5, 0.0, 0.0),
+//!         TransformInterpolation,
+//!         TranslationEasingState {
+//!             start: Some(Vec3::ZERO),
+//!             end: Some(Vec3::X),
+//!         },
+//!     ))
+//!     .id();
+//!
+//! // Save the scene.
+//! let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+//! let scene = DynamicScene::from_world(app.world());
+//! let serialized = scene.serialize(&type_registry.read()).unwrap();
+//!
+//! // Load the scene into a new world.
+//! let deserializer = SceneDeserializer {
+//!     type_registry: &type_registry.read(),
+//! };
+//! let mut ron_deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+//! let loaded = deserializer.deserialize(&mut ron_deserializer).unwrap();
+//!
+//! let mut world = World::new();
+//! world.insert_resource(type_registry.clone());
+//! let mut entity_map = EntityHashMap::default();
+//! loaded.write_to_world(&mut world, &mut entity_map).unwrap();
+//!
+//! // The easing state is preserved.
+//! let easing = world.get::<TranslationEasingState>(entity_map[&entity]).unwrap();
+//! assert_eq!(easing.start, Some(Vec3::ZERO));
+//! assert_eq!(easing.end, Some(Vec3::X));
+//! # }
+//! ```
+//!
+//! ## Determinism
+//!
+//! Easing is computed independently for each entity, and the floating-point easing math
+//! produces identical results given identical inputs on the same platform and build.
+//! Parallel iteration therefore doesn't affect the eased transforms, only the order in which they are computed.
+//!
+//! For replays or lockstep setups that additionally require a fixed iteration order,
+//! the `deterministic` feature makes all easing systems iterate serially, even if the `multithreaded` feature is enabled.
+//!
+//! ## `no_std` Support
+//!
+//! This crate currently requires the standard library, because Bevy 0.15 itself does.
+//! Apart from the exceptions below, it only depends on `core` and `alloc`, and all floating-point math
+//! goes through [`bevy::math::ops`], so it is ready for `no_std` once Bevy supports it.
+//!
+//! The only parts that would remain unavailable without the standard library are the CSV and file export
+//! of the [`EasingRecorderPlugin`](recording::EasingRecorderPlugin), [`EasingRecorder::write_csv`](recording::EasingRecorder::write_csv)
+//! and [`ExportEasingRecording`](recording::ExportEasingRecording), which use [`std::io`] and [`std::fs`].
 //!
 //! ## How Does It Work?
 //!
@@ -117,50 +257,189 @@
 //! However, thanks to the modular and flexible architecture, other easing methods can also be used.
 //! The [`TransformHermiteEasingPlugin`] provides an easing backend using Hermite interpolation,
 //! overwriting the linear interpolation for specific entities with the [`NonlinearTranslationEasing`]
-//! and [`NonlinearRotationEasing`] marker components. Custom easing solutions can be implemented using the same pattern.
+//! and [`NonlinearRotationEasing`] marker components. Custom easing solutions can be implemented using the same pattern,
+//! or with the [`EasingBackend`] trait, which sets up the marker components and systems automatically.
+//!
+//! ### Frames With Zero or Multiple Fixed Timesteps
+//!
+//! The fixed timestep is decoupled from the frame rate, so [`FixedMain`](bevy::app::FixedMain) can run zero times
+//! in a frame when the frame rate is high, or several times when it is low. Easing is performed once per frame
+//! in either case:
+//!
+//! - With zero fixed timesteps, the easing states are unchanged, but the overstep accumulated towards the next
+//!   fixed timestep has grown. Easing continues towards `end` with the new [`EasingOverstep`],
+//!   so the entity keeps moving even though no fixed timestep was run.
+//! - With multiple fixed timesteps, the states are overwritten by each timestep, and only the states
+//!   from the last timestep are used for easing. [`FixedTransform`] is also updated by each timestep.
+//!
+//! The [`LastEasingTick`] and per-entity [`EasingTick`] are updated once per frame after easing.
+//! Changes to [`Transform`] made by the fixed timestep schedules since then match the recorded easing states,
+//! so they are not mistaken for user changes, regardless of how many fixed timesteps were run.
+//!
+//! Easing only appears frozen when the overstep does not change, such as when [`Time<Virtual>`] is paused,
+//! or when `start` and `end` are equal, in which case [`Transform`] is not written at all.
+//!
+//! ### Precision Over Long Sessions
+//!
+//! Eased values are only ever used for rendering, and are never fed back into the fixed timestep.
+//! Before the next fixed timestep runs, the [`Transform`] is restored to the exact value it had
+//! at the end of the previous fixed timestep:
+//!
+//! - With interpolation, the `end` of the easing, which was copied from [`Transform`] in [`FixedLast`], is written back in [`FixedFirst`].
+//! - With extrapolation, the `start` of the easing, which was copied from [`Transform`] in [`FixedLast`], is written back in [`FixedFirst`].
+//!
+//! The values are copied bit for bit without any arithmetic, so the result of `start.lerp(end, overstep)`
+//! never accumulates into the simulation, and the fixed timestep sees the same [`Transform`] as it would without easing.
+//! Any floating-point drift over long sessions comes from the simulation itself, not from easing.
+//!
+//! This only holds if nothing else reads the eased [`Transform`] in [`Update`] and writes it back in the fixed timestep.
+//! Use [`FixedTransform`] to read the authoritative value outside of the fixed timestep schedules.
 //!
 //! [`TransformHermiteEasingPlugin`]: crate::hermite::TransformHermiteEasingPlugin
+//! [`EasingBackend`]: crate::backend::EasingBackend
+//! [`EasingRecorderPlugin`]: crate::recording::EasingRecorderPlugin
+//! [`EasingInfo`]: crate::inspection::EasingInfo
+//! [`hermite_eased_transform`]: crate::hermite::hermite_eased_transform
+//! [`TransformInterpolationDiagnosticsPlugin`]: crate::diagnostics::TransformInterpolationDiagnosticsPlugin
+//! [`TeleportAndReset`]: crate::commands::TeleportAndReset
+//! [`ResetEasing`]: crate::commands::ResetEasing
+//! [`ResetAllEasing`]: crate::commands::ResetAllEasing
+//! [`SetEasing`]: crate::commands::SetEasing
+//! [`AdjustEasingEnd`]: crate::commands::AdjustEasingEnd
+//! [`SetEasingMode`]: crate::commands::SetEasingMode
+//! [`TransformPredictionPlugin`]: crate::prediction::TransformPredictionPlugin
+//! [`SourcePositionInterpolationPlugin`]: crate::source::SourcePositionInterpolationPlugin
+//! [`AsyncTransformEasingPlugin`]: crate::async_easing::AsyncTransformEasingPlugin
+//! [`SnapshotInterpolationPlugin`]: crate::snapshot::SnapshotInterpolationPlugin
+//! [`CorrectionBlendPlugin`]: crate::correction::CorrectionBlendPlugin
+//! [`TransformPathPlugin`]: crate::path::TransformPathPlugin
+//! [`CameraFollowPlugin`]: crate::follow::CameraFollowPlugin
+//! [`TransformSmoothingPlugin`]: crate::smoothing::TransformSmoothingPlugin
+//! [`BulkEasingPlugin`]: crate::bulk::BulkEasingPlugin
+//! [`EaseTarget`]: crate::target::EaseTarget
+//! [`EaseTargetPlugin`]: crate::target::EaseTargetPlugin
 
-#![expect(clippy::needless_doctest_main)]
+#![allow(clippy::needless_doctest_main)]
 #![expect(clippy::type_complexity)]
 #![warn(missing_docs)]
 
+extern crate alloc;
+
+// Commands
+pub mod commands;
+
 // Core interpolation and extrapolation plugins
+pub mod bulk;
 pub mod extrapolation;
 pub mod interpolation;
+#[cfg(feature = "2d")]
+pub mod interpolation_2d;
+#[cfg(feature = "f64")]
+pub mod interpolation_f64;
+pub mod prediction;
+pub mod source;
+pub mod target;
+
+// Velocity sources for extrapolation and Hermite interpolation
+pub mod velocity;
 
 // Easing backends
+pub mod backend;
+pub mod winding;
 // TODO: Catmull-Rom (like Hermite interpolation, but velocity is estimated from four points)
 pub mod hermite;
 
+// Easing for asynchronously arriving targets, buffered snapshots, and network corrections
+pub mod async_easing;
+pub mod correction;
+pub mod snapshot;
+
+// Scripted movement along paths, following eased entities, and exponential smoothing
+pub mod follow;
+pub mod path;
+pub mod smoothing;
+
+// Physics engine integrations
+#[cfg(feature = "avian2d")]
+pub mod avian2d;
+#[cfg(feature = "avian3d")]
+pub mod avian3d;
+#[cfg(feature = "rapier2d")]
+pub mod rapier2d;
+#[cfg(feature = "rapier3d")]
+pub mod rapier3d;
+
+// Bevy animation integration
+#[cfg(feature = "animation")]
+pub mod animation;
+
+// Debugging utilities
+pub mod diagnostics;
+#[cfg(feature = "gizmos")]
+pub mod gizmos;
+pub mod inspection;
+pub mod recording;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
 /// The prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(inline)]
     pub use crate::{
+        commands::{
+            AdjustEasingEnd, EasingCommandsExt, EasingMode, ResetAllEasing, ResetEasing, SetEasing,
+            SetEasingMode, TeleportAndReset,
+        },
         extrapolation::*,
         hermite::{
             RotationHermiteEasing, TransformHermiteEasing, TransformHermiteEasingPlugin,
             TranslationHermiteEasing,
         },
         interpolation::*,
-        NoRotationEasing, NoScaleEasing, NoTransformEasing, NoTranslationEasing,
-        TransformEasingPlugin,
+        prediction::{
+            PredictionBlend, RotationPrediction, TransformPrediction, TransformPredictionPlugin,
+            TranslationPrediction,
+        },
+        CustomRotationInterpolator, EasedTransform, EasingAutoDisabled, EasingCompleted,
+        EasingCulled, EasingFlags, EasingOverstep, EasingPaused, EasingRenderOffset,
+        EasingResetEvent, EasingResetReason, EasingStrength, EasingTimeScale, FixedTransform,
+        IsEasing, KeepEasingOnChange, LastEasingAlpha, MaxEasingDistance, MotionSampleCount,
+        MotionSamples, NoRotationEasing, NoScaleEasing, NoTransformEasing, NoTranslationEasing,
+        NonlinearRotationEasing, NonlinearScaleEasing, NonlinearTranslationEasing, OverstepClamp,
+        PreviousEasedTransform, RotationEasingMode, RotationInterpolator, ScaleSignSnap,
+        SkipEasingOnce, SnapZLayer, TeleportEpsilon, TransformEasingCurve, TransformEasingMode,
+        TransformEasingPlugin, TranslationEasingMask, WrappingTranslation,
     };
 }
 
-use std::marker::PhantomData;
+use core::{
+    any::{type_name, TypeId},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use alloc::sync::Arc;
 
 // For doc links.
 #[allow(unused_imports)]
 use extrapolation::*;
 #[allow(unused_imports)]
 use interpolation::*;
+use target::{CustomEaseTarget, EaseTarget};
 
 use bevy::{
-    ecs::{component::Tick, query::QueryData, system::SystemChangeTick},
+    ecs::{
+        component::{ComponentId, Tick},
+        query::{QueryData, QueryFilter, QueryItem},
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::SystemChangeTick,
+        world::DeferredWorld,
+    },
+    math::curve::{Curve, EaseFunction, EasingCurve},
     prelude::*,
+    transform::TransformSystem,
 };
 
 /// A plugin for applying easing to [`Transform`] changes, making movement in [`FixedUpdate`] appear smooth.
@@ -171,12 +450,304 @@ use bevy::{
 ///
 /// To actually perform automatic easing, an easing backend that updates the `start` and `end` states must be used.
 /// The [`TransformInterpolationPlugin`] is provided for transform interpolation, but custom backends can also be implemented.
-#[derive(Debug, Default)]
-pub struct TransformEasingPlugin;
+#[derive(Debug)]
+pub struct TransformEasingPlugin {
+    /// If `true`, the [`TransformEasingSet::Reset`], [`TransformEasingSet::UpdateStart`], [`TransformEasingSet::UpdateEnd`],
+    /// and [`TransformEasingSet::Ease`] system sets are skipped when no entities have easing states.
+    /// This avoids running easing systems every frame in scenes where nothing needs easing.
+    ///
+    /// The run condition is evaluated every time the system sets would run, so entities with easing
+    /// can still be added and removed dynamically.
+    ///
+    /// Default: `true`
+    pub skip_without_easing_entities: bool,
+    /// If `true`, an [`EasingResetEvent`] is sent whenever easing is reset for an entity
+    /// due to a [`Transform`] change outside of the fixed timestep schedules, or due to
+    /// a command such as [`ResetEasing`](commands::ResetEasing).
+    ///
+    /// This can be useful for debugging stutter. When disabled, the events have no cost.
+    ///
+    /// Default: `false`
+    pub emit_reset_events: bool,
+    /// If `true`, an [`EasingCompleted`] event is triggered for each eased entity
+    /// when easing reaches the `end` of the current fixed timestep.
+    ///
+    /// When disabled, the events have no cost.
+    ///
+    /// Default: `false`
+    pub emit_completion_events: bool,
+    /// If `true`, the [`IsEasing`] marker component is maintained for entities
+    /// that are currently being eased.
+    ///
+    /// When disabled, the marker is never inserted, avoiding archetype changes for users that don't need it.
+    ///
+    /// Default: `false`
+    pub track_is_easing: bool,
+    /// If `true`, easing is skipped automatically while the fixed timestep is at least as fast as the frame rate,
+    /// so that entities are rendered at their true transforms without the latency of interpolation.
+    ///
+    /// Easing is enabled again once the fixed timestep becomes slower than the frame rate, for example
+    /// on high refresh rate or variable refresh rate displays. The current state is stored in the
+    /// [`EasingAutoDisabled`] resource.
+    ///
+    /// Default: `false`
+    pub auto_disable_when_fast: bool,
+    /// If `true`, easing is skipped for entities that are not visible to any view,
+    /// based on their [`ViewVisibility`] from the previous frame.
+    ///
+    /// This can save a lot of work in large worlds with many off-screen entities.
+    /// Skipped entities are marked with [`EasingCulled`], and stay at their [`Transform`]
+    /// from the last fixed timestep until they become visible again.
+    ///
+    /// Requires the `visibility` feature.
+    ///
+    /// Default: `false`
+    #[cfg(feature = "visibility")]
+    pub skip_invisible: bool,
+    /// The range that the overstep fraction used for easing is clamped to.
+    ///
+    /// The clamped value is stored in the [`EasingOverstep`] resource, which is used by all easing backends.
+    /// The clamp can be changed at runtime through the [`OverstepClamp`] resource.
+    ///
+    /// Default: `[0.0, 1.0]`
+    pub overstep_clamp: OverstepClamp,
+    /// The tolerances used for detecting whether a [`Transform`] was changed outside of the fixed timestep,
+    /// such as by a teleport. Changes within the tolerances are not treated as user changes.
+    ///
+    /// The tolerances can be changed at runtime through the [`TeleportEpsilon`] resource.
+    ///
+    /// Default: [`TeleportEpsilon::default()`]
+    pub teleport_epsilon: TeleportEpsilon,
+    /// The schedules that the easing systems run in.
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// The function used to compute the overstep fraction for easing, before it is clamped.
+    ///
+    /// This is typically set with [`TransformEasingPlugin::with_overstep_source`].
+    ///
+    /// Default: the [`OverstepSource`] implementation of [`Time<Fixed>`]
+    pub overstep_source: fn(&World) -> f32,
+}
+
+impl Default for TransformEasingPlugin {
+    fn default() -> Self {
+        Self {
+            skip_without_easing_entities: true,
+            emit_reset_events: false,
+            emit_completion_events: false,
+            track_is_easing: false,
+            auto_disable_when_fast: false,
+            #[cfg(feature = "visibility")]
+            skip_invisible: false,
+            overstep_clamp: OverstepClamp::default(),
+            teleport_epsilon: TeleportEpsilon::default(),
+            schedules: EasingSchedules::DEFAULT,
+            overstep_source: <Time<Fixed> as OverstepSource>::overstep,
+        }
+    }
+}
+
+impl TransformEasingPlugin {
+    /// Sets the schedules that the easing systems run in.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+
+    /// Sets the [`OverstepSource`] used to compute the overstep fraction for easing.
+    ///
+    /// See [`OverstepSource`] for more information.
+    pub fn with_overstep_source<S: OverstepSource>(mut self) -> Self {
+        self.overstep_source = S::overstep;
+        self
+    }
+}
+
+/// A source for the overstep fraction used for easing, in the range `[0.0, 1.0]`.
+///
+/// By default, the overstep fraction is computed from [`Time<Fixed>`], relative to the [`EasingTimestep`].
+/// When the fixed timestep is driven by something else, such as a custom schedule or a simulation
+/// running in the background, a custom source can be set with [`TransformEasingPlugin::with_overstep_source`]
+/// instead of reimplementing the easing systems.
+///
+/// The result is clamped with the [`OverstepClamp`] and stored in the [`EasingOverstep`] resource.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, OverstepSource};
+///
+/// /// The time accumulated towards the next step of a custom simulation.
+/// #[derive(Resource)]
+/// struct SimulationTime {
+///     accumulated: f32,
+///     timestep: f32,
+/// }
+///
+/// impl OverstepSource for SimulationTime {
+///     fn overstep(world: &World) -> f32 {
+///         world
+///             .get_resource::<Self>()
+///             .map_or(0.0, |time| time.accumulated / time.timestep)
+///     }
+/// }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     // Add the `TransformEasingPlugin` before the easing backends so that they don't add the default one.
+///     app.add_plugins((
+///         TransformEasingPlugin::default().with_overstep_source::<SimulationTime>(),
+///         TransformInterpolationPlugin::default(),
+///     ));
+/// }
+/// ```
+pub trait OverstepSource: Send + Sync + 'static {
+    /// Returns the unclamped overstep fraction for the current frame.
+    fn overstep(world: &World) -> f32;
+}
+
+/// Returns the fraction of the fixed timestep that has accumulated towards the next fixed timestep.
+///
+/// This is a thin wrapper around the overstep API of [`Time<Fixed>`], used by the easing systems
+/// so that custom easing backends don't need to depend on the exact Bevy method names.
+/// Note that the easing systems themselves read the [`EasingOverstep`] resource, which is also clamped
+/// and accounts for changes to the timestep.
+pub fn interpolation_overstep(time: &Time<Fixed>) -> f32 {
+    time.overstep_fraction()
+}
+
+/// Computes the overstep fraction relative to the [`EasingTimestep`] that was active
+/// when the `end` of easing was recorded, falling back to the current timestep.
+///
+/// This keeps easing speed consistent when the fixed timestep is changed in between fixed timesteps.
+/// If there is no [`Time<Fixed>`] resource, the overstep is `0.0`.
+impl OverstepSource for Time<Fixed> {
+    fn overstep(world: &World) -> f32 {
+        // Without a fixed timestep, there is nothing to ease.
+        let Some(time) = world.get_resource::<Time<Fixed>>() else {
+            return 0.0;
+        };
+        let timestep = world
+            .get_resource::<EasingTimestep>()
+            .map_or(Duration::ZERO, |timestep| timestep.0);
+
+        if timestep.is_zero() {
+            interpolation_overstep(time)
+        } else {
+            time.overstep().as_secs_f32() / timestep.as_secs_f32()
+        }
+    }
+}
+
+/// The schedules that the transform easing plugins run their systems in.
+///
+/// By default, the `start` of easing is updated in [`FixedFirst`], the `end` is updated in [`FixedLast`],
+/// and easing is performed in [`RunFixedMainLoop`]. These can be overridden for running the fixed timestep
+/// in a custom schedule. The same schedules should be used for the [`TransformEasingPlugin`]
+/// and the easing backends, such as the [`TransformInterpolationPlugin`] and [`TransformExtrapolationPlugin`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EasingSchedules {
+    fixed_first: Option<InternedScheduleLabel>,
+    fixed_last: Option<InternedScheduleLabel>,
+    fixed_main_loop: Option<InternedScheduleLabel>,
+}
+
+impl EasingSchedules {
+    /// The default schedules: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`].
+    pub const DEFAULT: Self = Self {
+        fixed_first: None,
+        fixed_last: None,
+        fixed_main_loop: None,
+    };
+
+    /// Creates a new [`EasingSchedules`] with the given schedules.
+    ///
+    /// - `fixed_first` runs at the start of each fixed timestep, and replaces [`FixedFirst`].
+    /// - `fixed_last` runs at the end of each fixed timestep, and replaces [`FixedLast`].
+    /// - `fixed_main_loop` runs once per frame after the fixed timesteps, and replaces [`RunFixedMainLoop`].
+    pub fn new(
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        Self {
+            fixed_first: Some(fixed_first.intern()),
+            fixed_last: Some(fixed_last.intern()),
+            fixed_main_loop: Some(fixed_main_loop.intern()),
+        }
+    }
+
+    /// Sets the schedule that easing is performed in, keeping the other schedules unchanged.
+    ///
+    /// By default, easing is performed in [`RunFixedMainLoop`] right after the fixed timesteps, before [`Update`].
+    /// Easing can instead be performed in [`PostUpdate`] to finalize the eased transforms later in the frame,
+    /// for example for custom camera synchronization or rendering effects that read the [`Transform`].
+    /// Easing is always ordered before transform propagation, and the overstep fraction of [`Time<Fixed>`]
+    /// is not changed after the fixed timesteps, so it is still correct in [`PostUpdate`].
+    ///
+    /// Note that when easing in [`PostUpdate`], gameplay systems in [`Update`] see the eased value
+    /// from the previous frame. The [`FixedTransform`] component can be used to read the non-eased value instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_transform_interpolation::{prelude::*, EasingSchedules};
+    ///
+    /// fn main() {
+    ///     let mut app = App::new();
+    ///
+    ///     app.add_plugins(TransformInterpolationPlugin {
+    ///         schedules: EasingSchedules::DEFAULT.with_fixed_main_loop(PostUpdate),
+    ///         ..default()
+    ///     });
+    /// }
+    /// ```
+    pub fn with_fixed_main_loop(mut self, fixed_main_loop: impl ScheduleLabel) -> Self {
+        self.fixed_main_loop = Some(fixed_main_loop.intern());
+        self
+    }
+
+    /// Returns the schedule that runs at the start of each fixed timestep.
+    pub fn fixed_first(&self) -> InternedScheduleLabel {
+        self.fixed_first.unwrap_or_else(|| FixedFirst.intern())
+    }
+
+    /// Returns the schedule that runs at the end of each fixed timestep.
+    pub fn fixed_last(&self) -> InternedScheduleLabel {
+        self.fixed_last.unwrap_or_else(|| FixedLast.intern())
+    }
+
+    /// Returns the schedule that runs once per frame after the fixed timesteps.
+    pub fn fixed_main_loop(&self) -> InternedScheduleLabel {
+        self.fixed_main_loop
+            .unwrap_or_else(|| RunFixedMainLoop.intern())
+    }
+}
 
 impl Plugin for TransformEasingPlugin {
     fn build(&self, app: &mut App) {
+        let fixed_first = self.schedules.fixed_first();
+        let fixed_last = self.schedules.fixed_last();
+        let fixed_main_loop = self.schedules.fixed_main_loop();
+
         // Register easing components.
+        app.register_type::<(
+            NoTransformEasing,
+            NonlinearTranslationEasing,
+            NonlinearRotationEasing,
+            NonlinearScaleEasing,
+        )>();
         app.register_type::<(
             TranslationEasingState,
             RotationEasingState,
@@ -184,33 +755,146 @@ impl Plugin for TransformEasingPlugin {
             NoTranslationEasing,
             NoRotationEasing,
             NoScaleEasing,
+            EasingFlags,
+            TranslationEasingMask,
+            SnapZLayer,
+            WrappingTranslation,
+            ScaleSignSnap,
+        )>();
+        app.register_type::<(
+            TransformEasingMode,
+            SkipEasingOnce,
+            KeepEasingOnChange,
+            EasingCulled,
+            EasingRenderOffset,
+            IsEasing,
+        )>();
+        app.register_type::<(
+            RotationEasingMode,
+            OverstepClamp,
+            TeleportEpsilon,
+            EasingTimeScale,
+            EasingStrength,
+            MaxEasingDistance,
+            EasingPaused,
+            EasingAutoDisabled,
+            FixedTransform,
+            PreviousEasedTransform,
+            MotionSampleCount,
+            MotionSamples,
         )>();
 
         app.init_resource::<LastEasingTick>();
+        app.init_resource::<LastEasingAlpha>();
+        app.init_resource::<EasingOverstep>();
+        app.init_resource::<EasingTimestep>();
+        app.init_resource::<EasingPaused>();
+        app.insert_resource(self.overstep_clamp);
+        app.insert_resource(self.teleport_epsilon);
+
+        if self.emit_reset_events {
+            app.add_event::<EasingResetEvent>();
+        }
 
         // Reset easing states and update start values at the start of the fixed timestep.
         app.configure_sets(
-            FixedFirst,
+            fixed_first,
             (TransformEasingSet::Reset, TransformEasingSet::UpdateStart).chain(),
         );
 
         // Update end values at the end of the fixed timestep.
-        app.configure_sets(FixedLast, TransformEasingSet::UpdateEnd);
+        app.configure_sets(fixed_last, TransformEasingSet::UpdateEnd);
+
+        // Store the non-eased transforms at the end of the fixed timestep,
+        // and initialize newly added fixed transforms before easing.
+        app.add_systems(
+            fixed_last,
+            update_fixed_transforms.before(TransformEasingSet::UpdateEnd),
+        );
+
+        // Store the timestep that the `end` of easing is recorded with.
+        app.add_systems(
+            fixed_last,
+            update_easing_timestep.before(TransformEasingSet::UpdateEnd),
+        );
+        app.add_systems(
+            fixed_main_loop,
+            init_fixed_transforms
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformEasingSet::Ease),
+        );
+
+        // Store the eased transforms from the previous frame before the fixed timesteps,
+        // and initialize newly added previous eased transforms before easing.
+        app.add_systems(
+            fixed_main_loop,
+            update_previous_eased_transforms
+                .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop)
+                .before(revert_render_offsets),
+        );
+        app.add_systems(
+            fixed_main_loop,
+            init_previous_eased_transforms
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformEasingSet::Ease),
+        );
 
         // Perform transform easing right after the fixed timestep, before `Update`.
+        // If easing is performed in `PostUpdate` instead, it must run before transform propagation.
         app.configure_sets(
-            RunFixedMainLoop,
+            fixed_main_loop,
             (
                 TransformEasingSet::Ease,
                 TransformEasingSet::UpdateEasingTick,
             )
                 .chain()
-                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformSystem::TransformPropagate),
+        );
+
+        // Skip easing while it is paused.
+        app.configure_sets(
+            fixed_main_loop,
+            TransformEasingSet::Ease.run_if(resource_equals(EasingPaused(false))),
         );
 
+        // Skip easing while the fixed timestep is at least as fast as the frame rate.
+        app.init_resource::<EasingAutoDisabled>();
+        if self.auto_disable_when_fast {
+            app.add_systems(
+                fixed_main_loop,
+                update_easing_auto_disabled
+                    .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                    .before(TransformEasingSet::Ease),
+            );
+            app.configure_sets(
+                fixed_main_loop,
+                TransformEasingSet::Ease.run_if(resource_equals(EasingAutoDisabled(false))),
+            );
+        }
+
+        // Skip easing entirely when there are no entities with easing states.
+        if self.skip_without_easing_entities {
+            app.configure_sets(
+                fixed_first,
+                (
+                    TransformEasingSet::Reset.run_if(has_easing_entities),
+                    TransformEasingSet::UpdateStart.run_if(has_easing_entities),
+                ),
+            );
+            app.configure_sets(
+                fixed_last,
+                TransformEasingSet::UpdateEnd.run_if(has_easing_entities),
+            );
+            app.configure_sets(
+                fixed_main_loop,
+                TransformEasingSet::Ease.run_if(has_easing_entities),
+            );
+        }
+
         // Reset easing states.
         app.add_systems(
-            FixedFirst,
+            fixed_first,
             (
                 reset_translation_easing,
                 reset_rotation_easing,
@@ -221,100 +905,1468 @@ impl Plugin for TransformEasingPlugin {
         );
 
         app.add_systems(
-            RunFixedMainLoop,
+            fixed_main_loop,
             reset_easing_states_on_transform_change.before(TransformEasingSet::Ease),
         );
 
+        // When easing or virtual time is resumed, continue easing from the current transform to avoid a jump.
+        app.add_systems(
+            fixed_main_loop,
+            resume_easing_from_current_transform
+                .run_if(easing_resumed)
+                .after(reset_easing_states_on_transform_change)
+                .after(update_easing_auto_disabled)
+                .before(TransformEasingSet::Ease),
+        );
+
+        // Compute the clamped overstep fraction used for easing.
+        let overstep_source = self.overstep_source;
+        app.add_systems(
+            fixed_main_loop,
+            (move |world: &mut World| update_easing_overstep(world, overstep_source))
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(resume_easing_from_current_transform)
+                .before(TransformEasingSet::Ease),
+        );
+
         // Perform easing.
         app.add_systems(
-            RunFixedMainLoop,
-            (ease_translation_lerp, ease_rotation_slerp, ease_scale_lerp)
+            fixed_main_loop,
+            (
+                ease_translation_lerp::<Transform, Without<CustomEaseTarget>>,
+                ease_rotation_slerp::<Transform, Without<CustomEaseTarget>>,
+                ease_scale_lerp::<Transform, Without<CustomEaseTarget>>,
+            )
                 .in_set(TransformEasingSet::Ease),
         );
 
-        // Update the last easing tick.
+        // Update the last easing tick, and remove one-shot easing markers.
+        app.add_systems(
+            fixed_main_loop,
+            (
+                update_last_easing_tick,
+                update_entity_easing_ticks,
+                remove_skip_easing_once,
+            )
+                .in_set(TransformEasingSet::UpdateEasingTick),
+        );
+
+        // Skip easing for entities that are not visible.
+        #[cfg(feature = "visibility")]
+        if self.skip_invisible {
+            app.add_systems(
+                fixed_main_loop,
+                update_easing_culling
+                    .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                    .before(TransformEasingSet::Ease),
+            );
+        }
+
+        // Snap entities that are too far from the end of easing directly to the end.
+        app.add_systems(
+            fixed_main_loop,
+            snap_far_easing
+                .after(TransformEasingSet::Ease)
+                .before(TransformEasingSet::UpdateEasingTick)
+                .run_if(resource_equals(EasingPaused(false)))
+                .run_if(resource_equals(EasingAutoDisabled(false))),
+        );
+
+        // Apply render offsets on top of the eased transforms, and revert them
+        // before the next fixed timesteps so that they don't affect gameplay.
+        app.add_systems(
+            fixed_main_loop,
+            apply_render_offsets
+                .after(TransformEasingSet::Ease)
+                .after(snap_far_easing)
+                .before(TransformEasingSet::UpdateEasingTick),
+        );
+        app.add_systems(
+            fixed_main_loop,
+            revert_render_offsets.in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+        );
+
+        // Sample the motion over the frame for entities with `MotionSampleCount` after easing.
         app.add_systems(
-            RunFixedMainLoop,
-            update_last_easing_tick.in_set(TransformEasingSet::UpdateEasingTick),
+            fixed_main_loop,
+            update_motion_samples
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .after(TransformEasingSet::Ease)
+                .before(TransformEasingSet::UpdateEasingTick)
+                .run_if(resource_equals(EasingPaused(false)))
+                .run_if(resource_equals(EasingAutoDisabled(false))),
         );
+
+        // Maintain `IsEasing` markers once the easing states have been updated for the fixed timestep.
+        if self.track_is_easing {
+            app.add_systems(
+                fixed_last,
+                update_is_easing_markers.after(TransformEasingSet::UpdateEnd),
+            );
+        }
+
+        // Trigger completion events for entities that have reached the end of easing.
+        if self.emit_completion_events {
+            app.add_systems(
+                fixed_main_loop,
+                trigger_easing_completed
+                    .after(TransformEasingSet::Ease)
+                    .before(TransformEasingSet::UpdateEasingTick),
+            );
+        }
     }
 }
 
 /// A system set for easing transform.
+///
+/// # Ordering With Simulation
+///
+/// [`TransformEasingSet::UpdateStart`] runs in [`FixedFirst`] and [`TransformEasingSet::UpdateEnd`] runs in [`FixedLast`],
+/// so any systems in [`FixedPreUpdate`], [`FixedUpdate`], or [`FixedPostUpdate`] are already guaranteed to run
+/// in between them. Systems that move entities in the same fixed timestep schedule, such as physics,
+/// can additionally be added to [`TransformEasingSet::Simulation`] to give easing backends
+/// and physics plugins a shared anchor for ordering within that schedule.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::TransformEasingSet;
+///
+/// # fn run_physics() {}
+/// # fn update_velocity_sources() {}
+/// #
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_systems(FixedPostUpdate, run_physics.in_set(TransformEasingSet::Simulation));
+///
+///     // Other plugins can run their systems relative to the simulation.
+///     app.add_systems(
+///         FixedPostUpdate,
+///         update_velocity_sources.after(TransformEasingSet::Simulation),
+///     );
+/// }
+/// ```
 #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TransformEasingSet {
     /// Resets easing states to `None` at the start of the fixed timestep.
     Reset,
     /// Updates the `start` values for easing at the start of the fixed timestep.
     UpdateStart,
+    /// A marker set for systems that move entities in the fixed timestep schedules, such as physics.
+    ///
+    /// This set is not configured by the [`TransformEasingPlugin`], and can be used in any of
+    /// [`FixedPreUpdate`], [`FixedUpdate`], or [`FixedPostUpdate`]. It always runs after
+    /// [`TransformEasingSet::UpdateStart`] and before [`TransformEasingSet::UpdateEnd`].
+    Simulation,
     /// Updates the `end` values for easing at the end of the fixed timestep.
     UpdateEnd,
     /// Eases the transform values in between the `start` and `end` states.
     /// Runs in [`RunFixedMainLoop`], right after [`FixedMain`](bevy::app::FixedMain), before [`Update`].
     Ease,
-    /// Updates [`LastEasingTick`], the last tick when easing was performed.
+    /// Updates [`LastEasingTick`] and [`LastEasingAlpha`], the last tick when easing was performed
+    /// and the interpolation alpha used for it.
     UpdateEasingTick,
 }
 
 /// A resource that stores the last tick when easing was performed.
+///
+/// For entities with an [`EasingTick`], the per-entity tick is used instead.
 #[derive(Resource, Clone, Copy, Debug, Default, Deref, DerefMut)]
 pub struct LastEasingTick(Tick);
 
-/// Explicitly marks this entity as having no transform easing, disabling interpolation and/or extrapolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-#[require(NoTranslationEasing, NoRotationEasing, NoScaleEasing)]
-pub struct NoTransformEasing;
-
-/// Explicitly marks this entity as having no translation easing, disabling interpolation and/or extrapolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-pub struct NoTranslationEasing;
-
-/// Explicitly marks this entity as having no rotation easing, disabling interpolation and/or extrapolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-pub struct NoRotationEasing;
-
-/// Explicitly marks this entity as having no scale easing, disabling interpolation and/or extrapolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-pub struct NoScaleEasing;
-
-/// A marker component that indicates that the entity has non-linear translation easing,
-/// and linear easing should not be applied.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-pub struct NonlinearTranslationEasing;
+/// Stores the last tick when easing was performed for an entity.
+///
+/// This is used to determine whether a change to the [`Transform`] was made by easing or by the user.
+/// Unlike the global [`LastEasingTick`], this is correct even when entities are eased at different times,
+/// for example with custom schedule configurations. The tick is updated by [`update_entity_easing_ticks`]
+/// in [`TransformEasingSet::UpdateEasingTick`]. Custom easing that runs elsewhere should add that system
+/// right after its easing systems.
+///
+/// This is inserted automatically for entities with easing states.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct EasingTick(pub Tick);
 
-/// A marker component that indicates that the entity has non-linear rotation easing,
-/// and linear easing should not be applied.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
-#[reflect(Component, Debug, Default)]
-pub struct NonlinearRotationEasing;
+/// A resource that stores the interpolation alpha used for the last easing run.
+///
+/// This is the same overstep fraction that was used by the easing systems in [`TransformEasingSet::Ease`],
+/// in the range of the [`OverstepClamp`], which is `[0.0, 1.0]` by default. It can be used to match
+/// custom interpolation, such as in shaders or particle systems, to the easing applied to transforms.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut)]
+pub struct LastEasingAlpha(pub f32);
 
-/// A [`QueryData`] type for specifying the components that store velocity for easing.
-/// Required for [`TransformExtrapolationPlugin`] and [`TransformHermiteEasingPlugin`].
+/// A resource that stores the fixed timestep that was active when the `end` of easing was last recorded.
 ///
-/// [`TransformExtrapolationPlugin`]: crate::extrapolation::TransformExtrapolationPlugin
-/// [`TransformHermiteEasingPlugin`]: crate::hermite::TransformHermiteEasingPlugin
+/// The default [`OverstepSource`] computes the overstep fraction relative to this timestep
+/// instead of the current [`Time<Fixed>`] timestep. This avoids a brief change in easing speed
+/// when the timestep is changed at runtime in between fixed timesteps.
 ///
-/// # Example
+/// This is updated at the end of each fixed timestep. A zero duration means that no timestep has been recorded yet.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct EasingTimestep(pub Duration);
+
+/// A resource that stores the overstep fraction used for easing during the current frame,
+/// clamped to the range specified by the [`OverstepClamp`].
 ///
-/// ```
-/// use bevy::{ecs::query::QueryData, prelude::*};
-/// use bevy_transform_interpolation::VelocitySource;
+/// This is updated right before [`TransformEasingSet::Ease`], and should be used by all easing backends
+/// instead of reading the overstep of [`Time<Fixed>`] directly.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut)]
+pub struct EasingOverstep(pub f32);
+
+/// A resource for the range that the overstep fraction used for easing is clamped to.
 ///
-/// // Velocity components
+/// This can be used to prevent easing from overshooting past the `end` state,
+/// for example with custom easing curves or extrapolation.
 ///
-/// #[derive(Component)]
-/// struct LinearVelocity(Vec3);
+/// See [`TransformEasingPlugin::overstep_clamp`] for more information.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Resource, Debug, Default)]
+pub struct OverstepClamp {
+    /// The minimum overstep fraction.
+    pub min: f32,
+    /// The maximum overstep fraction.
+    pub max: f32,
+}
+
+impl Default for OverstepClamp {
+    fn default() -> Self {
+        Self { min: 0.0, max: 1.0 }
+    }
+}
+
+impl OverstepClamp {
+    /// Clamps the given overstep fraction to the range.
+    pub fn clamp(&self, overstep: f32) -> f32 {
+        overstep.max(self.min).min(self.max)
+    }
+}
+
+/// A resource for the tolerances used for detecting whether a [`Transform`] was changed outside of the fixed timestep.
 ///
-/// #[derive(Component)]
-/// struct PreviousLinearVelocity(Vec3);
+/// When the [`Transform`] of an entity is changed outside of the fixed timestep, it is compared against the
+/// `start` and `end` of easing. If it differs from both, the change is treated as a teleport, and easing is reset.
+/// Floating-point round-trips, for example through other transform representations, can make values differ
+/// by a tiny amount, which could cause spurious resets. Differences within these tolerances are ignored.
 ///
-/// #[derive(Component)]
-/// struct AngularVelocity(Vec3);
+/// Use [`TeleportEpsilon::ZERO`] for exact comparisons.
+///
+/// See [`TransformEasingPlugin::teleport_epsilon`] for more information.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Resource, Debug, Default)]
+pub struct TeleportEpsilon {
+    /// The maximum squared distance between two translations for them to be considered equal.
+    ///
+    /// Default: `1e-8`
+    pub translation_squared: f32,
+    /// The maximum angle between two rotations in radians for them to be considered equal.
+    ///
+    /// Default: `1e-4`
+    pub rotation: f32,
+    /// The maximum squared distance between two scales for them to be considered equal.
+    ///
+    /// Default: `1e-8`
+    pub scale_squared: f32,
+}
+
+impl Default for TeleportEpsilon {
+    fn default() -> Self {
+        Self {
+            translation_squared: 1e-8,
+            rotation: 1e-4,
+            scale_squared: 1e-8,
+        }
+    }
+}
+
+impl TeleportEpsilon {
+    /// Tolerances of zero, meaning that values are compared exactly.
+    pub const ZERO: Self = Self {
+        translation_squared: 0.0,
+        rotation: 0.0,
+        scale_squared: 0.0,
+    };
+
+    /// Returns `true` if the translations differ by more than the tolerance,
+    /// only considering the axes enabled in the given `mask`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_transform_interpolation::TeleportEpsilon;
+    ///
+    /// let epsilon = TeleportEpsilon::default();
+    /// let translation = Vec3::new(100.0, 20.0, 0.0);
+    ///
+    /// // A round-trip through a rotation changes the translation slightly.
+    /// let rotation = Quat::from_rotation_z(0.3);
+    /// let round_trip = rotation.inverse() * (rotation * translation);
+    ///
+    /// // The tiny difference is not treated as a teleport.
+    /// assert!(!epsilon.translation_differs(round_trip, translation, BVec3::TRUE));
+    /// assert!(epsilon.translation_differs(translation + Vec3::X, translation, BVec3::TRUE));
+    /// ```
+    pub fn translation_differs(&self, a: Vec3, b: Vec3, mask: BVec3) -> bool {
+        let delta = Vec3::select(mask, a - b, Vec3::ZERO);
+        (a.cmpne(b) & mask).any() && delta.length_squared() > self.translation_squared
+    }
+
+    /// Returns `true` if the rotations differ by more than the tolerance.
+    pub fn rotation_differs(&self, a: Quat, b: Quat) -> bool {
+        // Compute the angle with `asin`, which is more precise than `acos` for small angles.
+        a != b && 2.0 * ops::asin((a.inverse() * b).xyz().length().min(1.0)) > self.rotation
+    }
+
+    /// Returns `true` if the scales differ by more than the tolerance.
+    pub fn scale_differs(&self, a: Vec3, b: Vec3) -> bool {
+        a != b && a.distance_squared(b) > self.scale_squared
+    }
+}
+
+/// A resource indicating whether easing is currently skipped because the fixed timestep
+/// is at least as fast as the frame rate.
+///
+/// This is only updated if [`TransformEasingPlugin::auto_disable_when_fast`] is enabled.
+/// When easing is enabled again, it continues from the current transform, like when [`EasingPaused`] is unset.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct EasingAutoDisabled(pub bool);
+
+/// A resource for pausing transform easing globally without removing any components.
+///
+/// While paused, the easing systems in [`TransformEasingSet::Ease`] and the extrapolation and prediction
+/// systems are skipped, leaving transforms at their current values. When easing is resumed,
+/// the `start` of each easing state is set to the current transform, so easing continues
+/// from where it was paused instead of jumping.
+///
+/// The same is done automatically when [`Time<Virtual>`] is unpaused, so the remaining overstep
+/// from before the pause doesn't cause a visible jump for entities that were moved while paused.
+///
+/// If [`TransformExtrapolationPlugin::complete_extrapolation`] or [`TransformPredictionPlugin::complete_prediction`]
+/// is enabled, the extrapolation or prediction systems keep running while paused, so extrapolated or predicted entities
+/// are still restored to their true [`Transform`] before each fixed timestep. Their `start` is not moved on resume,
+/// so they continue easing from the true [`Transform`].
+///
+/// [`TransformPredictionPlugin::complete_prediction`]: prediction::TransformPredictionPlugin::complete_prediction
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn toggle_pause(keyboard: Res<ButtonInput<KeyCode>>, mut paused: ResMut<EasingPaused>) {
+///     if keyboard.just_pressed(KeyCode::Escape) {
+///         paused.0 = !paused.0;
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct EasingPaused(pub bool);
+
+/// An event sent when the easing states of an entity are reset outside of the normal easing flow.
+///
+/// Only sent if [`TransformEasingPlugin::emit_reset_events`] is enabled.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn log_easing_resets(mut events: EventReader<EasingResetEvent>) {
+///     for event in events.read() {
+///         info!("easing reset for {}: {:?}", event.entity, event.reason);
+///     }
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EasingResetEvent {
+    /// The entity whose easing was reset.
+    pub entity: Entity,
+    /// The reason why easing was reset.
+    pub reason: EasingResetReason,
+}
+
+/// An observer event triggered for an entity when its easing reaches the `end`
+/// of the current fixed timestep, meaning that the [`EasingOverstep`] is close to `1.0`.
+///
+/// This is triggered at most once per entity per fixed timestep, right after [`TransformEasingSet::Ease`].
+/// If the [`OverstepClamp`] prevents the overstep from reaching `1.0`, the event is never triggered.
+///
+/// Only triggered if [`TransformEasingPlugin::emit_completion_events`] is enabled.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn((Transform::default(), TransformInterpolation))
+///         .observe(|trigger: Trigger<EasingCompleted>| {
+///             info!("{} reached its target", trigger.entity());
+///         });
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EasingCompleted;
+
+/// The reason for an [`EasingResetEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EasingResetReason {
+    /// The [`Transform`] was modified outside of the fixed timestep schedules, such as in [`Update`].
+    UserTransformChange,
+    /// Easing was reset by a command, such as [`ResetEasing`](commands::ResetEasing)
+    /// or [`TeleportAndReset`](commands::TeleportAndReset).
+    Command,
+}
+
+/// Explicitly marks this entity as having no transform easing, disabling interpolation and/or extrapolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(NoTranslationEasing, NoRotationEasing, NoScaleEasing)]
+pub struct NoTransformEasing;
+
+/// Explicitly marks this entity as having no translation easing, disabling interpolation and/or extrapolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NoTranslationEasing;
+
+/// Explicitly marks this entity as having no rotation easing, disabling interpolation and/or extrapolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NoRotationEasing;
+
+/// Explicitly marks this entity as having no scale easing, disabling interpolation and/or extrapolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NoScaleEasing;
+
+/// Runtime flags for enabling and disabling the easing of the translation, rotation, and scale of an entity.
+///
+/// Inserting and removing marker components such as [`NoTranslationEasing`] moves the entity
+/// to another archetype, which is expensive when done frequently. Toggling a flag of [`EasingFlags`]
+/// is a plain value write instead, so it is better suited for pausing easing dynamically, for example every frame.
+///
+/// While a flag is disabled, the corresponding property is not eased, and keeps the value
+/// from the last fixed timestep. The easing states are still updated, so easing resumes seamlessly
+/// when the flag is enabled again. The marker components continue to work for statically disabling easing.
+///
+/// If an entity has no [`EasingFlags`], all properties are eased.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Grabbed(bool);
+///
+/// // Disable easing while the entity is grabbed and moved by the cursor.
+/// fn toggle_easing(mut query: Query<(&Grabbed, &mut EasingFlags)>) {
+///     for (grabbed, mut flags) in &mut query {
+///         flags.set_all(!grabbed.0);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct EasingFlags {
+    /// If `true`, the translation is eased.
+    ///
+    /// Default: `true`
+    pub translation: bool,
+    /// If `true`, the rotation is eased.
+    ///
+    /// Default: `true`
+    pub rotation: bool,
+    /// If `true`, the scale is eased.
+    ///
+    /// Default: `true`
+    pub scale: bool,
+}
+
+impl Default for EasingFlags {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl EasingFlags {
+    /// Flags that enable easing for all properties.
+    pub const ALL: Self = Self {
+        translation: true,
+        rotation: true,
+        scale: true,
+    };
+
+    /// Flags that disable easing for all properties.
+    pub const NONE: Self = Self {
+        translation: false,
+        rotation: false,
+        scale: false,
+    };
+
+    /// Enables or disables easing for all properties.
+    pub fn set_all(&mut self, enabled: bool) {
+        *self = if enabled { Self::ALL } else { Self::NONE };
+    }
+
+    /// Returns `true` if easing is enabled for any property.
+    pub const fn any(&self) -> bool {
+        self.translation || self.rotation || self.scale
+    }
+}
+
+/// A one-shot marker component that disables transform easing for an entity for a single frame.
+///
+/// The easing systems skip entities with this component, leaving the [`Transform`] at its current value,
+/// and the component is removed automatically in [`TransformEasingSet::UpdateEasingTick`].
+///
+/// This can be used to avoid visual artifacts for a single frame without permanently disabling easing
+/// with [`NoTransformEasing`], for example right after spawning an entity.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Don't ease the transform on the first frame after spawning.
+///     commands.spawn((
+///         Transform::from_xyz(100.0, 0.0, 0.0),
+///         TransformInterpolation,
+///         SkipEasingOnce,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct SkipEasingOnce;
+
+/// A marker component that exempts an entity from resetting easing when its [`Transform`]
+/// is changed outside of the fixed timestep schedules.
+///
+/// By default, [`reset_easing_states_on_transform_change`] treats changes to [`Transform`] made
+/// outside of the fixed timestep as teleports, and resets easing to avoid overwriting them.
+/// For entities whose [`Transform`] is legitimately written every frame in [`Update`] by another system,
+/// this can reset easing unexpectedly. With [`KeepEasingOnChange`], easing is driven purely by
+/// the `start` and `end` updates in the fixed timestep, regardless of changes made elsewhere.
+///
+/// Note that easing then overwrites the eased properties of the [`Transform`] every frame,
+/// so any changes made to them outside of the fixed timestep are lost. Teleports must be performed
+/// explicitly with commands such as [`TeleportAndReset`](crate::commands::TeleportAndReset).
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Don't reset easing when the transform is changed in `Update`.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         KeepEasingOnChange,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct KeepEasingOnChange;
+
+/// A marker component for entities whose easing is skipped because they are not visible.
+///
+/// This is inserted and removed automatically based on `ViewVisibility` when
+/// `TransformEasingPlugin::skip_invisible` is enabled (requires the `visibility` feature),
+/// but it can also be inserted manually to skip easing for an entity until the component is removed.
+///
+/// While easing is skipped, the [`Transform`] stays at the value from the last fixed timestep.
+/// When the entity is eased again, the easing continues between the current `start` and `end`,
+/// so the entity doesn't slide to catch up.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct EasingCulled;
+
+/// A marker component for entities that are currently being eased, meaning that they have
+/// at least one easing state whose `start` and `end` are both `Some` and differ from each other.
+///
+/// This makes it cheap to find moving entities, for example to drive animation state machines,
+/// with a `Query<Entity, With<IsEasing>>`.
+///
+/// The marker is only maintained if [`TransformEasingPlugin::track_is_easing`] is enabled.
+/// It is inserted and removed at the end of each fixed timestep, after the `end` of easing has been updated,
+/// so it updates at most once per fixed timestep. Resets of easing between fixed timesteps,
+/// such as teleports, are only reflected at the end of the next fixed timestep.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(TransformInterpolationPlugin::default());
+///
+///     // Enable the `IsEasing` marker.
+///     app.add_plugins(TransformEasingPlugin {
+///         track_is_easing: true,
+///         ..default()
+///     });
+///
+///     app.add_systems(Update, play_walk_animation);
+/// }
+///
+/// fn play_walk_animation(query: Query<Entity, With<IsEasing>>) {
+///     for entity in &query {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct IsEasing;
+
+/// A visual offset applied on top of the eased [`Transform`] of an entity, without affecting gameplay.
+///
+/// The offset is composed with the eased transform in the local space of the entity
+/// right after easing, and reverted before the next fixed timesteps. The fixed timestep
+/// and the `start` and `end` of easing therefore always see the [`Transform`] without the offset.
+///
+/// This can be used for per-view visual nudges, such as in split-screen or render target setups.
+/// Note that systems in [`Update`] see the [`Transform`] with the offset applied.
+/// If the [`Transform`] is modified outside of the fixed timestep, the offset is not reverted,
+/// and the new value is kept as is.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Render the entity slightly above its gameplay position.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         EasingRenderOffset(Transform::from_xyz(0.0, 0.5, 0.0)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(RenderOffsetRevert)]
+pub struct EasingRenderOffset(pub Transform);
+
+/// Stores the transforms before and after applying an [`EasingRenderOffset`],
+/// so that the offset can be reverted before the next fixed timesteps.
+#[derive(Component, Clone, Copy, Debug, Default)]
+struct RenderOffsetRevert(Option<AppliedRenderOffset>);
+
+#[derive(Clone, Copy, Debug)]
+struct AppliedRenderOffset {
+    eased: Transform,
+    rendered: Transform,
+}
+
+/// A marker component that indicates that the entity has non-linear translation easing,
+/// and linear easing should not be applied.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NonlinearTranslationEasing;
+
+/// A marker component that indicates that the entity has non-linear rotation easing,
+/// and linear easing should not be applied.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NonlinearRotationEasing;
+
+/// A marker component that indicates that the entity has non-linear scale easing,
+/// and linear easing should not be applied.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct NonlinearScaleEasing;
+
+/// Controls which axes of the translation are eased for an entity.
+///
+/// Axes that are set to `false` are not eased, and instead keep the current value of the [`Transform`].
+/// Changes to these axes outside of the fixed timestep schedules also don't reset easing.
+///
+/// This can be useful for 2D games that set the Z coordinate based on the Y coordinate for sorting,
+/// where the Z coordinate should not be eased.
+///
+/// If an entity has no [`TranslationEasingMask`], all axes are eased.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Only ease the X and Y axes of the translation.
+///     commands.spawn((
+///         Transform::default(),
+///         TranslationInterpolation,
+///         TranslationEasingMask(BVec3::new(true, true, false)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct TranslationEasingMask(pub BVec3);
+
+impl Default for TranslationEasingMask {
+    fn default() -> Self {
+        Self(BVec3::TRUE)
+    }
+}
+
+/// Treats the Z coordinate of the translation as a discrete sort layer for 2D games,
+/// snapping it to the current value instead of easing it. The X and Y coordinates are eased normally.
+///
+/// Easing the Z coordinate of 2D entities can cause depth sorting to flicker while entities
+/// move between layers. With [`SnapZLayer`], the Z coordinate changes immediately when the layer changes.
+///
+/// This is a shorthand for disabling the Z axis in the [`TranslationEasingMask`] of the entity.
+/// Adding [`SnapZLayer`] disables the Z axis in an existing mask, or inserts a new mask,
+/// and removing it enables the Z axis again.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Ease X and Y, but snap the sort layer in Z.
+///     commands.spawn((
+///         Transform::from_xyz(0.0, 0.0, 2.0),
+///         TranslationInterpolation,
+///         SnapZLayer,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[component(on_add = snap_z_layer_on_add, on_remove = snap_z_layer_on_remove)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationEasingMask)]
+pub struct SnapZLayer;
+
+fn snap_z_layer_on_add(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    if let Some(mut mask) = world.get_mut::<TranslationEasingMask>(entity) {
+        mask.z = false;
+    }
+}
+
+fn snap_z_layer_on_remove(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    if let Some(mut mask) = world.get_mut::<TranslationEasingMask>(entity) {
+        mask.z = true;
+    }
+}
+
+/// Makes translation easing take the shortest path in a world that wraps around,
+/// such as a toroidal world where entities leaving one side reappear on the opposite side.
+///
+/// When an entity crosses the world boundary in the fixed timestep, its translation jumps by the size
+/// of the world. With [`WrappingTranslation`], the difference between the `start` and `end` of easing
+/// is wrapped to the range `[-bounds / 2, bounds / 2]` on each axis, so the entity is eased across
+/// the boundary instead of across the whole world. The eased translation can then briefly lie
+/// outside of the world bounds.
+///
+/// Axes with a size of zero are not wrapped. This is only supported by the default linear translation easing.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // The world wraps around on the X and Y axes every 1000 units.
+///     commands.spawn((
+///         Transform::default(),
+///         TranslationInterpolation,
+///         WrappingTranslation {
+///             bounds: Vec3::new(1000.0, 1000.0, 0.0),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct WrappingTranslation {
+    /// The size of the world on each axis. Axes with a size of zero are not wrapped.
+    pub bounds: Vec3,
+}
+
+impl WrappingTranslation {
+    /// Returns the shortest difference from `start` to `end` in the wrapping world.
+    pub fn wrapped_delta(&self, start: Vec3, end: Vec3) -> Vec3 {
+        let delta = end - start;
+        let wrapped = delta - self.bounds * (delta / self.bounds).round();
+        Vec3::select(self.bounds.cmpgt(Vec3::ZERO), wrapped, delta)
+    }
+}
+
+/// Makes scale easing snap axes whose sign changes, instead of easing them through zero.
+///
+/// 2D sprites are commonly flipped by negating an axis of the scale, such as `scale.x`.
+/// With linear scale easing, the axis passes through zero, causing the sprite to visibly squish
+/// in the middle of the flip. With [`ScaleSignSnap`], axes whose sign differs between the `start`
+/// and `end` of easing are set to the `end` immediately, while the other axes are eased normally.
+///
+/// This is only supported by the default linear scale easing.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Flip the sprite instantly when the sign of the scale changes.
+///     commands.spawn((
+///         Transform::default(),
+///         ScaleInterpolation,
+///         ScaleSignSnap,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct ScaleSignSnap;
+
+/// Stores the non-eased [`Transform`] of an entity from the end of the last fixed timestep.
+///
+/// Easing overwrites the [`Transform`] every frame, so systems reading it in schedules like [`Update`]
+/// see the visual value rather than the gameplay value from the fixed timestep. Gameplay logic outside
+/// of the fixed timestep schedules can read this component instead to get the ground truth.
+///
+/// The component is updated in [`FixedLast`], right before [`TransformEasingSet::UpdateEnd`].
+/// When it is first added, it is initialized to the current [`Transform`] before easing.
+///
+/// Note that changes made to the [`Transform`] outside of the fixed timestep schedules, such as teleports in [`Update`],
+/// are only reflected in this component at the end of the next fixed timestep.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         FixedTransform::default(),
+///     ));
+/// }
+///
+/// fn print_true_positions(query: Query<&FixedTransform>) {
+///     for fixed_transform in &query {
+///         info!("true translation: {}", fixed_transform.translation);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct FixedTransform(pub Transform);
+
+/// Stores the eased [`Transform`] of an entity from the previous frame.
+///
+/// The component is updated at the start of each frame's [`RunFixedMainLoop`], before the fixed timesteps
+/// and easing for the frame. When it is first added, it is initialized to the current [`Transform`] before easing.
+///
+/// This can be used with [`EasedTransform`] to compute the velocity of the rendered movement,
+/// for example for motion blur or screen-space trails.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct PreviousEasedTransform(pub Transform);
+
+/// A [`QueryData`] for reading the eased [`Transform`] of an entity together with its non-eased [`FixedTransform`],
+/// and optionally the [`PreviousEasedTransform`] from the previous frame.
+///
+/// Only entities with a [`FixedTransform`] are matched.
+///
+/// # Example
+///
+/// Rendering effects like motion blur can derive per-object velocity vectors from the rendered movement,
+/// computed as `(eased - previous_eased) / delta_secs`:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         FixedTransform::default(),
+///         PreviousEasedTransform::default(),
+///     ));
+/// }
+///
+/// fn compute_motion_vectors(query: Query<EasedTransform>, time: Res<Time>) {
+///     for transform in &query {
+///         if let Some(velocity) = transform.linear_velocity(time.delta_secs()) {
+///             // Use the velocity for motion blur...
+///         }
+///
+///         // The visual offset from the true gameplay position.
+///         let offset = transform.easing_offset();
+///     }
+/// }
+/// ```
+#[derive(QueryData)]
+pub struct EasedTransform {
+    /// The eased [`Transform`] that is rendered.
+    pub eased: &'static Transform,
+    /// The non-eased [`Transform`] from the end of the last fixed timestep.
+    pub fixed: &'static FixedTransform,
+    /// The eased [`Transform`] from the previous frame, if the entity has a [`PreviousEasedTransform`].
+    pub previous: Option<&'static PreviousEasedTransform>,
+}
+
+impl EasedTransformItem<'_> {
+    /// Returns the translation offset of the eased [`Transform`] from the non-eased [`FixedTransform`].
+    pub fn easing_offset(&self) -> Vec3 {
+        self.eased.translation - self.fixed.translation
+    }
+
+    /// Returns the linear velocity of the rendered movement over the last frame,
+    /// or `None` if the entity has no [`PreviousEasedTransform`] or `delta_secs` is zero.
+    pub fn linear_velocity(&self, delta_secs: f32) -> Option<Vec3> {
+        let previous = self.previous.filter(|_| delta_secs > 0.0)?;
+        Some((self.eased.translation - previous.translation) / delta_secs)
+    }
+
+    /// Returns the angular velocity of the rendered movement over the last frame,
+    /// or `None` if the entity has no [`PreviousEasedTransform`] or `delta_secs` is zero.
+    pub fn angular_velocity(&self, delta_secs: f32) -> Option<Vec3> {
+        let previous = self.previous.filter(|_| delta_secs > 0.0)?;
+        let mut delta = self.eased.rotation * previous.rotation.inverse();
+
+        // Take the shortest path.
+        if delta.w < 0.0 {
+            delta = -delta;
+        }
+
+        Some(delta.to_scaled_axis() / delta_secs)
+    }
+}
+
+/// The number of intermediate eased samples to compute for an entity every frame, stored in [`MotionSamples`].
+///
+/// This can be used for accumulation-style motion blur, where the entity is rendered
+/// at several points along its motion during the frame.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Sample the motion at four points during each frame.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         MotionSampleCount(4),
+///     ));
+/// }
+///
+/// fn draw_motion_blur(query: Query<&MotionSamples>) {
+///     for samples in &query {
+///         for transform in samples.iter() {
+///             // Accumulate the entity rendered with `transform`...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(MotionSamples)]
+pub struct MotionSampleCount(pub usize);
+
+/// Intermediate eased samples of the [`Transform`] of an entity with a [`MotionSampleCount`],
+/// spread evenly over the motion during the current frame.
+///
+/// With `n` samples, the samples are computed at `1/n`, `2/n`, ..., `n/n` of the way from the easing alpha
+/// of the previous frame to the current one, so with the built-in linear easing, the last sample matches
+/// the current eased [`Transform`].
+/// If a fixed timestep was run during the frame, the samples start from the beginning of the new easing,
+/// as the previous easing states are no longer available.
+///
+/// The samples are updated after [`TransformEasingSet::Ease`] with the same easing as the built-in linear easing,
+/// including the [`TransformEasingCurve`], [`EasingTimeScale`], [`EasingStrength`], [`RotationEasingMode`],
+/// and other easing configuration. Properties that are not being eased use the current [`Transform`].
+///
+/// Other easing backends, such as Hermite interpolation, [winding](winding::RotationWindingPlugin),
+/// or [extrapolation of fast spins](extrapolation::FastSpin), are not taken into account. For entities eased by them,
+/// the samples approximate the motion with linear easing, and the last sample can differ from the eased [`Transform`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct MotionSamples(pub Vec<Transform>);
+
+/// Determines how changes to [`Transform`] made outside of the fixed timestep schedules,
+/// such as in [`Update`], affect easing for an entity.
+///
+/// If an entity has no [`TransformEasingMode`], [`TransformEasingMode::Teleport`] is used.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Smoothly ease towards transforms set in `Update` instead of teleporting.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         TransformEasingMode::EaseToTarget,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq, Hash)]
+pub enum TransformEasingMode {
+    /// The change is treated as a teleport, and easing is reset to `None`
+    /// for the remainder of the fixed timestep.
+    #[default]
+    Teleport,
+    /// The change is treated as the new `end` target of easing, and the visual transform
+    /// is eased towards it from the last eased value over the remainder of the fixed timestep.
+    ///
+    /// This is primarily intended for interpolation. The last eased value is computed with the
+    /// [`LastEasingAlpha`] using linear easing.
+    EaseToTarget,
+}
+
+/// Determines which path rotation easing takes between the `start` and `end` rotations of an entity.
+///
+/// If an entity has no [`RotationEasingMode`], [`RotationEasingMode::ShortestPath`] is used.
+///
+/// The `axis` of [`RotationEasingMode::Planar`] is compared and hashed by its bit representation,
+/// so that the mode can implement [`Eq`] and [`Hash`].
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default, PartialEq, Hash)]
+pub enum RotationEasingMode {
+    /// Rotation is eased along the shortest path with `slerp`.
+    ///
+    /// When an entity rotates by more than 180 degrees in a single fixed timestep,
+    /// this can make the rotation visually flip to the other direction.
+    #[default]
+    ShortestPath,
+    /// Rotation is eased in the direction of the angular velocity of the entity.
+    ///
+    /// Requires the [`RotationWindingPlugin`](winding::RotationWindingPlugin) with a velocity source.
+    /// Without angular velocity, the shortest path is used.
+    RespectWinding,
+    /// Rotation is eased by interpolating the signed angle around a fixed `axis`,
+    /// such as [`Vec3::Z`] for 2D games.
+    ///
+    /// This is cheaper than `slerp`, and only rotates around the given axis. Without angular velocity,
+    /// the angle takes the shortest path. With the [`RotationWindingPlugin`](winding::RotationWindingPlugin),
+    /// the angular velocity is used to determine the direction and to represent multiple full revolutions.
+    ///
+    /// See [`planar_rotation_lerp`](winding::planar_rotation_lerp) for more information.
+    Planar {
+        /// The axis that the rotation is interpolated around.
+        axis: Vec3,
+    },
+    /// Rotation is eased along the shortest path with normalized linear interpolation (`nlerp`).
+    ///
+    /// This is cheaper than `slerp`, but the angular speed is not constant over the timestep.
+    /// The error grows with the angle between the `start` and `end` rotations, so this is best suited
+    /// for large numbers of entities that only rotate by small angles per fixed timestep.
+    ///
+    /// The maximum angular error compared to `slerp` over the timestep is roughly `angle^3 / 250`:
+    ///
+    /// | Rotation per timestep | Maximum error |
+    /// | --------------------- | ------------- |
+    /// | 10°                   | 0.001°        |
+    /// | 30°                   | 0.03°         |
+    /// | 45°                   | 0.11°         |
+    /// | 90°                   | 0.92°         |
+    /// | 180°                  | 8.1°          |
+    ///
+    /// The error is measured by the `nlerp_quality` report of the `easing` benchmark.
+    Nlerp,
+}
+
+impl RotationEasingMode {
+    /// Returns the bit representation of the `axis` of [`RotationEasingMode::Planar`],
+    /// used for comparing and hashing the mode.
+    fn axis_bits(&self) -> Option<[u32; 3]> {
+        match self {
+            Self::Planar { axis } => Some(axis.to_array().map(f32::to_bits)),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for RotationEasingMode {
+    fn eq(&self, other: &Self) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+            && self.axis_bits() == other.axis_bits()
+    }
+}
+
+impl Eq for RotationEasingMode {}
+
+impl core::hash::Hash for RotationEasingMode {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        self.axis_bits().hash(state);
+    }
+}
+
+/// A strategy for interpolating between two rotations, used for linear rotation easing.
+///
+/// The built-in [`Slerp`] and [`Nlerp`] interpolators are used for [`RotationEasingMode::ShortestPath`]
+/// and [`RotationEasingMode::Nlerp`] respectively. Custom interpolators, such as squad or log-quaternion blending,
+/// can be used for individual entities with the [`CustomRotationInterpolator`] component.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// /// Blends rotations in the tangent space of the start rotation using the logarithmic map.
+/// struct LogBlend;
+///
+/// impl RotationInterpolator for LogBlend {
+///     fn interpolate(&self, start: Quat, end: Quat, alpha: f32) -> Quat {
+///         let mut delta = start.inverse() * end;
+///
+///         // Take the shortest path.
+///         if delta.w < 0.0 {
+///             delta = -delta;
+///         }
+///
+///         start * Quat::from_scaled_axis(delta.to_scaled_axis() * alpha)
+///     }
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         CustomRotationInterpolator::new(LogBlend),
+///     ));
+/// }
+/// ```
+pub trait RotationInterpolator: Send + Sync + 'static {
+    /// Interpolates between the `start` and `end` rotations with the given interpolation `alpha`.
+    fn interpolate(&self, start: Quat, end: Quat, alpha: f32) -> Quat;
+}
+
+/// A [`RotationInterpolator`] using spherical linear interpolation (`slerp`) along the shortest path.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Slerp;
+
+impl RotationInterpolator for Slerp {
+    fn interpolate(&self, start: Quat, end: Quat, alpha: f32) -> Quat {
+        start.slerp(end, alpha)
+    }
+}
+
+/// A [`RotationInterpolator`] using normalized linear interpolation (`nlerp`) along the shortest path.
+///
+/// See [`RotationEasingMode::Nlerp`] for more information.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Nlerp;
+
+impl RotationInterpolator for Nlerp {
+    fn interpolate(&self, start: Quat, end: Quat, alpha: f32) -> Quat {
+        // `Quat::lerp` normalizes the result and takes the shortest path.
+        start.lerp(end, alpha)
+    }
+}
+
+/// Uses a custom [`RotationInterpolator`] for the linear rotation easing of an entity,
+/// taking precedence over the [`RotationEasingMode`].
+///
+/// The interpolation alpha still respects the [`TransformEasingCurve`] and [`EasingTimeScale`] of the entity.
+/// Nonlinear easing backends, such as Hermite interpolation, are not affected.
+///
+/// See [`RotationInterpolator`] for an example.
+#[derive(Component, Clone, Deref)]
+pub struct CustomRotationInterpolator(pub Arc<dyn RotationInterpolator>);
+
+impl CustomRotationInterpolator {
+    /// Creates a new [`CustomRotationInterpolator`] with the given interpolator.
+    pub fn new(interpolator: impl RotationInterpolator) -> Self {
+        Self(Arc::new(interpolator))
+    }
+}
+
+impl core::fmt::Debug for CustomRotationInterpolator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CustomRotationInterpolator(..)")
+    }
+}
+
+/// Remaps the interpolation alpha used for linear transform easing, enabling effects such as
+/// ease-in and ease-out on top of interpolation or extrapolation.
+///
+/// This only affects the default `lerp` and `slerp` easing. Nonlinear easing backends
+/// such as Hermite interpolation are not affected.
+///
+/// If an entity has no [`TransformEasingCurve`], the alpha is used as is, resulting in linear easing.
+///
+/// # Example
+///
+/// ```
+/// use bevy::{math::curve::EaseFunction, prelude::*};
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Use an ease-in-out curve for interpolation.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         TransformEasingCurve::EaseFunction(EaseFunction::CubicInOut),
+///     ));
+///
+///     // Use a custom function for interpolation.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         TransformEasingCurve::Custom(|t| t * t),
+///     ));
+///
+///     // Snap from the start to the end halfway through the timestep.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         TransformEasingCurve::Step { at: 0.5 },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub enum TransformEasingCurve {
+    /// Remaps the alpha using the given [`EaseFunction`].
+    EaseFunction(EaseFunction),
+    /// Remaps the alpha using a custom function.
+    ///
+    /// The function should map `0.0` to `0.0` and `1.0` to `1.0`.
+    Custom(fn(f32) -> f32),
+    /// Snaps the value from `start` to `end` once the alpha reaches `at`, without continuous easing.
+    ///
+    /// This can be useful for discrete changes such as flip-book animation or teleporting at the midpoint.
+    Step {
+        /// The alpha at which the value jumps from `start` to `end`.
+        at: f32,
+    },
+}
+
+impl TransformEasingCurve {
+    /// Remaps the given interpolation alpha `t` using the curve.
+    pub fn sample(&self, t: f32) -> f32 {
+        match self {
+            Self::EaseFunction(ease_fn) => EasingCurve::new(0.0, 1.0, *ease_fn).sample_clamped(t),
+            Self::Custom(f) => f(t),
+            Self::Step { at } => {
+                if t >= *at {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Scales the speed of easing for an entity, for example for per-entity slow-motion or hit-stop effects.
+///
+/// The overstep fraction used for easing the entity is multiplied by the scale and clamped to the range
+/// of the [`OverstepClamp`], which is `[0.0, 1.0]` by default.
+/// With a scale below `1.0`, easing appears slower and lags further behind the `end` of easing,
+/// and with a scale of `0.0`, the entity stays at the `start`.
+///
+/// This is purely cosmetic: it only affects the eased [`Transform`] in between fixed timesteps,
+/// and the gameplay transform in the fixed timestep schedules is unaffected. Because the states are
+/// still updated every fixed timestep, a scale far from `1.0` can make movement look choppy.
+///
+/// If an entity has no [`EasingTimeScale`], the overstep is used as is.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Ease at half speed.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         EasingTimeScale(0.5),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct EasingTimeScale(pub f32);
+
+impl Default for EasingTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl EasingTimeScale {
+    /// Returns the given overstep fraction scaled by the time scale, clamped to the range of the [`OverstepClamp`].
+    ///
+    /// This uses the same range as the global overstep, so that for example a `max` above `1.0`
+    /// also allows entities with a time scale to overshoot.
+    pub fn apply(&self, overstep: f32, clamp: &OverstepClamp) -> f32 {
+        clamp.clamp(overstep * self.0)
+    }
+}
+
+/// Controls how strongly easing is applied to an entity, blending between the eased [`Transform`]
+/// and the true [`Transform`] from the last fixed timestep.
+///
+/// A strength of `1.0` applies easing fully, and a strength of `0.0` disables smoothing, so that the entity
+/// is rendered at the `end` of easing, as if it had no easing at all. Values in between linearly blend
+/// from the `end` towards the eased value, which can be used for subtle smoothing, or for comparing
+/// eased and non-eased movement side by side. The strength is clamped to `[0.0, 1.0]`.
+///
+/// This is applied by the core `lerp` and `slerp` easing. Note that for extrapolation, the `end` of easing
+/// is the predicted [`Transform`] rather than the true one. Hermite interpolation and other custom easing backends
+/// are unaffected.
+///
+/// If an entity has no [`EasingStrength`], easing is applied fully.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Apply half of the easing.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         EasingStrength(0.5),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct EasingStrength(pub f32);
+
+impl Default for EasingStrength {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl EasingStrength {
+    /// Returns the strength clamped to `[0.0, 1.0]`.
+    pub fn clamped(&self) -> f32 {
+        self.0.clamp(0.0, 1.0)
+    }
+}
+
+/// Limits how far apart the `start` and `end` of easing can be for an entity to be eased.
+///
+/// When the difference exceeds the limit, easing is skipped, and the entity is snapped directly
+/// to the `end` for the current fixed timestep. This prevents slowly "sliding" across
+/// large distances, for example after the simulation ran many steps while the entity was off-screen.
+///
+/// The limits apply to all easing backends. Each limit defaults to infinity, meaning no limit.
+/// If an entity has no [`MaxEasingDistance`], easing is never skipped.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Snap instead of easing when moving more than 10 units or rotating more than 90 degrees.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         MaxEasingDistance {
+///             translation: 10.0,
+///             rotation: core::f32::consts::FRAC_PI_2,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct MaxEasingDistance {
+    /// The maximum distance between the `start` and `end` translation.
+    ///
+    /// Default: `f32::INFINITY`
+    pub translation: f32,
+    /// The maximum angle between the `start` and `end` rotation, in radians.
+    ///
+    /// Default: `f32::INFINITY`
+    pub rotation: f32,
+    /// The maximum distance between the `start` and `end` scale.
+    ///
+    /// Default: `f32::INFINITY`
+    pub scale: f32,
+}
+
+impl Default for MaxEasingDistance {
+    fn default() -> Self {
+        Self {
+            translation: f32::INFINITY,
+            rotation: f32::INFINITY,
+            scale: f32::INFINITY,
+        }
+    }
+}
+
+/// A [`QueryData`] type for specifying the components that store velocity for easing.
+/// Required for [`TransformExtrapolationPlugin`] and [`TransformHermiteEasingPlugin`].
+///
+/// [`TransformExtrapolationPlugin`]: crate::extrapolation::TransformExtrapolationPlugin
+/// [`TransformHermiteEasingPlugin`]: crate::hermite::TransformHermiteEasingPlugin
+///
+/// # Example
+///
+/// ```
+/// use bevy::{ecs::query::QueryData, prelude::*};
+/// use bevy_transform_interpolation::VelocitySource;
+///
+/// // Velocity components
+///
+/// #[derive(Component)]
+/// struct LinearVelocity(Vec3);
+///
+/// #[derive(Component)]
+/// struct PreviousLinearVelocity(Vec3);
+///
+/// #[derive(Component)]
+/// struct AngularVelocity(Vec3);
 ///
 /// #[derive(Component)]
 /// struct PreviousAngularVelocity(Vec3);
@@ -356,6 +2408,11 @@ pub struct NonlinearRotationEasing;
 ///
 /// Some forms of easing such as extrapolation may not require the previous velocity.
 /// In such cases, the `Previous` component can be set to `()`, and `previous` can simply return `Vec3::ZERO`.
+///
+/// For 2D velocity components that dereference to a [`Vec2`] or [`f32`], the ready-made
+/// [`LinVel2dSource`](velocity::LinVel2dSource) and [`AngVel2dSource`](velocity::AngVel2dSource)
+/// adapters can be used instead of implementing this trait manually. The previous velocity
+/// can be maintained automatically with the [`TrackPreviousPlugin`](velocity::TrackPreviousPlugin).
 pub trait VelocitySource: QueryData + Send + Sync + 'static {
     /// The component that stores the previous velocity.
     ///
@@ -374,6 +2431,51 @@ pub trait VelocitySource: QueryData + Send + Sync + 'static {
 
     /// Returns the current velocity.
     fn current(end: &Self::Current) -> Vec3;
+
+    /// Returns the previous angular velocity as a quaternion rate, the time derivative of the rotation.
+    ///
+    /// Some physics backends store the rate of change of orientation as a quaternion instead of
+    /// a scaled-axis angular velocity. If this returns `Some`, it is used instead of [`VelocitySource::previous`]
+    /// for rotation easing, converted to a global angular velocity with [`angular_velocity_from_quat_rate`].
+    ///
+    /// Default: `None`
+    fn previous_quat(start: &Self::Previous) -> Option<Quat> {
+        let _ = start;
+        None
+    }
+
+    /// Returns the current angular velocity as a quaternion rate, the time derivative of the rotation.
+    ///
+    /// Some physics backends store the rate of change of orientation as a quaternion instead of
+    /// a scaled-axis angular velocity. If this returns `Some`, it is used instead of [`VelocitySource::current`]
+    /// for rotation easing, converted to a global angular velocity with [`angular_velocity_from_quat_rate`].
+    ///
+    /// Default: `None`
+    fn current_quat(end: &Self::Current) -> Option<Quat> {
+        let _ = end;
+        None
+    }
+}
+
+/// Converts a quaternion rate, the time derivative of the given `rotation`,
+/// to an angular velocity in the global frame, represented as a scaled axis.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::angular_velocity_from_quat_rate;
+///
+/// let rotation = Quat::from_rotation_x(1.0);
+/// let angular_velocity = Vec3::new(0.0, 0.0, 2.0);
+///
+/// // The time derivative of a rotation with a global angular velocity.
+/// let rate = Quat::from_vec4(angular_velocity.extend(0.0)) * rotation * 0.5;
+///
+/// assert!(angular_velocity_from_quat_rate(rate, rotation).abs_diff_eq(angular_velocity, 1e-5));
+/// ```
+pub fn angular_velocity_from_quat_rate(rate: Quat, rotation: Quat) -> Vec3 {
+    2.0 * (rate * rotation.conjugate()).xyz()
 }
 
 trait VelocitySourceItem<V>
@@ -382,105 +2484,708 @@ where
 {
     fn previous(start: &V::Previous) -> Vec3;
     fn current(end: &V::Current) -> Vec3;
+
+    /// Returns the previous angular velocity, preferring the quaternion rate if the source provides one.
+    fn previous_angular(start: &V::Previous, rotation: Quat) -> Vec3;
+
+    /// Returns the current angular velocity, preferring the quaternion rate if the source provides one.
+    fn current_angular(end: &V::Current, rotation: Quat) -> Vec3;
+}
+
+impl<V: VelocitySource> VelocitySourceItem<V> for V::Item<'_> {
+    fn previous(start: &V::Previous) -> Vec3 {
+        V::previous(start)
+    }
+
+    fn current(end: &V::Current) -> Vec3 {
+        V::current(end)
+    }
+
+    fn previous_angular(start: &V::Previous, rotation: Quat) -> Vec3 {
+        V::previous_quat(start).map_or_else(
+            || V::previous(start),
+            |rate| angular_velocity_from_quat_rate(rate, rotation),
+        )
+    }
+
+    fn current_angular(end: &V::Current, rotation: Quat) -> Vec3 {
+        V::current_quat(end).map_or_else(
+            || V::current(end),
+            |rate| angular_velocity_from_quat_rate(rate, rotation),
+        )
+    }
+}
+
+// Required so that `()` can be used as a "null" velocity source despite it not being a component itself.
+// This can be useful if you only want to use Hermite interpolation for rotation, for example.
+//
+// This must be public, because `VelocitySource::Start` and `VelocitySource::End` are public interfaces,
+// but you can't actually create this component since the stored value is private and there are no constructors.
+#[derive(Component)]
+#[doc(hidden)]
+pub struct DummyComponent(PhantomData<()>);
+
+impl VelocitySource for () {
+    type Previous = DummyComponent;
+    type Current = DummyComponent;
+
+    fn previous(_: &Self::Previous) -> Vec3 {
+        Vec3::ZERO
+    }
+
+    fn current(_: &Self::Current) -> Vec3 {
+        Vec3::ZERO
+    }
+}
+
+/// Returns `true` if the given [`VelocitySource`] reads velocity from components,
+/// and `false` for the `()` source, which has no velocity.
+pub(crate) fn has_velocity_components<V: VelocitySource>() -> bool {
+    TypeId::of::<V::Current>() != TypeId::of::<DummyComponent>()
+}
+
+/// Returns the names of the components that the given [`VelocitySource`] reads velocity from,
+/// formatted for warnings.
+pub(crate) fn velocity_component_names<V: VelocitySource>() -> String {
+    let previous = type_name::<V::Previous>();
+    let current = type_name::<V::Current>();
+    if previous == current {
+        format!("the `{current}` component")
+    } else {
+        format!("the `{previous}` and `{current}` components")
+    }
+}
+
+/// Stores the start and end states used for interpolating the translation of an entity.
+/// The change in translation is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
+///
+/// On its own, this component is not updated automatically. Enable an easing backend
+/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+#[require(EasingTick)]
+pub struct TranslationEasingState {
+    /// The start translation for the interpolation.
+    pub start: Option<Vec3>,
+    /// The end translation for the interpolation.
+    pub end: Option<Vec3>,
+}
+
+/// Stores the start and end states used for interpolating the rotation of an entity.
+/// The change in rotation is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
+///
+/// On its own, this component is not updated automatically. Enable an easing backend
+/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+#[require(EasingTick)]
+pub struct RotationEasingState {
+    /// The start rotation for the interpolation.
+    pub start: Option<Quat>,
+    /// The end rotation for the interpolation.
+    pub end: Option<Quat>,
+}
+
+/// Stores the start and end states used for interpolating the scale of an entity.
+/// The change in scale is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
+///
+/// On its own, this component is not updated automatically. Enable an easing backend
+/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+#[require(EasingTick)]
+pub struct ScaleEasingState {
+    /// The start scale for the interpolation.
+    pub start: Option<Vec3>,
+    /// The end scale for the interpolation.
+    pub end: Option<Vec3>,
+}
+
+/// Computes the [`Transform`] that the given easing states would be eased to at the given `alpha`,
+/// using linear interpolation for translation and scale, and spherical linear interpolation for rotation.
+///
+/// This computes the same result as the easing systems of the [`TransformInterpolationPlugin`]
+/// without any per-entity configuration, such as a [`TransformEasingCurve`], and without mutating any components.
+/// It can be useful for prediction and debugging tools that need to know where an entity will be rendered.
+///
+/// If only one of the `start` and `end` of a state is set, that value is used as is.
+/// If neither is set, the value of [`Transform::IDENTITY`] is used for that property.
+///
+/// See [`hermite_eased_transform`](hermite::hermite_eased_transform) for a variant that uses Hermite interpolation.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{
+///     eased_transform, RotationEasingState, ScaleEasingState, TranslationEasingState,
+/// };
+///
+/// let translation = TranslationEasingState {
+///     start: Some(Vec3::ZERO),
+///     end: Some(Vec3::new(2.0, 0.0, 0.0)),
+/// };
+/// let rotation = RotationEasingState::default();
+/// let scale = ScaleEasingState::default();
+///
+/// let transform = eased_transform(&translation, &rotation, &scale, 0.5);
+/// assert_eq!(transform.translation, Vec3::new(1.0, 0.0, 0.0));
+/// assert_eq!(transform.rotation, Quat::IDENTITY);
+/// assert_eq!(transform.scale, Vec3::ONE);
+/// ```
+pub fn eased_transform(
+    translation: &TranslationEasingState,
+    rotation: &RotationEasingState,
+    scale: &ScaleEasingState,
+    alpha: f32,
+) -> Transform {
+    Transform {
+        translation: ease_or(
+            translation.start,
+            translation.end,
+            Vec3::ZERO,
+            |start, end| start.lerp(end, alpha),
+        ),
+        rotation: ease_or(
+            rotation.start,
+            rotation.end,
+            Quat::IDENTITY,
+            |start, end| start.slerp(end, alpha),
+        ),
+        scale: ease_or(scale.start, scale.end, Vec3::ONE, |start, end| {
+            start.lerp(end, alpha)
+        }),
+    }
+}
+
+/// Eases between `start` and `end` with the given function if both are set,
+/// and otherwise returns whichever is set, or `default` if neither is.
+pub(crate) fn ease_or<T>(
+    start: Option<T>,
+    end: Option<T>,
+    default: T,
+    ease: impl FnOnce(T, T) -> T,
+) -> T {
+    match (start, end) {
+        (Some(start), Some(end)) => ease(start, end),
+        (Some(value), None) | (None, Some(value)) => value,
+        (None, None) => default,
+    }
+}
+
+/// Calls the given function for each item of the query.
+///
+/// The items are iterated in parallel if the `multithreaded` feature is enabled and the target is not `wasm32`,
+/// and serially otherwise to avoid the overhead of parallel iteration on a single thread.
+/// The `deterministic` feature forces serial iteration.
+pub(crate) fn for_each_mut<'a, D: QueryData, F: QueryFilter>(
+    query: &'a mut Query<'_, '_, D, F>,
+    func: impl Fn(QueryItem<'a, D>) + Send + Sync + Clone,
+) {
+    #[cfg(all(
+        feature = "multithreaded",
+        not(feature = "deterministic"),
+        not(target_arch = "wasm32")
+    ))]
+    query.par_iter_mut().for_each(func);
+    #[cfg(not(all(
+        feature = "multithreaded",
+        not(feature = "deterministic"),
+        not(target_arch = "wasm32")
+    )))]
+    query.iter_mut().for_each(func);
+}
+
+/// A run condition that returns `true` if any entity has a [`TranslationEasingState`],
+/// [`RotationEasingState`], [`ScaleEasingState`], or [`GlobalTransformEasingState`].
+///
+/// The 2D and double-precision easing states are also counted if the `2d` and `f64` features are enabled.
+pub fn has_easing_entities(
+    query: Query<
+        (),
+        Or<(
+            With<TranslationEasingState>,
+            With<RotationEasingState>,
+            With<ScaleEasingState>,
+            With<GlobalTransformEasingState>,
+        )>,
+    >,
+    #[cfg(feature = "2d")] query_2d: Query<
+        (),
+        Or<(
+            With<interpolation_2d::Translation2dEasingState>,
+            With<interpolation_2d::Rotation2dEasingState>,
+        )>,
+    >,
+    #[cfg(feature = "f64")] query_f64: Query<
+        (),
+        Or<(
+            With<interpolation_f64::TranslationF64EasingState>,
+            With<interpolation_f64::RotationF64EasingState>,
+            With<interpolation_f64::ScaleF64EasingState>,
+        )>,
+    >,
+) -> bool {
+    #[cfg(feature = "2d")]
+    if !query_2d.is_empty() {
+        return true;
+    }
+    #[cfg(feature = "f64")]
+    if !query_f64.is_empty() {
+        return true;
+    }
+    !query.is_empty()
+}
+
+fn update_easing_overstep(world: &mut World, overstep_source: fn(&World) -> f32) {
+    let overstep = world
+        .resource::<OverstepClamp>()
+        .clamp(overstep_source(world));
+    *world.resource_mut::<EasingOverstep>() = EasingOverstep(overstep);
+}
+
+fn update_last_easing_tick(
+    mut last_easing_tick: ResMut<LastEasingTick>,
+    mut last_easing_alpha: ResMut<LastEasingAlpha>,
+    system_change_tick: SystemChangeTick,
+    overstep: Res<EasingOverstep>,
+) {
+    *last_easing_tick = LastEasingTick(system_change_tick.this_run());
+    *last_easing_alpha = LastEasingAlpha(overstep.0);
+}
+
+/// The 2D easing states considered by [`update_entity_easing_ticks`].
+#[cfg(feature = "2d")]
+type EasingStates2d = (
+    Option<&'static interpolation_2d::Translation2dEasingState>,
+    Option<&'static interpolation_2d::Rotation2dEasingState>,
+);
+#[cfg(not(feature = "2d"))]
+type EasingStates2d = ();
+
+/// Updates the [`EasingTick`] of entities that were eased, meaning that they have
+/// at least one easing state with both a `start` and an `end`.
+///
+/// The 2D easing states are also considered if the `2d` feature is enabled.
+///
+/// This runs in [`TransformEasingSet::UpdateEasingTick`]. If easing is performed in a custom schedule,
+/// this system should also be added right after the easing systems in that schedule.
+pub fn update_entity_easing_ticks(
+    mut query: Query<
+        (
+            &mut EasingTick,
+            Option<&TranslationEasingState>,
+            Option<&RotationEasingState>,
+            Option<&ScaleEasingState>,
+            EasingStates2d,
+        ),
+        Without<SkipEasingOnce>,
+    >,
+    system_change_tick: SystemChangeTick,
+) {
+    let this_run = system_change_tick.this_run();
+
+    for_each_mut(
+        &mut query,
+        |(mut tick, translation, rotation, scale, _states_2d)| {
+            let is_eased = translation
+                .is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+                || rotation.is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+                || scale.is_some_and(|easing| easing.start.is_some() && easing.end.is_some());
+
+            #[cfg(feature = "2d")]
+            let is_eased = is_eased || {
+                let (translation_2d, rotation_2d) = _states_2d;
+                translation_2d.is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+                    || rotation_2d
+                        .is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+            };
+
+            if is_eased {
+                tick.0 = this_run;
+            }
+        },
+    );
+}
+
+/// Snaps entities to the `end` of easing when it is further from the `start` than their [`MaxEasingDistance`].
+fn snap_far_easing(
+    mut query: Query<
+        (
+            &mut Transform,
+            &MaxEasingDistance,
+            Option<&TranslationEasingState>,
+            Option<&RotationEasingState>,
+            Option<&ScaleEasingState>,
+            Option<&TranslationEasingMask>,
+        ),
+        (
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+) {
+    for_each_mut(
+        &mut query,
+        |(mut transform, max_distance, translation, rotation, scale, mask)| {
+            if let Some((start, end)) = translation.and_then(|easing| easing.start.zip(easing.end))
+            {
+                if start.distance(end) > max_distance.translation {
+                    let translation = match mask {
+                        Some(mask) => Vec3::select(mask.0, end, transform.translation),
+                        None => end,
+                    };
+                    if transform.translation != translation {
+                        transform.translation = translation;
+                    }
+                }
+            }
+            if let Some((start, end)) = rotation.and_then(|easing| easing.start.zip(easing.end)) {
+                if start.angle_between(end) > max_distance.rotation && transform.rotation != end {
+                    transform.rotation = end;
+                }
+            }
+            if let Some((start, end)) = scale.and_then(|easing| easing.start.zip(easing.end)) {
+                if start.distance(end) > max_distance.scale && transform.scale != end {
+                    transform.scale = end;
+                }
+            }
+        },
+    );
+}
+
+/// The overstep at or above which easing is considered to have reached the `end`.
+const COMPLETION_THRESHOLD: f32 = 1.0 - 1e-3;
+
+/// Triggers [`EasingCompleted`] for eased entities when the overstep reaches the `end` of easing.
+fn trigger_easing_completed(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            Option<&TranslationEasingState>,
+            Option<&RotationEasingState>,
+            Option<&ScaleEasingState>,
+        ),
+        (
+            Or<(
+                With<TranslationEasingState>,
+                With<RotationEasingState>,
+                With<ScaleEasingState>,
+            )>,
+            Without<SkipEasingOnce>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    time: Option<Res<Time<Fixed>>>,
+    mut completed_at: Local<Option<Duration>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    // Only trigger once per fixed timestep, even if the overstep stays above the threshold for several frames.
+    if overstep.0 < COMPLETION_THRESHOLD || *completed_at == Some(time.elapsed()) {
+        return;
+    }
+    *completed_at = Some(time.elapsed());
+
+    let entities: Vec<Entity> = query
+        .iter()
+        .filter(|(_, translation, rotation, scale)| {
+            translation.is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+                || rotation.is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+                || scale.is_some_and(|easing| easing.start.is_some() && easing.end.is_some())
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+
+    if !entities.is_empty() {
+        commands.trigger_targets(EasingCompleted, entities);
+    }
+}
+
+/// Stores the current fixed timestep in the [`EasingTimestep`] at the end of the fixed timestep.
+fn update_easing_timestep(
+    mut easing_timestep: ResMut<EasingTimestep>,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    if let Some(time) = time {
+        easing_timestep.0 = time.timestep();
+    }
+}
+
+/// Stores the current [`Transform`] in the [`FixedTransform`] at the end of the fixed timestep.
+fn update_fixed_transforms(mut query: Query<(&Transform, &mut FixedTransform)>) {
+    for_each_mut(&mut query, |(transform, mut fixed_transform)| {
+        fixed_transform.0 = *transform;
+    });
+}
+
+/// Initializes newly added [`FixedTransform`]s to the current [`Transform`] before easing.
+fn init_fixed_transforms(
+    mut query: Query<(&Transform, &mut FixedTransform), Added<FixedTransform>>,
+) {
+    for (transform, mut fixed_transform) in &mut query {
+        fixed_transform.0 = *transform;
+    }
+}
+
+/// Computes the [`MotionSamples`] for entities with a [`MotionSampleCount`].
+#[allow(clippy::type_complexity)]
+fn update_motion_samples(
+    mut query: Query<(
+        &Transform,
+        &MotionSampleCount,
+        &mut MotionSamples,
+        (
+            Option<&TranslationEasingState>,
+            Option<&TranslationEasingMask>,
+            Option<&WrappingTranslation>,
+        ),
+        (
+            Option<&RotationEasingState>,
+            Option<&RotationEasingMode>,
+            Option<&CustomRotationInterpolator>,
+        ),
+        (Option<&ScaleEasingState>, Has<ScaleSignSnap>),
+        (
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingStrength>,
+            Option<&EasingFlags>,
+        ),
+    )>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+    last_alpha: Res<LastEasingAlpha>,
+    fixed_time: Option<Res<Time<Fixed>>>,
+    mut last_fixed_elapsed: Local<Duration>,
+) {
+    // If a fixed timestep was run, the easing starts over from the new `start`.
+    let fixed_elapsed = fixed_time.map_or(Duration::ZERO, |time| time.elapsed());
+    let to = overstep.0;
+    let from = if fixed_elapsed != *last_fixed_elapsed {
+        0.0
+    } else {
+        last_alpha.0.min(to)
+    };
+    *last_fixed_elapsed = fixed_elapsed;
+
+    for_each_mut(
+        &mut query,
+        |(
+            transform,
+            count,
+            mut samples,
+            (translation_easing, mask, wrapping),
+            (rotation_easing, mode, interpolator),
+            (scale_easing, sign_snap),
+            (curve, time_scale, strength, flags),
+        )| {
+            let flags = flags.copied().unwrap_or_default();
+            let translation = translation_easing
+                .and_then(|easing| easing.start.zip(easing.end))
+                .filter(|_| flags.translation);
+            let rotation = rotation_easing
+                .and_then(|easing| easing.start.zip(easing.end))
+                .filter(|_| flags.rotation);
+            let scale = scale_easing
+                .and_then(|easing| easing.start.zip(easing.end))
+                .filter(|_| flags.scale);
+
+            samples.clear();
+            for i in 1..=count.0 {
+                let overstep = from + (to - from) * i as f32 / count.0 as f32;
+                let alpha = easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+
+                samples.push(Transform {
+                    translation: translation.map_or(transform.translation, |(start, end)| {
+                        let eased = ease_translation(start, end, alpha, wrapping, strength);
+                        match mask {
+                            Some(mask) => Vec3::select(mask.0, eased, transform.translation),
+                            None => eased,
+                        }
+                    }),
+                    rotation: rotation.map_or(transform.rotation, |(start, end)| {
+                        ease_rotation(start, end, alpha, mode, interpolator, strength)
+                    }),
+                    scale: scale.map_or(transform.scale, |(start, end)| {
+                        ease_scale(start, end, alpha, strength, sign_snap)
+                    }),
+                });
+            }
+        },
+    );
 }
 
-impl<V: VelocitySource> VelocitySourceItem<V> for V::Item<'_> {
-    fn previous(start: &V::Previous) -> Vec3 {
-        V::previous(start)
-    }
+/// Stores the eased transforms from the previous frame in [`PreviousEasedTransform`].
+fn update_previous_eased_transforms(mut query: Query<(&Transform, &mut PreviousEasedTransform)>) {
+    for_each_mut(&mut query, |(transform, mut previous)| {
+        previous.0 = *transform;
+    });
+}
 
-    fn current(end: &V::Current) -> Vec3 {
-        V::current(end)
+/// Initializes newly added [`PreviousEasedTransform`]s to the current [`Transform`] before easing.
+fn init_previous_eased_transforms(
+    mut query: Query<(&Transform, &mut PreviousEasedTransform), Added<PreviousEasedTransform>>,
+) {
+    for (transform, mut previous) in &mut query {
+        previous.0 = *transform;
     }
 }
 
-// Required so that `()` can be used as a "null" velocity source despite it not being a component itself.
-// This can be useful if you only want to use Hermite interpolation for rotation, for example.
-//
-// This must be public, because `VelocitySource::Start` and `VelocitySource::End` are public interfaces,
-// but you can't actually create this component since the stored value is private and there are no constructors.
-#[derive(Component)]
-#[doc(hidden)]
-pub struct DummyComponent(PhantomData<()>);
-
-impl VelocitySource for () {
-    type Previous = DummyComponent;
-    type Current = DummyComponent;
+/// Inserts [`IsEasing`] for entities that are being eased, and removes it for entities that are not.
+fn update_is_easing_markers(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            Option<&TranslationEasingState>,
+            Option<&RotationEasingState>,
+            Option<&ScaleEasingState>,
+            Has<IsEasing>,
+        ),
+        Or<(
+            With<TranslationEasingState>,
+            With<RotationEasingState>,
+            With<ScaleEasingState>,
+            With<IsEasing>,
+        )>,
+    >,
+) {
+    for (entity, translation, rotation, scale, has_marker) in &query {
+        let is_easing = translation.is_some_and(|easing| {
+            easing
+                .start
+                .zip(easing.end)
+                .is_some_and(|(start, end)| !start.abs_diff_eq(end, STATIONARY_EPSILON))
+        }) || rotation.is_some_and(|easing| {
+            easing
+                .start
+                .zip(easing.end)
+                .is_some_and(|(start, end)| !start.abs_diff_eq(end, STATIONARY_EPSILON))
+        }) || scale.is_some_and(|easing| {
+            easing
+                .start
+                .zip(easing.end)
+                .is_some_and(|(start, end)| !start.abs_diff_eq(end, STATIONARY_EPSILON))
+        });
 
-    fn previous(_: &Self::Previous) -> Vec3 {
-        Vec3::ZERO
+        // Only insert or remove the marker when it changes to avoid archetype churn.
+        if is_easing && !has_marker {
+            commands.entity(entity).insert(IsEasing);
+        } else if !is_easing && has_marker {
+            commands.entity(entity).remove::<IsEasing>();
+        }
     }
+}
 
-    fn current(_: &Self::Current) -> Vec3 {
-        Vec3::ZERO
+/// Applies the [`EasingRenderOffset`] of entities on top of their eased [`Transform`].
+fn apply_render_offsets(
+    mut query: Query<(&mut Transform, &EasingRenderOffset, &mut RenderOffsetRevert)>,
+) {
+    for (mut transform, offset, mut revert) in &mut query {
+        let eased = *transform;
+        let rendered = eased.mul_transform(offset.0);
+
+        if *transform != rendered {
+            *transform = rendered;
+        }
+        revert.0 = Some(AppliedRenderOffset { eased, rendered });
     }
 }
 
-/// Stores the start and end states used for interpolating the translation of an entity.
-/// The change in translation is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
+/// Reverts the [`EasingRenderOffset`] applied by [`apply_render_offsets`].
 ///
-/// On its own, this component is not updated automatically. Enable an easing backend
-/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
-#[reflect(Component, Debug, Default)]
-pub struct TranslationEasingState {
-    /// The start translation for the interpolation.
-    pub start: Option<Vec3>,
-    /// The end translation for the interpolation.
-    pub end: Option<Vec3>,
+/// Change detection is bypassed so that reverting is not treated as a change made outside of the fixed timestep.
+fn revert_render_offsets(mut query: Query<(&mut Transform, &mut RenderOffsetRevert)>) {
+    for (mut transform, mut revert) in &mut query {
+        let Some(applied) = revert.bypass_change_detection().0.take() else {
+            continue;
+        };
+
+        // Keep changes made to the transform after the offset was applied.
+        if *transform == applied.rendered {
+            *transform.bypass_change_detection() = applied.eased;
+        }
+    }
 }
 
-/// Stores the start and end states used for interpolating the rotation of an entity.
-/// The change in rotation is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
-///
-/// On its own, this component is not updated automatically. Enable an easing backend
-/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
-#[reflect(Component, Debug, Default)]
-pub struct RotationEasingState {
-    /// The start rotation for the interpolation.
-    pub start: Option<Quat>,
-    /// The end rotation for the interpolation.
-    pub end: Option<Quat>,
+/// Inserts [`EasingCulled`] for entities that are not visible, and removes it for entities that are visible again.
+#[cfg(feature = "visibility")]
+fn update_easing_culling(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &ViewVisibility, Has<EasingCulled>),
+        Or<(
+            With<TranslationEasingState>,
+            With<RotationEasingState>,
+            With<ScaleEasingState>,
+        )>,
+    >,
+) {
+    for (entity, view_visibility, is_culled) in &query {
+        let is_visible = view_visibility.get();
+        if !is_visible && !is_culled {
+            commands.entity(entity).insert(EasingCulled);
+        } else if is_visible && is_culled {
+            commands.entity(entity).remove::<EasingCulled>();
+        }
+    }
 }
 
-/// Stores the start and end states used for interpolating the scale of an entity.
-/// The change in scale is smoothed from `start` to `end` in between [`FixedUpdate`] runs.
-///
-/// On its own, this component is not updated automatically. Enable an easing backend
-/// such as the [`TransformInterpolationPlugin`] to perform automatic interpolation.
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
-#[reflect(Component, Debug, Default)]
-pub struct ScaleEasingState {
-    /// The start scale for the interpolation.
-    pub start: Option<Vec3>,
-    /// The end scale for the interpolation.
-    pub end: Option<Vec3>,
+/// Removes [`SkipEasingOnce`] after the frame it was skipped for.
+fn remove_skip_easing_once(mut commands: Commands, query: Query<Entity, With<SkipEasingOnce>>) {
+    for entity in &query {
+        commands.entity(entity).remove::<SkipEasingOnce>();
+    }
 }
 
-fn update_last_easing_tick(
-    mut last_easing_tick: ResMut<LastEasingTick>,
-    system_change_tick: SystemChangeTick,
-) {
-    *last_easing_tick = LastEasingTick(system_change_tick.this_run());
+/// Returns `true` if the [`Transform`] was changed by the user since the entity was last eased.
+pub(crate) fn is_user_change(
+    transform: &Ref<Transform>,
+    easing_tick: Option<&EasingTick>,
+    last_easing_tick: Tick,
+    this_run: Tick,
+) -> bool {
+    // Prefer the per-entity easing tick, so that entities eased at different times are handled correctly.
+    let last_eased = easing_tick.map_or(last_easing_tick, |tick| tick.0);
+    transform.last_changed().is_newer_than(last_eased, this_run)
 }
 
 /// Resets the easing states to `None` when [`Transform`] is modified outside of the fixed timestep schedules
 /// or interpolation logic. This makes it possible to "teleport" entities in schedules like [`Update`].
+///
+/// For entities with [`TransformEasingMode::EaseToTarget`], the change is instead used as the new `end` of easing,
+/// and `start` is set to the last eased value.
+///
+/// Changes that are within the [`TeleportEpsilon`] of the `start` or `end` of easing are ignored.
+/// Easing states that were inserted along with the change, such as when loading a scene, are not reset.
+///
+/// If [`TransformEasingPlugin::emit_reset_events`] is enabled, an [`EasingResetEvent`]
+/// is sent for each entity whose easing was reset.
+///
+/// Entities with [`KeepEasingOnChange`] or [`CorrectionBlend`](correction::CorrectionBlend) are skipped.
 #[allow(clippy::type_complexity, private_interfaces)]
 pub fn reset_easing_states_on_transform_change(
     mut query: Query<
         (
+            Entity,
             Ref<Transform>,
             Option<&mut TranslationEasingState>,
             Option<&mut RotationEasingState>,
             Option<&mut ScaleEasingState>,
+            Option<&TranslationEasingMask>,
+            Option<&TransformEasingMode>,
+            Option<&EasingTick>,
         ),
         (
             Changed<Transform>,
@@ -489,49 +3194,273 @@ pub fn reset_easing_states_on_transform_change(
                 With<RotationEasingState>,
                 With<ScaleEasingState>,
             )>,
+            Without<KeepEasingOnChange>,
+            // The `end` of easing for corrected entities is intentionally offset from the `Transform`.
+            Without<correction::CorrectionBlend>,
         ),
     >,
     last_easing_tick: Res<LastEasingTick>,
+    last_easing_alpha: Res<LastEasingAlpha>,
+    epsilon: Res<TeleportEpsilon>,
     system_change_tick: SystemChangeTick,
+    reset_events: Option<ResMut<Events<EasingResetEvent>>>,
 ) {
     let this_run = system_change_tick.this_run();
+    let alpha = last_easing_alpha.0;
 
-    query.par_iter_mut().for_each(
-        |(transform, translation_easing, rotation_easing, scale_easing)| {
-            let last_changed = transform.last_changed();
-            let is_user_change = last_changed.is_newer_than(last_easing_tick.0, this_run);
+    // Returns `true` if any of the easing states were reset.
+    let reset_on_change = |(
+        _,
+        transform,
+        translation_easing,
+        rotation_easing,
+        scale_easing,
+        translation_mask,
+        mode,
+        easing_tick,
+    ): (
+        Entity,
+        Ref<Transform>,
+        Option<Mut<TranslationEasingState>>,
+        Option<Mut<RotationEasingState>>,
+        Option<Mut<ScaleEasingState>>,
+        Option<&TranslationEasingMask>,
+        Option<&TransformEasingMode>,
+        Option<&EasingTick>,
+    )| {
+        let ease_to_target = mode == Some(&TransformEasingMode::EaseToTarget);
 
-            if !is_user_change {
-                return;
-            }
+        if !is_user_change(&transform, easing_tick, last_easing_tick.0, this_run) {
+            return false;
+        }
+
+        let mut was_reset = false;
+
+        // Easing states that were inserted along with the transform, for example when a scene is loaded,
+        // already match the new transform, so the change is not a teleport.
+        let translation_easing = translation_easing.filter(|easing| !easing.is_added());
+        let rotation_easing = rotation_easing.filter(|easing| !easing.is_added());
+        let scale_easing = scale_easing.filter(|easing| !easing.is_added());
+
+        if let Some(mut translation_easing) = translation_easing {
+            if let (Some(start), Some(end)) = (translation_easing.start, translation_easing.end) {
+                // Only consider axes that are eased. Other axes can be freely modified.
+                let mask = translation_mask.map_or(BVec3::TRUE, |mask| mask.0);
+                let differs =
+                    |value: Vec3| epsilon.translation_differs(transform.translation, value, mask);
 
-            if let Some(mut translation_easing) = translation_easing {
-                if let (Some(start), Some(end)) = (translation_easing.start, translation_easing.end)
-                {
-                    if transform.translation != start && transform.translation != end {
+                if differs(start) && differs(end) {
+                    if ease_to_target {
+                        // Ease from the last eased position towards the new target.
+                        translation_easing.start = Some(start.lerp(end, alpha));
+                        translation_easing.end = Some(transform.translation);
+                    } else {
                         translation_easing.start = None;
                         translation_easing.end = None;
+                        was_reset = true;
                     }
                 }
             }
-            if let Some(mut rotation_easing) = rotation_easing {
-                if let (Some(start), Some(end)) = (rotation_easing.start, rotation_easing.end) {
-                    if transform.rotation != start && transform.rotation != end {
+        }
+        if let Some(mut rotation_easing) = rotation_easing {
+            if let (Some(start), Some(end)) = (rotation_easing.start, rotation_easing.end) {
+                let differs = |value: Quat| epsilon.rotation_differs(transform.rotation, value);
+
+                if differs(start) && differs(end) {
+                    if ease_to_target {
+                        rotation_easing.start = Some(start.slerp(end, alpha));
+                        rotation_easing.end = Some(transform.rotation);
+                    } else {
                         rotation_easing.start = None;
                         rotation_easing.end = None;
+                        was_reset = true;
                     }
                 }
             }
-            if let Some(mut scale_easing) = scale_easing {
-                if let (Some(start), Some(end)) = (scale_easing.start, scale_easing.end) {
-                    if transform.scale != start && transform.scale != end {
+        }
+        if let Some(mut scale_easing) = scale_easing {
+            if let (Some(start), Some(end)) = (scale_easing.start, scale_easing.end) {
+                let differs = |value: Vec3| epsilon.scale_differs(transform.scale, value);
+
+                if differs(start) && differs(end) {
+                    if ease_to_target {
+                        scale_easing.start = Some(start.lerp(end, alpha));
+                        scale_easing.end = Some(transform.scale);
+                    } else {
                         scale_easing.start = None;
                         scale_easing.end = None;
+                        was_reset = true;
                     }
                 }
             }
-        },
-    );
+        }
+
+        was_reset
+    };
+
+    // Events can not be sent from a parallel iterator, so only iterate serially when they are enabled.
+    if let Some(mut reset_events) = reset_events {
+        for item in &mut query {
+            let entity = item.0;
+            if reset_on_change(item) {
+                reset_events.send(EasingResetEvent {
+                    entity,
+                    reason: EasingResetReason::UserTransformChange,
+                });
+            }
+        }
+    } else {
+        for_each_mut(&mut query, |item| {
+            reset_on_change(item);
+        });
+    }
+}
+
+/// The relative margin by which the fixed timestep must be slower than the frame time
+/// for [`TransformEasingPlugin::auto_disable_when_fast`] to enable easing again.
+///
+/// This avoids toggling easing every frame when the two are close to each other.
+const AUTO_DISABLE_HYSTERESIS: f32 = 0.05;
+
+/// Updates [`EasingAutoDisabled`] based on the fixed timestep and the frame time.
+fn update_easing_auto_disabled(
+    mut auto_disabled: ResMut<EasingAutoDisabled>,
+    fixed_time: Option<Res<Time<Fixed>>>,
+    time: Res<Time<Virtual>>,
+) {
+    let Some(fixed_time) = fixed_time else {
+        return;
+    };
+
+    let frame_secs = time.delta_secs();
+
+    // Keep the current state while virtual time is paused.
+    if frame_secs == 0.0 {
+        return;
+    }
+
+    let timestep_secs = fixed_time.timestep().as_secs_f32();
+    let disabled = if auto_disabled.0 {
+        timestep_secs <= frame_secs * (1.0 + AUTO_DISABLE_HYSTERESIS)
+    } else {
+        timestep_secs <= frame_secs
+    };
+
+    auto_disabled.set_if_neq(EasingAutoDisabled(disabled));
+}
+
+/// A run condition that returns `true` on the first frame after easing is resumed,
+/// either by unpausing [`Time<Virtual>`], or by [`EasingPaused`] or [`EasingAutoDisabled`] becoming `false`.
+///
+/// While virtual time is paused, the fixed timesteps stop running, but gameplay code may still
+/// modify transforms, for example to teleport entities in a pause menu.
+///
+/// The pause states are combined into a single condition that observes them every frame,
+/// so that the initial insertion of the resources is not mistaken for a change.
+fn easing_resumed(
+    time: Option<Res<Time<Virtual>>>,
+    paused: Res<EasingPaused>,
+    auto_disabled: Res<EasingAutoDisabled>,
+    mut was_disabled: Local<bool>,
+) -> bool {
+    let disabled = time.is_some_and(|time| time.is_paused()) || paused.0 || auto_disabled.0;
+    let resumed = *was_disabled && !disabled;
+    *was_disabled = disabled;
+    resumed
+}
+
+/// Rebases the `start` of each easing state when easing or [`Time<Virtual>`] is resumed,
+/// so that easing continues from the current transform without a jump.
+///
+/// The overstep usually keeps its value from before the pause, so the `start` is moved such that
+/// linear easing from it to the `end` produces the current transform at the current easing alpha.
+/// When the alpha is close to `1.0`, the remaining easing can't be stretched, and the entity snaps to the `end`.
+///
+/// If [`TransformExtrapolationPlugin::complete_extrapolation`] or [`TransformPredictionPlugin::complete_prediction`]
+/// is enabled, extrapolated or predicted properties are not rebased, since their `start` is the true transform
+/// that is restored before the next fixed timestep. They continue easing from the current alpha instead.
+///
+/// [`TransformPredictionPlugin::complete_prediction`]: prediction::TransformPredictionPlugin::complete_prediction
+fn resume_easing_from_current_transform(
+    mut query: Query<(
+        &Transform,
+        Option<&mut TranslationEasingState>,
+        Option<&mut RotationEasingState>,
+        Option<&mut ScaleEasingState>,
+        (Option<&EasingTimeScale>, Option<&TransformEasingCurve>),
+        (Has<TranslationExtrapolation>, Has<RotationExtrapolation>),
+        (
+            Has<prediction::TranslationPrediction>,
+            Has<prediction::RotationPrediction>,
+        ),
+    )>,
+    paused: Res<EasingPaused>,
+    auto_disabled: Res<EasingAutoDisabled>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+    complete_extrapolation: Option<Res<CompleteExtrapolation>>,
+    complete_prediction: Option<Res<prediction::CompletePrediction>>,
+) {
+    if paused.0 || auto_disabled.0 {
+        return;
+    }
+
+    let complete_extrapolation = complete_extrapolation.is_some();
+    let complete_prediction = complete_prediction.is_some();
+
+    for (
+        transform,
+        translation_easing,
+        rotation_easing,
+        scale_easing,
+        (time_scale, curve),
+        (extrapolate_translation, extrapolate_rotation),
+        (predict_translation, predict_rotation),
+    ) in &mut query
+    {
+        let alpha = easing_alpha(overstep.0, time_scale, curve, &overstep_clamp);
+        let remaining = 1.0 - alpha;
+
+        // Keep the true `start` of completed extrapolation and prediction.
+        let keep_translation_start = (extrapolate_translation && complete_extrapolation)
+            || (predict_translation && complete_prediction);
+        let keep_rotation_start = (extrapolate_rotation && complete_extrapolation)
+            || (predict_rotation && complete_prediction);
+
+        if let Some(mut easing) = translation_easing.filter(|_| !keep_translation_start) {
+            if let Some(end) = easing.end {
+                easing.start = Some(rebase_vec3(transform.translation, end, alpha, remaining));
+            }
+        }
+        if let Some(mut easing) = rotation_easing.filter(|_| !keep_rotation_start) {
+            if let Some(end) = easing.end {
+                easing.start = Some(if remaining > REBASE_EPSILON {
+                    let delta = (transform.rotation * end.inverse()).to_scaled_axis();
+                    (Quat::from_scaled_axis(delta / remaining) * end).normalize()
+                } else {
+                    end
+                });
+            }
+        }
+        if let Some(mut easing) = scale_easing {
+            if let Some(end) = easing.end {
+                easing.start = Some(rebase_vec3(transform.scale, end, alpha, remaining));
+            }
+        }
+    }
+}
+
+/// The smallest remaining fraction of easing for which the `start` of easing is rebased when easing is resumed.
+const REBASE_EPSILON: f32 = 1e-4;
+
+/// Returns the `start` for which `start.lerp(end, alpha)` is equal to `current`,
+/// or `end` if almost no easing remains.
+fn rebase_vec3(current: Vec3, end: Vec3, alpha: f32, remaining: f32) -> Vec3 {
+    if remaining > REBASE_EPSILON {
+        (current - end * alpha) / remaining
+    } else {
+        end
+    }
 }
 
 /// Resets the `start` and `end` states for translation interpolation.
@@ -558,57 +3487,341 @@ fn reset_scale_easing(mut query: Query<&mut ScaleEasingState>) {
     }
 }
 
-/// Eases the translations of entities with linear interpolation.
-fn ease_translation_lerp(
+/// The easing state that stores the [`Transform`] from the end of the last fixed timestep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FixedState {
+    /// The `start` stores the fixed transform, like for extrapolation.
+    Start,
+    /// The `end` stores the fixed transform, like for interpolation.
+    End,
+}
+
+impl FixedState {
+    fn get<T: Copy>(self, start: Option<T>, end: Option<T>) -> Option<T> {
+        match self {
+            FixedState::Start => start,
+            FixedState::End => end,
+        }
+    }
+}
+
+/// Restores the translation from the last fixed timestep and resets the translation easing state to `None`.
+///
+/// This is used when the easing method of an entity is removed, so that the eased translation
+/// is not left behind and no stale state is used by another easing method.
+pub(crate) fn restore_and_reset_translation(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    state: FixedState,
+) {
+    let Some(mut easing) = world.get_mut::<TranslationEasingState>(entity) else {
+        return;
+    };
+    let fixed = state.get(easing.start, easing.end);
+    easing.start = None;
+    easing.end = None;
+
+    let mask = world.get::<TranslationEasingMask>(entity).copied();
+    if let (Some(fixed), Some(mut transform)) = (fixed, world.get_mut::<Transform>(entity)) {
+        transform.translation = match mask {
+            Some(mask) => Vec3::select(mask.0, fixed, transform.translation),
+            None => fixed,
+        };
+    }
+}
+
+/// Restores the rotation from the last fixed timestep and resets the rotation easing state to `None`.
+///
+/// This is used when the easing method of an entity is removed, so that the eased rotation
+/// is not left behind and no stale state is used by another easing method.
+pub(crate) fn restore_and_reset_rotation(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    state: FixedState,
+) {
+    let Some(mut easing) = world.get_mut::<RotationEasingState>(entity) else {
+        return;
+    };
+    let fixed = state.get(easing.start, easing.end);
+    easing.start = None;
+    easing.end = None;
+
+    if let (Some(fixed), Some(mut transform)) = (fixed, world.get_mut::<Transform>(entity)) {
+        transform.rotation = fixed;
+    }
+}
+
+/// Restores the scale from the last fixed timestep and resets the scale easing state to `None`.
+///
+/// This is used when the easing method of an entity is removed, so that the eased scale
+/// is not left behind and no stale state is used by another easing method.
+pub(crate) fn restore_and_reset_scale(
+    world: &mut DeferredWorld,
+    entity: Entity,
+    state: FixedState,
+) {
+    let Some(mut easing) = world.get_mut::<ScaleEasingState>(entity) else {
+        return;
+    };
+    let fixed = state.get(easing.start, easing.end);
+    easing.start = None;
+    easing.end = None;
+
+    if let (Some(fixed), Some(mut transform)) = (fixed, world.get_mut::<Transform>(entity)) {
+        transform.scale = fixed;
+    }
+}
+
+/// The maximum difference between the `start` and `end` of easing for them to be considered equal.
+///
+/// When they are equal, the easing systems don't write to [`Transform`] at all,
+/// so that change detection and transform propagation are not triggered for stationary entities.
+const STATIONARY_EPSILON: f32 = 1e-6;
+
+/// Computes the easing alpha from the overstep, applying the [`EasingTimeScale`] and [`TransformEasingCurve`] of an entity.
+///
+/// The scaled overstep is clamped to the range of the [`OverstepClamp`].
+pub(crate) fn easing_alpha(
+    overstep: f32,
+    time_scale: Option<&EasingTimeScale>,
+    curve: Option<&TransformEasingCurve>,
+    overstep_clamp: &OverstepClamp,
+) -> f32 {
+    let overstep = time_scale.map_or(overstep, |time_scale| {
+        time_scale.apply(overstep, overstep_clamp)
+    });
+    curve.map_or(overstep, |curve| curve.sample(overstep))
+}
+
+/// Eases a translation from `start` to `end` with linear interpolation, taking the [`WrappingTranslation`]
+/// and [`EasingStrength`] of an entity into account.
+pub(crate) fn ease_translation(
+    start: Vec3,
+    end: Vec3,
+    alpha: f32,
+    wrapping: Option<&WrappingTranslation>,
+    strength: Option<&EasingStrength>,
+) -> Vec3 {
+    // Take the shortest path across the world boundary.
+    let end = wrapping.map_or(end, |wrapping| start + wrapping.wrapped_delta(start, end));
+    let eased = start.lerp(end, alpha);
+
+    match strength {
+        // Blend from the true translation towards the eased translation.
+        Some(strength) => end.lerp(eased, strength.clamped()),
+        None => eased,
+    }
+}
+
+/// Eases a rotation from `start` to `end`, taking the [`RotationEasingMode`], [`CustomRotationInterpolator`],
+/// and [`EasingStrength`] of an entity into account.
+pub(crate) fn ease_rotation(
+    start: Quat,
+    end: Quat,
+    alpha: f32,
+    mode: Option<&RotationEasingMode>,
+    interpolator: Option<&CustomRotationInterpolator>,
+    strength: Option<&EasingStrength>,
+) -> Quat {
+    let rotation = match (interpolator, mode) {
+        (Some(interpolator), _) => interpolator.interpolate(start, end, alpha),
+        (None, Some(RotationEasingMode::Planar { axis })) => {
+            winding::planar_rotation_lerp(start, end, *axis, None, alpha)
+        }
+        (None, Some(RotationEasingMode::Nlerp)) => Nlerp.interpolate(start, end, alpha),
+        // Note: `slerp` will always take the shortest path, but when the two rotations are more than
+        // 180 degrees apart, this can cause visual artifacts as the rotation "flips" to the other side.
+        // `RotationEasingMode::RespectWinding` can be used to follow angular velocity instead.
+        _ => Slerp.interpolate(start, end, alpha),
+    };
+
+    match strength {
+        // Blend from the true rotation towards the eased rotation.
+        Some(strength) => end.slerp(rotation, strength.clamped()),
+        None => rotation,
+    }
+}
+
+/// Eases a scale from `start` to `end` with linear interpolation, taking the [`EasingStrength`]
+/// and [`ScaleSignSnap`] of an entity into account.
+pub(crate) fn ease_scale(
+    start: Vec3,
+    end: Vec3,
+    alpha: f32,
+    strength: Option<&EasingStrength>,
+    sign_snap: bool,
+) -> Vec3 {
+    let mut scale = start.lerp(end, alpha);
+
+    if let Some(strength) = strength {
+        // Blend from the true scale towards the eased scale.
+        scale = end.lerp(scale, strength.clamped());
+    }
+
+    if sign_snap {
+        // Snap axes that flip their sign instead of easing them through zero.
+        let flipped = (start * end).cmplt(Vec3::ZERO);
+        scale = Vec3::select(flipped, end, scale);
+    }
+
+    scale
+}
+
+/// Eases the translations of entities with linear interpolation, writing the result to the [`EaseTarget`] `T`.
+///
+/// Entities whose `start` and `end` are equal are skipped.
+fn ease_translation_lerp<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&mut Transform, &TranslationEasingState),
         (
+            &mut T,
+            &TranslationEasingState,
+            Option<&TransformEasingCurve>,
+            Option<&TranslationEasingMask>,
+            Option<&WrappingTranslation>,
+            Option<&EasingTimeScale>,
+            Option<&EasingStrength>,
+            Option<&EasingFlags>,
+        ),
+        (
+            F,
             Without<NonlinearTranslationEasing>,
             Without<NoTranslationEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
         ),
     >,
-    time: Res<Time<Fixed>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
 ) {
-    let overstep = time.overstep_fraction();
+    let overstep = overstep.0;
 
-    query.iter_mut().for_each(|(mut transform, interpolation)| {
-        if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
-            transform.translation = start.lerp(end, overstep);
-        }
-    });
+    for_each_mut(
+        &mut query,
+        |(mut target, interpolation, curve, mask, wrapping, time_scale, strength, flags)| {
+            if flags.is_some_and(|flags| !flags.translation) {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                if start.abs_diff_eq(end, STATIONARY_EPSILON) {
+                    // Avoid dirtying `Transform` for stationary entities.
+                    return;
+                }
+
+                let alpha = easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+                let eased = ease_translation(start, end, alpha, wrapping, strength);
+                let translation = match mask {
+                    Some(mask) => Vec3::select(mask.0, eased, target.translation()),
+                    None => eased,
+                };
+
+                // Only write when the value changes to avoid triggering change detection.
+                if target.translation() != translation {
+                    target.set_translation(translation);
+                }
+            }
+        },
+    );
 }
 
-/// Eases the rotations of entities with spherical linear interpolation.
-fn ease_rotation_slerp(
+/// Eases the rotations of entities with spherical linear interpolation, writing the result to the [`EaseTarget`] `T`.
+///
+/// Entities whose `start` and `end` are equal are skipped.
+fn ease_rotation_slerp<T: EaseTarget, F: QueryFilter>(
     mut query: Query<
-        (&mut Transform, &RotationEasingState),
-        (Without<NonlinearRotationEasing>, Without<NoRotationEasing>),
+        (
+            &mut T,
+            &RotationEasingState,
+            Option<&TransformEasingCurve>,
+            Option<&RotationEasingMode>,
+            Option<&CustomRotationInterpolator>,
+            Option<&EasingTimeScale>,
+            Option<&EasingStrength>,
+            Option<&EasingFlags>,
+        ),
+        (
+            F,
+            Without<NonlinearRotationEasing>,
+            Without<NoRotationEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
     >,
-    time: Res<Time<Fixed>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
 ) {
-    let overstep = time.overstep_fraction();
+    let overstep = overstep.0;
 
-    query
-        .par_iter_mut()
-        .for_each(|(mut transform, interpolation)| {
+    for_each_mut(
+        &mut query,
+        |(mut target, interpolation, curve, mode, interpolator, time_scale, strength, flags)| {
+            if flags.is_some_and(|flags| !flags.rotation) {
+                return;
+            }
             if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
-                // Note: `slerp` will always take the shortest path, but when the two rotations are more than
-                // 180 degrees apart, this can cause visual artifacts as the rotation "flips" to the other side.
-                transform.rotation = start.slerp(end, overstep);
+                if start.abs_diff_eq(end, STATIONARY_EPSILON) {
+                    // Avoid dirtying `Transform` for stationary entities.
+                    return;
+                }
+
+                let alpha = easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+                let rotation = ease_rotation(start, end, alpha, mode, interpolator, strength);
+
+                // Only write when the value changes to avoid triggering change detection.
+                if target.rotation() != rotation {
+                    target.set_rotation(rotation);
+                }
             }
-        });
+        },
+    );
 }
 
-/// Eases the scales of entities with linear interpolation.
-fn ease_scale_lerp(
-    mut query: Query<(&mut Transform, &ScaleEasingState), Without<NoScaleEasing>>,
-    time: Res<Time<Fixed>>,
+/// Eases the scales of entities with linear interpolation, writing the result to the [`EaseTarget`] `T`.
+///
+/// Entities whose `start` and `end` are equal are skipped.
+fn ease_scale_lerp<T: EaseTarget, F: QueryFilter>(
+    mut query: Query<
+        (
+            &mut T,
+            &ScaleEasingState,
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingStrength>,
+            Option<&EasingFlags>,
+            Has<ScaleSignSnap>,
+        ),
+        (
+            F,
+            Without<NonlinearScaleEasing>,
+            Without<NoScaleEasing>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
 ) {
-    let overstep = time.overstep_fraction();
+    let overstep = overstep.0;
 
-    query.iter_mut().for_each(|(mut transform, interpolation)| {
-        if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
-            transform.scale = start.lerp(end, overstep);
-        }
-    });
+    for_each_mut(
+        &mut query,
+        |(mut target, interpolation, curve, time_scale, strength, flags, sign_snap)| {
+            if flags.is_some_and(|flags| !flags.scale) {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                if start.abs_diff_eq(end, STATIONARY_EPSILON) {
+                    // Avoid dirtying `Transform` for stationary entities.
+                    return;
+                }
+
+                let alpha = easing_alpha(overstep, time_scale, curve, &overstep_clamp);
+                let scale = ease_scale(start, end, alpha, strength, sign_snap);
+
+                // Only write when the value changes to avoid triggering change detection.
+                if target.scale() != scale {
+                    target.set_scale(scale);
+                }
+            }
+        },
+    );
 }
@@ -0,0 +1,374 @@
+//! Scripted movement along a path of waypoints, such as for cutscene cameras and patrol routes.
+//!
+//! See the [`TransformPathPlugin`] for more information.
+
+use crate::{
+    EasingSchedules, NoRotationEasing, NoScaleEasing, NoTranslationEasing, RotationEasingState,
+    ScaleEasingState, TransformEasingPlugin, TransformEasingSet, TranslationEasingState,
+};
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+/// A plugin for moving entities along a [`TransformPath`] in the fixed timestep,
+/// with easing in between fixed timesteps.
+///
+/// Each fixed timestep, the cursor of the path is advanced by the fixed delta time,
+/// and the path is sampled at the previous and new cursor positions. The samples are used
+/// as the `start` and `end` of the [`TranslationEasingState`], [`RotationEasingState`], and [`ScaleEasingState`],
+/// and the actual easing is performed by the [`TransformEasingPlugin`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::path::*;
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(TransformPathPlugin::default());
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Patrol between three points, spending two seconds on each segment.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformPath {
+///             points: vec![
+///                 Transform::from_xyz(0.0, 0.0, 0.0),
+///                 Transform::from_xyz(10.0, 0.0, 0.0),
+///                 Transform::from_xyz(10.0, 0.0, 10.0),
+///             ],
+///             looping: true,
+///             segment_secs: 2.0,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+///
+/// The path is the ground truth, so [`Transform`] should *not* also be modified in the fixed timestep
+/// for entities following a path. Changes to [`Transform`] outside of the fixed timestep reset easing as usual.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+#[derive(Debug)]
+pub struct TransformPathPlugin {
+    /// The schedules that the path systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for TransformPathPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl TransformPathPlugin {
+    /// Sets the schedules that the path systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for TransformPathPlugin {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<(TransformPath, PathInterpolation)>();
+
+        // Update the start state of the easing at the start of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            update_path_start.in_set(TransformEasingSet::UpdateStart),
+        );
+
+        // Advance the path and update the end state of the easing at the end of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_last(),
+            advance_path.in_set(TransformEasingSet::UpdateEnd),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        // It performs the actual easing based on the start and end states set by this plugin.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// The method used for interpolating the translation between the points of a [`TransformPath`].
+///
+/// Rotation always uses spherical linear interpolation (`slerp`), and scale uses linear interpolation (`lerp`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default)]
+pub enum PathInterpolation {
+    /// Moves along straight lines between points.
+    Linear,
+    /// Moves along a smooth Catmull-Rom spline that passes through every point.
+    #[default]
+    CatmullRom,
+}
+
+/// A path of waypoints that an entity follows in the fixed timestep.
+///
+/// The position on the path is stored in the `cursor`, where the integer part is the index
+/// of the current segment and the fractional part is the progress along it. Each segment
+/// takes `segment_secs` seconds to traverse.
+///
+/// See the [`TransformPathPlugin`] for more information.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationEasingState, RotationEasingState, ScaleEasingState)]
+pub struct TransformPath {
+    /// The waypoints of the path.
+    pub points: Vec<Transform>,
+    /// If `true`, the path continues from the last point back to the first point.
+    /// Otherwise, the entity stops at the last point.
+    pub looping: bool,
+    /// The time in seconds that it takes to move from one point to the next.
+    pub segment_secs: f32,
+    /// The method used for interpolating the translation between points.
+    pub interpolation: PathInterpolation,
+    /// The position on the path, in segments.
+    pub cursor: f32,
+}
+
+impl Default for TransformPath {
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            looping: false,
+            segment_secs: 1.0,
+            interpolation: PathInterpolation::default(),
+            cursor: 0.0,
+        }
+    }
+}
+
+impl TransformPath {
+    /// Returns the number of segments in the path.
+    pub fn segment_count(&self) -> usize {
+        if self.looping {
+            self.points.len()
+        } else {
+            self.points.len().saturating_sub(1)
+        }
+    }
+
+    /// Returns `true` if the end of a non-looping path has been reached.
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.cursor >= self.segment_count() as f32
+    }
+
+    /// Advances the cursor of the path by the given time in seconds.
+    ///
+    /// The cursor wraps around for looping paths, and stops at the end for non-looping paths.
+    pub fn advance(&mut self, delta_secs: f32) {
+        let segment_count = self.segment_count() as f32;
+
+        if segment_count == 0.0 {
+            self.cursor = 0.0;
+            return;
+        }
+
+        if self.segment_secs <= 0.0 {
+            // Jump straight to the end.
+            self.cursor = if self.looping { 0.0 } else { segment_count };
+            return;
+        }
+
+        self.cursor += delta_secs / self.segment_secs;
+
+        if self.looping {
+            self.cursor = self.cursor.rem_euclid(segment_count);
+        } else {
+            self.cursor = self.cursor.clamp(0.0, segment_count);
+        }
+    }
+
+    /// Samples the path at the given cursor position, or returns `None` if the path has no points.
+    pub fn sample(&self, cursor: f32) -> Option<Transform> {
+        let len = self.points.len();
+        let segment_count = self.segment_count();
+
+        if segment_count == 0 {
+            return self.points.first().copied();
+        }
+
+        let cursor = cursor.clamp(0.0, segment_count as f32);
+        let index = (cursor as usize).min(segment_count - 1);
+        let t = cursor - index as f32;
+
+        // Get the point at the given offset from the start of the segment,
+        // wrapping around for looping paths and clamping to the ends otherwise.
+        let point = |offset: isize| {
+            let i = index as isize + offset;
+            let i = if self.looping {
+                i.rem_euclid(len as isize)
+            } else {
+                i.clamp(0, len as isize - 1)
+            };
+            &self.points[i as usize]
+        };
+
+        let (p0, p1, p2, p3) = (point(-1), point(0), point(1), point(2));
+
+        let translation = match self.interpolation {
+            PathInterpolation::Linear => p1.translation.lerp(p2.translation, t),
+            PathInterpolation::CatmullRom => catmull_rom(
+                p0.translation,
+                p1.translation,
+                p2.translation,
+                p3.translation,
+                t,
+            ),
+        };
+
+        Some(Transform {
+            translation,
+            rotation: p1.rotation.slerp(p2.rotation, t),
+            scale: p1.scale.lerp(p2.scale, t),
+        })
+    }
+
+    /// Samples the path at the current cursor position, or returns `None` if the path has no points.
+    pub fn current(&self) -> Option<Transform> {
+        self.sample(self.cursor)
+    }
+}
+
+/// Evaluates a uniform Catmull-Rom spline segment between `p1` and `p2` at `t`.
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Applies the current sample of the path to the [`Transform`] so that the previous easing is fully applied,
+/// and uses it as the `start` of easing.
+fn update_path_start(
+    mut query: Query<(
+        &mut Transform,
+        &TransformPath,
+        &mut TranslationEasingState,
+        &mut RotationEasingState,
+        &mut ScaleEasingState,
+        Has<NoTranslationEasing>,
+        Has<NoRotationEasing>,
+        Has<NoScaleEasing>,
+    )>,
+) {
+    for (
+        mut transform,
+        path,
+        mut translation_easing,
+        mut rotation_easing,
+        mut scale_easing,
+        no_translation,
+        no_rotation,
+        no_scale,
+    ) in &mut query
+    {
+        let Some(current) = path.current() else {
+            continue;
+        };
+
+        // Only write when the value changes to avoid triggering change detection.
+        if !no_translation {
+            if transform.translation != current.translation {
+                transform.translation = current.translation;
+            }
+            translation_easing.start = Some(current.translation);
+        }
+        if !no_rotation {
+            if transform.rotation != current.rotation {
+                transform.rotation = current.rotation;
+            }
+            rotation_easing.start = Some(current.rotation);
+        }
+        if !no_scale {
+            if transform.scale != current.scale {
+                transform.scale = current.scale;
+            }
+            scale_easing.start = Some(current.scale);
+        }
+    }
+}
+
+/// Advances each [`TransformPath`] by the fixed timestep and sets the `end` of easing to the new path sample.
+fn advance_path(
+    mut query: Query<(
+        &mut TransformPath,
+        &mut TranslationEasingState,
+        &mut RotationEasingState,
+        &mut ScaleEasingState,
+        Has<NoTranslationEasing>,
+        Has<NoRotationEasing>,
+        Has<NoScaleEasing>,
+    )>,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    let delta_secs = time.delta_secs();
+
+    for (
+        mut path,
+        mut translation_easing,
+        mut rotation_easing,
+        mut scale_easing,
+        no_translation,
+        no_rotation,
+        no_scale,
+    ) in &mut query
+    {
+        let Some(previous) = path.current() else {
+            continue;
+        };
+
+        path.advance(delta_secs);
+
+        let Some(current) = path.current() else {
+            continue;
+        };
+
+        // Backfill `start` for paths added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        if !no_translation {
+            translation_easing.start.get_or_insert(previous.translation);
+            translation_easing.end = Some(current.translation);
+        }
+        if !no_rotation {
+            rotation_easing.start.get_or_insert(previous.rotation);
+            rotation_easing.end = Some(current.rotation);
+        }
+        if !no_scale {
+            scale_easing.start.get_or_insert(previous.scale);
+            scale_easing.end = Some(current.scale);
+        }
+    }
+}
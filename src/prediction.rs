@@ -0,0 +1,449 @@
+//! Acceleration-aware [`Transform`] prediction, making movement in [`FixedUpdate`] appear smooth
+//! by easing between the current and predicted [`Transform`] in between fixed timesteps.
+//!
+//! See the [`TransformPredictionPlugin`] for more information.
+
+use core::marker::PhantomData;
+
+use crate::{
+    EasingPaused, EasingSchedules, NoRotationEasing, NoTranslationEasing, RotationEasingState,
+    TransformEasingPlugin, TransformEasingSet, TranslationEasingMask, TranslationEasingState,
+    VelocitySource, VelocitySourceItem,
+};
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+/// A plugin for acceleration-aware [`Transform`] prediction, making movement in [`FixedUpdate`] appear smooth.
+///
+/// Like [extrapolation], prediction eases between the current [`Transform`] and a predicted [`Transform`]
+/// in between fixed timesteps. However, the prediction also takes acceleration into account,
+/// which makes it more accurate for objects whose velocity changes smoothly, such as falling objects
+/// or vehicles speeding up.
+///
+/// How far the predicted [`Transform`] is trusted can be tuned per entity with the [`PredictionBlend`] component.
+/// A blend factor of `0.0` keeps the entity at the authoritative current [`Transform`],
+/// while a blend factor of `1.0` (the default) eases all the way to the predicted [`Transform`].
+/// Values in between trade responsiveness for smaller visual errors on mispredictions.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+///
+/// [extrapolation]: crate::extrapolation::TransformExtrapolationPlugin
+///
+/// # Usage
+///
+/// Prediction requires velocity and, optionally, acceleration. Both are provided with the [`VelocitySource`] trait,
+/// using the `current` value of the source. If an entity has no acceleration component, or the acceleration source is `()`,
+/// the prediction is purely velocity-based.
+///
+/// ```
+/// use bevy::{ecs::query::QueryData, prelude::*};
+/// use bevy_transform_interpolation::{prediction::*, VelocitySource};
+///
+/// #[derive(Component, Default)]
+/// struct LinearVelocity(Vec3);
+///
+/// #[derive(Component, Default)]
+/// struct LinearAcceleration(Vec3);
+///
+/// #[derive(QueryData)]
+/// struct LinVelSource;
+///
+/// impl VelocitySource for LinVelSource {
+///     type Previous = LinearVelocity;
+///     type Current = LinearVelocity;
+///
+///     fn previous(start: &Self::Previous) -> Vec3 {
+///         start.0
+///     }
+///
+///     fn current(end: &Self::Current) -> Vec3 {
+///         end.0
+///     }
+/// }
+///
+/// #[derive(QueryData)]
+/// struct LinAccSource;
+///
+/// impl VelocitySource for LinAccSource {
+///     type Previous = LinearAcceleration;
+///     type Current = LinearAcceleration;
+///
+///     fn previous(start: &Self::Previous) -> Vec3 {
+///         start.0
+///     }
+///
+///     fn current(end: &Self::Current) -> Vec3 {
+///         end.0
+///     }
+/// }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     // Predict translation with linear velocity and acceleration. Rotation is not predicted.
+///     app.add_plugins(TransformPredictionPlugin::<LinVelSource, (), LinAccSource, ()>::default());
+///
+///     // ...
+///
+///     app.run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Predict translation, but only ease halfway to the predicted translation.
+///     commands.spawn((
+///         Transform::default(),
+///         TranslationPrediction,
+///         PredictionBlend(0.5),
+///         LinearVelocity(Vec3::X),
+///         LinearAcceleration(Vec3::NEG_Y * 9.81),
+///     ));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TransformPredictionPlugin<
+    LinVel: VelocitySource,
+    AngVel: VelocitySource,
+    LinAcc: VelocitySource = (),
+    AngAcc: VelocitySource = (),
+> {
+    /// If `true`, predicted entities are always snapped to their true [`Transform`] from the end
+    /// of the previous fixed timestep when the next fixed timestep starts, even while [`EasingPaused`] is set.
+    ///
+    /// This matches interpolation, which always completes the previous easing before the next fixed timestep.
+    /// The prediction states are then also updated while paused, and resuming easing continues
+    /// predicting from the true [`Transform`].
+    ///
+    /// By default, the prediction systems are skipped while easing is paused, so pausing easing
+    /// in between fixed timesteps keeps the partially predicted [`Transform`] for the simulation.
+    ///
+    /// See also [`TransformExtrapolationPlugin::complete_extrapolation`].
+    ///
+    /// Default: `false`
+    ///
+    /// [`TransformExtrapolationPlugin::complete_extrapolation`]: crate::extrapolation::TransformExtrapolationPlugin::complete_extrapolation
+    pub complete_prediction: bool,
+    /// The schedules that the prediction systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<(LinVel, AngVel, LinAcc, AngAcc)>,
+}
+
+impl<
+        LinVel: VelocitySource,
+        AngVel: VelocitySource,
+        LinAcc: VelocitySource,
+        AngAcc: VelocitySource,
+    > Default for TransformPredictionPlugin<LinVel, AngVel, LinAcc, AngAcc>
+{
+    fn default() -> Self {
+        Self {
+            complete_prediction: false,
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<
+        LinVel: VelocitySource,
+        AngVel: VelocitySource,
+        LinAcc: VelocitySource,
+        AngAcc: VelocitySource,
+    > TransformPredictionPlugin<LinVel, AngVel, LinAcc, AngAcc>
+{
+    /// Sets the schedules that the prediction systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<
+        LinVel: VelocitySource,
+        AngVel: VelocitySource,
+        LinAcc: VelocitySource,
+        AngAcc: VelocitySource,
+    > Plugin for TransformPredictionPlugin<LinVel, AngVel, LinAcc, AngAcc>
+{
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<(
+            TransformPrediction,
+            TranslationPrediction,
+            RotationPrediction,
+            PredictionBlend,
+        )>();
+
+        // Reset the transform to the start of the prediction at the beginning of the fixed timestep
+        // to match the true position from the end of the previous fixed tick.
+        let reset_systems = (reset_translation_prediction, reset_rotation_prediction)
+            .before(TransformEasingSet::Reset);
+
+        // Update the start and end state of the prediction at the end of the fixed timestep.
+        let update_systems = (
+            update_translation_prediction_states::<LinVel, LinAcc>,
+            update_rotation_prediction_states::<AngVel, AngAcc>,
+        )
+            .in_set(TransformEasingSet::UpdateEnd);
+
+        if self.complete_prediction {
+            // Always complete the previous prediction, like interpolation does.
+            // The states are also updated while paused, so that the true transform can be restored
+            // at the start of the next fixed timestep.
+            app.init_resource::<CompletePrediction>();
+            app.add_systems(self.schedules.fixed_first(), reset_systems);
+            app.add_systems(self.schedules.fixed_last(), update_systems);
+        } else {
+            app.add_systems(
+                self.schedules.fixed_first(),
+                reset_systems.run_if(resource_equals(EasingPaused(false))),
+            );
+            app.add_systems(
+                self.schedules.fixed_last(),
+                update_systems.run_if(resource_equals(EasingPaused(false))),
+            );
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        // It performs the actual easing based on the start and end states set by the prediction.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// A marker resource inserted when [`TransformPredictionPlugin::complete_prediction`] is enabled.
+///
+/// The `start` of predicted properties is then the true [`Transform`], so it is not rebased when easing is resumed.
+#[derive(Resource, Default)]
+pub(crate) struct CompletePrediction;
+
+/// Enables acceleration-aware [`Transform`] prediction for an entity, making changes to translation
+/// and rotation in [`FixedUpdate`] appear smooth.
+///
+/// Prediction only works for entities with velocity components.
+/// [`TransformPredictionPlugin`] must be added to the app with the appropriate velocity and acceleration sources.
+///
+/// See the [`TransformPredictionPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationPrediction, RotationPrediction)]
+pub struct TransformPrediction;
+
+/// Enables acceleration-aware translation prediction for an entity, making changes to translation
+/// in [`FixedUpdate`] appear smooth.
+///
+/// Prediction only works for entities with a linear velocity component.
+/// [`TransformPredictionPlugin`] must be added to the app with the appropriate velocity and acceleration sources.
+///
+/// See the [`TransformPredictionPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationEasingState)]
+pub struct TranslationPrediction;
+
+/// Enables acceleration-aware rotation prediction for an entity, making changes to rotation
+/// in [`FixedUpdate`] appear smooth.
+///
+/// Prediction only works for entities with an angular velocity component.
+/// [`TransformPredictionPlugin`] must be added to the app with the appropriate velocity and acceleration sources.
+///
+/// See the [`TransformPredictionPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(RotationEasingState)]
+pub struct RotationPrediction;
+
+/// Controls how far the predicted [`Transform`] is trusted for [`TransformPrediction`].
+///
+/// The easing `end` is blended from the current [`Transform`] towards the predicted [`Transform`]
+/// by this factor. A value of `0.0` disables prediction, while a value of `1.0` uses the full prediction.
+///
+/// If an entity has no [`PredictionBlend`], the full prediction is used.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct PredictionBlend(pub f32);
+
+impl Default for PredictionBlend {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Predicts the translation after `delta_secs` from the current `translation`,
+/// linear `velocity`, and linear `acceleration`, assuming constant acceleration.
+///
+/// # Example
+///
+/// For an accelerating object, the prediction matches the true trajectory exactly,
+/// unlike a purely velocity-based prediction.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::prediction::predict_translation;
+///
+/// let acceleration = Vec3::NEG_Y * 9.81;
+/// let velocity = Vec3::new(2.0, 5.0, 0.0);
+/// let delta_secs = 1.0 / 10.0;
+///
+/// // The true position after `delta_secs` with constant acceleration.
+/// let expected = velocity * delta_secs + 0.5 * acceleration * delta_secs * delta_secs;
+///
+/// let predicted = predict_translation(Vec3::ZERO, velocity, acceleration, delta_secs);
+/// let velocity_only = predict_translation(Vec3::ZERO, velocity, Vec3::ZERO, delta_secs);
+///
+/// assert!(predicted.distance(expected) < 1e-6);
+/// assert!(velocity_only.distance(expected) > predicted.distance(expected));
+/// ```
+pub fn predict_translation(
+    translation: Vec3,
+    velocity: Vec3,
+    acceleration: Vec3,
+    delta_secs: f32,
+) -> Vec3 {
+    translation + velocity * delta_secs + 0.5 * acceleration * delta_secs * delta_secs
+}
+
+/// Predicts the rotation after `delta_secs` from the current `rotation`,
+/// angular `velocity`, and angular `acceleration`, assuming constant angular acceleration.
+///
+/// The angular velocity and acceleration are expected to be in scaled axis form,
+/// like the values returned by a [`VelocitySource`].
+pub fn predict_rotation(
+    rotation: Quat,
+    velocity: Vec3,
+    acceleration: Vec3,
+    delta_secs: f32,
+) -> Quat {
+    let scaled_axis = velocity * delta_secs + 0.5 * acceleration * delta_secs * delta_secs;
+    rotation * Quat::from_scaled_axis(scaled_axis)
+}
+
+/// Resets the translation to the start of the prediction at the beginning of the fixed timestep
+/// to match the true position from the end of the previous fixed tick.
+fn reset_translation_prediction(
+    mut query: Query<
+        (
+            &mut Transform,
+            &TranslationEasingState,
+            Option<&TranslationEasingMask>,
+        ),
+        (With<TranslationPrediction>, Without<NoTranslationEasing>),
+    >,
+) {
+    for (mut transform, translation_easing, mask) in &mut query {
+        if let Some(start) = translation_easing.start {
+            transform.translation = match mask {
+                Some(mask) => Vec3::select(mask.0, start, transform.translation),
+                None => start,
+            };
+        }
+    }
+}
+
+/// Resets the rotation to the start of the prediction at the beginning of the fixed timestep
+/// to match the true position from the end of the previous fixed tick.
+fn reset_rotation_prediction(
+    mut query: Query<
+        (&mut Transform, &RotationEasingState),
+        (With<RotationPrediction>, Without<NoRotationEasing>),
+    >,
+) {
+    for (mut transform, rotation_easing) in &mut query {
+        if let Some(start) = rotation_easing.start {
+            transform.rotation = start;
+        }
+    }
+}
+
+/// Updates the start and end states of the translation prediction for the next fixed timestep.
+fn update_translation_prediction_states<V: VelocitySource, A: VelocitySource>(
+    mut query: Query<
+        (
+            &Transform,
+            &mut TranslationEasingState,
+            &V::Current,
+            Option<&A::Current>,
+            Option<&PredictionBlend>,
+        ),
+        (With<TranslationPrediction>, Without<NoTranslationEasing>),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    // Predict one fixed timestep ahead. `Time<Fixed>` is used explicitly instead of the generic `Time`,
+    // which only refers to the fixed clock when the schedule is run as a part of `FixedMain`.
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut translation_easing, vel, acc, blend) in &mut query {
+        translation_easing.start = Some(transform.translation);
+
+        // Predict the next state based on the current state, velocity, and acceleration.
+        let lin_vel = <V::Item<'static> as VelocitySourceItem<V>>::current(vel);
+        let lin_acc = acc.map_or(Vec3::ZERO, |acc| {
+            <A::Item<'static> as VelocitySourceItem<A>>::current(acc)
+        });
+        let predicted = predict_translation(transform.translation, lin_vel, lin_acc, delta_secs);
+
+        let blend = blend.map_or(1.0, |blend| blend.0);
+        translation_easing.end = Some(transform.translation.lerp(predicted, blend));
+    }
+}
+
+/// Updates the start and end states of the rotation prediction for the next fixed timestep.
+fn update_rotation_prediction_states<V: VelocitySource, A: VelocitySource>(
+    mut query: Query<
+        (
+            &Transform,
+            &mut RotationEasingState,
+            &V::Current,
+            Option<&A::Current>,
+            Option<&PredictionBlend>,
+        ),
+        (With<RotationPrediction>, Without<NoRotationEasing>),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    // Predict one fixed timestep ahead. `Time<Fixed>` is used explicitly instead of the generic `Time`,
+    // which only refers to the fixed clock when the schedule is run as a part of `FixedMain`.
+    let delta_secs = time.delta_secs();
+
+    for (transform, mut rotation_easing, vel, acc, blend) in &mut query {
+        rotation_easing.start = Some(transform.rotation);
+
+        // Predict the next state based on the current state, velocity, and acceleration.
+        let ang_vel =
+            <V::Item<'static> as VelocitySourceItem<V>>::current_angular(vel, transform.rotation);
+        let ang_acc = acc.map_or(Vec3::ZERO, |acc| {
+            <A::Item<'static> as VelocitySourceItem<A>>::current(acc)
+        });
+        let predicted = predict_rotation(transform.rotation, ang_vel, ang_acc, delta_secs);
+
+        let blend = blend.map_or(1.0, |blend| blend.0);
+        rotation_easing.end = Some(transform.rotation.slerp(predicted, blend));
+    }
+}
@@ -0,0 +1,138 @@
+//! Ready-made velocity sources and plugins for the 3D version of the [Rapier](https://github.com/dimforge/bevy_rapier) physics engine.
+//!
+//! Requires the `rapier3d` feature. The `rapier2d` and `rapier3d` features can be enabled at the same time,
+//! and the 2D integration is in the [`rapier2d`](crate::rapier2d) module.
+//!
+//! See the [`RapierInterpolationPlugin`] for more information.
+
+use bevy::{ecs::query::QueryData, prelude::*};
+use bevy_rapier3d::{plugin::PhysicsSet, prelude::Velocity};
+
+use crate::{
+    extrapolation::TransformExtrapolationPlugin, hermite::TransformHermiteEasingPlugin,
+    interpolation::TransformInterpolationPlugin, TransformEasingSet, VelocitySource,
+};
+
+/// A [`VelocitySource`] for the linear velocity stored in Rapier's [`Velocity`] component.
+///
+/// Rapier does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+#[derive(QueryData)]
+pub struct RapierLinVelSource;
+
+impl VelocitySource for RapierLinVelSource {
+    type Previous = Velocity;
+    type Current = Velocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.linvel
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.linvel
+    }
+}
+
+/// A [`VelocitySource`] for the angular velocity stored in Rapier's [`Velocity`] component.
+///
+/// Rapier does not store the velocity from the previous fixed timestep, so the current velocity
+/// is also used as the previous velocity for [Hermite interpolation](TransformHermiteEasingPlugin).
+#[derive(QueryData)]
+pub struct RapierAngVelSource;
+
+impl VelocitySource for RapierAngVelSource {
+    type Previous = Velocity;
+    type Current = Velocity;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.angvel
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.angvel
+    }
+}
+
+/// A plugin that sets up transform interpolation, extrapolation, and Hermite interpolation
+/// using the [`Velocity`] component of Rapier 3D.
+///
+/// This adds the following plugins if they have not been added yet:
+///
+/// - [`TransformInterpolationPlugin`]
+/// - [`TransformExtrapolationPlugin`] with [`RapierLinVelSource`] and [`RapierAngVelSource`]
+/// - [`TransformHermiteEasingPlugin`] with [`RapierLinVelSource`] and [`RapierAngVelSource`]
+///
+/// Easing can then be enabled for individual rigid bodies with components such as
+/// [`TransformInterpolation`](crate::interpolation::TransformInterpolation),
+/// [`TransformExtrapolation`](crate::extrapolation::TransformExtrapolation),
+/// and [`TransformHermiteEasing`](crate::hermite::TransformHermiteEasing).
+///
+/// Requires the `rapier3d` feature.
+///
+/// # Ordering
+///
+/// Rapier runs in [`PostUpdate`] by default, which is outside of the fixed timestep. For easing to work,
+/// Rapier must run in [`FixedPostUpdate`] using `RapierPhysicsPlugin::in_fixed_schedule`, so that it writes
+/// transforms in between the `start` and `end` updates in [`FixedFirst`] and [`FixedLast`].
+///
+/// This plugin also adds Rapier's [`PhysicsSet`]s in [`FixedPostUpdate`] to [`TransformEasingSet::Simulation`],
+/// so that other systems can be ordered relative to the simulation.
+///
+/// # Usage
+///
+/// See `examples/rapier_2d.rs` for a complete example in 2D.
+///
+/// ```ignore
+/// use bevy::prelude::*;
+/// use bevy_rapier3d::prelude::*;
+/// use bevy_transform_interpolation::rapier3d::RapierInterpolationPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             // Run Rapier in the fixed timestep.
+///             RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule(),
+///             RapierInterpolationPlugin,
+///         ))
+///         // ...
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct RapierInterpolationPlugin;
+
+impl Plugin for RapierInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TransformInterpolationPlugin>() {
+            app.add_plugins(TransformInterpolationPlugin::default());
+        }
+        if !app
+            .is_plugin_added::<TransformExtrapolationPlugin<RapierLinVelSource, RapierAngVelSource>>()
+        {
+            app.add_plugins(TransformExtrapolationPlugin::<
+                RapierLinVelSource,
+                RapierAngVelSource,
+            >::default());
+        }
+        if !app
+            .is_plugin_added::<TransformHermiteEasingPlugin<RapierLinVelSource, RapierAngVelSource>>()
+        {
+            app.add_plugins(TransformHermiteEasingPlugin::<
+                RapierLinVelSource,
+                RapierAngVelSource,
+            >::default());
+        }
+
+        // Mark the physics simulation as the simulation for easing.
+        app.configure_sets(
+            FixedPostUpdate,
+            (
+                PhysicsSet::SyncBackend,
+                PhysicsSet::StepSimulation,
+                PhysicsSet::Writeback,
+            )
+                .in_set(TransformEasingSet::Simulation),
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Recording of eased [`Transform`] tracks for debugging.
+//!
+//! See the [`EasingRecorderPlugin`] for more information.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use bevy::{
+    ecs::{schedule::ScheduleLabel, world::Command},
+    prelude::*,
+};
+
+use crate::{
+    EasingOverstep, EasingSchedules, RotationEasingState, ScaleEasingState, TransformEasingSet,
+    TranslationEasingState,
+};
+
+/// A debugging plugin for recording the eased [`Transform`] of entities over time.
+///
+/// Every frame, right after [`TransformEasingSet::Ease`], a [`EasingSample`] is recorded
+/// for each entity with the [`EasingRecorder`] component. The sample contains the eased [`Transform`]
+/// along with the `start` and `end` states that were used for easing.
+///
+/// The recorded samples can be exported to a CSV file with the [`ExportEasingRecording`] command.
+/// With the `serialize` feature enabled, the [`EasingRecorder`] and its samples can also be serialized
+/// with `serde` into any other format, such as JSON.
+///
+/// # Usage
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, recording::*};
+///
+/// fn setup(mut commands: Commands) {
+///     // Record the last 600 eased frames of an interpolated entity.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         EasingRecorder::with_max_samples(600),
+///     ));
+/// }
+///
+/// fn export(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+///     if keyboard_input.just_pressed(KeyCode::KeyR) {
+///         commands.queue(ExportEasingRecording::new("easing_recording.csv"));
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct EasingRecorderPlugin {
+    /// The schedules that the recording systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for EasingRecorderPlugin {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+}
+
+impl EasingRecorderPlugin {
+    /// Sets the schedules that the recording systems run in.
+    ///
+    /// Samples are recorded once per frame in the `fixed_main_loop` schedule.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for EasingRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<(EasingRecorder, EasingSample)>();
+
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            record_easing_samples
+                .after(TransformEasingSet::Ease)
+                .before(TransformEasingSet::UpdateEasingTick),
+        );
+    }
+}
+
+/// Records the eased [`Transform`] and easing states of an entity every frame.
+///
+/// Requires the [`EasingRecorderPlugin`] to be added to the app.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct EasingRecorder {
+    /// The recorded samples, from oldest to newest.
+    pub samples: Vec<EasingSample>,
+    /// The maximum number of samples to keep. When exceeded, the oldest samples are discarded.
+    ///
+    /// If `None`, samples are recorded indefinitely.
+    pub max_samples: Option<usize>,
+}
+
+impl EasingRecorder {
+    /// Creates a new [`EasingRecorder`] that keeps at most `max_samples` of the most recent samples.
+    pub const fn with_max_samples(max_samples: usize) -> Self {
+        Self {
+            samples: Vec::new(),
+            max_samples: Some(max_samples),
+        }
+    }
+
+    /// Clears all recorded samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Writes the recorded samples to the given writer in CSV format, with one row per sample.
+    ///
+    /// Missing `start` and `end` states are written as empty cells.
+    pub fn write_csv(&self, entity: Entity, writer: &mut impl Write) -> io::Result<()> {
+        for sample in &self.samples {
+            write!(writer, "{entity},{},{}", sample.elapsed_secs, sample.alpha)?;
+            write_vec3(writer, Some(sample.transform.translation))?;
+            write_quat(writer, Some(sample.transform.rotation))?;
+            write_vec3(writer, Some(sample.transform.scale))?;
+            write_vec3(writer, sample.translation.start)?;
+            write_vec3(writer, sample.translation.end)?;
+            write_quat(writer, sample.rotation.start)?;
+            write_quat(writer, sample.rotation.end)?;
+            write_vec3(writer, sample.scale.start)?;
+            write_vec3(writer, sample.scale.end)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, sample: EasingSample) {
+        if let Some(max_samples) = self.max_samples {
+            if max_samples == 0 {
+                return;
+            }
+            if self.samples.len() >= max_samples {
+                let excess = self.samples.len() + 1 - max_samples;
+                self.samples.drain(..excess);
+            }
+        }
+        self.samples.push(sample);
+    }
+}
+
+/// A single sample recorded by an [`EasingRecorder`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default)]
+pub struct EasingSample {
+    /// The elapsed time in seconds when the sample was recorded.
+    pub elapsed_secs: f32,
+    /// The interpolation alpha used for easing.
+    pub alpha: f32,
+    /// The eased [`Transform`].
+    pub transform: Transform,
+    /// The translation easing state used for easing.
+    pub translation: TranslationEasingState,
+    /// The rotation easing state used for easing.
+    pub rotation: RotationEasingState,
+    /// The scale easing state used for easing.
+    pub scale: ScaleEasingState,
+}
+
+/// A [`Command`] that exports the samples of all [`EasingRecorder`]s into a CSV file.
+///
+/// Errors are logged rather than returned.
+#[derive(Clone, Debug)]
+pub struct ExportEasingRecording {
+    /// The path of the CSV file to write.
+    pub path: PathBuf,
+    /// If `true`, the recorded samples are cleared after exporting.
+    pub clear: bool,
+}
+
+impl ExportEasingRecording {
+    /// The header row of the exported CSV file.
+    pub const CSV_HEADER: &'static str = "entity,elapsed_secs,alpha,\
+        translation_x,translation_y,translation_z,\
+        rotation_x,rotation_y,rotation_z,rotation_w,\
+        scale_x,scale_y,scale_z,\
+        translation_start_x,translation_start_y,translation_start_z,\
+        translation_end_x,translation_end_y,translation_end_z,\
+        rotation_start_x,rotation_start_y,rotation_start_z,rotation_start_w,\
+        rotation_end_x,rotation_end_y,rotation_end_z,rotation_end_w,\
+        scale_start_x,scale_start_y,scale_start_z,\
+        scale_end_x,scale_end_y,scale_end_z";
+
+    /// Creates a new [`ExportEasingRecording`] command that writes to the given `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            clear: false,
+        }
+    }
+
+    /// Clears the recorded samples after exporting them.
+    pub fn and_clear(mut self) -> Self {
+        self.clear = true;
+        self
+    }
+
+    fn export(&self, world: &mut World) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+        writeln!(writer, "{}", Self::CSV_HEADER)?;
+
+        let mut query = world.query::<(Entity, &EasingRecorder)>();
+        for (entity, recorder) in query.iter(world) {
+            recorder.write_csv(entity, &mut writer)?;
+        }
+
+        writer.flush()
+    }
+}
+
+impl Command for ExportEasingRecording {
+    fn apply(self, world: &mut World) {
+        if let Err(error) = self.export(world) {
+            error!(
+                "failed to export easing recording to `{}`: {error}",
+                self.path.display()
+            );
+            return;
+        }
+
+        if self.clear {
+            let mut query = world.query::<&mut EasingRecorder>();
+            for mut recorder in query.iter_mut(world) {
+                recorder.clear();
+            }
+        }
+    }
+}
+
+fn write_vec3(writer: &mut impl Write, value: Option<Vec3>) -> io::Result<()> {
+    match value {
+        Some(v) => write!(writer, ",{},{},{}", v.x, v.y, v.z),
+        None => write!(writer, ",,,"),
+    }
+}
+
+fn write_quat(writer: &mut impl Write, value: Option<Quat>) -> io::Result<()> {
+    match value {
+        Some(q) => write!(writer, ",{},{},{},{}", q.x, q.y, q.z, q.w),
+        None => write!(writer, ",,,,"),
+    }
+}
+
+/// Records an [`EasingSample`] for each entity with an [`EasingRecorder`].
+fn record_easing_samples(
+    mut query: Query<(
+        &Transform,
+        &mut EasingRecorder,
+        Option<&TranslationEasingState>,
+        Option<&RotationEasingState>,
+        Option<&ScaleEasingState>,
+    )>,
+    time: Res<Time>,
+    overstep: Res<EasingOverstep>,
+) {
+    let elapsed_secs = time.elapsed_secs();
+    let alpha = overstep.0;
+
+    for (transform, mut recorder, translation, rotation, scale) in &mut query {
+        recorder.push(EasingSample {
+            elapsed_secs,
+            alpha,
+            transform: *transform,
+            translation: translation.copied().unwrap_or_default(),
+            rotation: rotation.copied().unwrap_or_default(),
+            scale: scale.copied().unwrap_or_default(),
+        });
+    }
+}
@@ -0,0 +1,155 @@
+//! Frame-rate independent exponential smoothing of [`Transform`] towards a target,
+//! such as for cameras that lag slightly behind what they follow.
+//!
+//! See the [`TransformSmoothingPlugin`] for more information.
+
+use bevy::{math::StableInterpolate, prelude::*};
+
+use crate::for_each_mut;
+
+/// A plugin for smoothly moving the [`Transform`] of entities towards a [`SmoothingTarget`]
+/// every frame with exponential smoothing, configured with the [`SmoothingRate`] component.
+///
+/// # Smoothing vs. Interpolation
+///
+/// Transform interpolation eases between the last two fixed timesteps, so the rendered [`Transform`]
+/// follows the simulation exactly, just slightly behind. Once the simulation stops, the [`Transform`]
+/// reaches its final value within one fixed timestep.
+///
+/// Exponential smoothing instead moves the [`Transform`] a fraction of the remaining distance towards the target
+/// every frame in [`Update`], using `1 - exp(-rate * delta_secs)` as the interpolation factor.
+/// It does not depend on the fixed timestep at all, and the result is independent of the frame rate,
+/// but the [`Transform`] only approaches the target asymptotically and lags further behind fast targets.
+/// This "soft follow" is often desired for cameras, but it is not a substitute for interpolation
+/// when the goal is to accurately present movement from [`FixedUpdate`].
+///
+/// This plugin is independent from the [`TransformEasingPlugin`](crate::TransformEasingPlugin),
+/// and the easing state components are not used for smoothing.
+///
+/// # Usage
+///
+/// Add the [`SmoothingTarget`] and [`SmoothingRate`] components to an entity,
+/// and update the target whenever it changes.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::smoothing::*;
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(TransformSmoothingPlugin);
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3d::default(),
+///         Transform::default(),
+///         SmoothingTarget(Transform::from_xyz(0.0, 5.0, 10.0)),
+///         SmoothingRate::new(8.0, 4.0),
+///     ));
+/// }
+/// ```
+///
+/// Note that the [`Transform`] of smoothed entities is written every frame that it is not at the target,
+/// so it should not be modified directly. Modify the [`SmoothingTarget`] instead.
+#[derive(Debug, Default)]
+pub struct TransformSmoothingPlugin;
+
+impl Plugin for TransformSmoothingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<(SmoothingTarget, SmoothingRate)>();
+
+        app.add_systems(Update, smooth_transforms);
+    }
+}
+
+/// The target [`Transform`] that an entity with a [`SmoothingRate`] is smoothly moved towards.
+///
+/// The scale of the target is applied directly without smoothing.
+///
+/// See the [`TransformSmoothingPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct SmoothingTarget(pub Transform);
+
+/// The rates at which an entity is smoothly moved towards its [`SmoothingTarget`] with exponential smoothing.
+///
+/// Higher values make the entity catch up faster. A rate of `0.0` disables movement, and an infinite rate
+/// snaps the entity to the target immediately. A rate of `r` removes `1 - exp(-r)` of the remaining
+/// distance every second, so for example a rate of `5.0` closes over 99% of the distance within a second.
+///
+/// See the [`TransformSmoothingPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(SmoothingTarget)]
+pub struct SmoothingRate {
+    /// The smoothing rate for the translation.
+    ///
+    /// Default: `10.0`
+    pub translation: f32,
+    /// The smoothing rate for the rotation.
+    ///
+    /// Default: `10.0`
+    pub rotation: f32,
+}
+
+impl Default for SmoothingRate {
+    fn default() -> Self {
+        Self::new(10.0, 10.0)
+    }
+}
+
+impl SmoothingRate {
+    /// Creates a new [`SmoothingRate`] with the given translation and rotation rates.
+    pub const fn new(translation: f32, rotation: f32) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Creates a new [`SmoothingRate`] with the same rate for translation and rotation.
+    pub const fn splat(rate: f32) -> Self {
+        Self::new(rate, rate)
+    }
+}
+
+/// Moves `current` towards `target` with exponential smoothing, snapping for infinite rates.
+fn smooth<T: StableInterpolate>(current: &mut T, target: T, rate: f32, delta_secs: f32) {
+    if rate.is_finite() {
+        current.smooth_nudge(&target, rate, delta_secs);
+    } else {
+        *current = target;
+    }
+}
+
+/// Smoothly moves the transforms of entities towards their [`SmoothingTarget`].
+fn smooth_transforms(
+    mut query: Query<(&mut Transform, &SmoothingTarget, &SmoothingRate)>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for_each_mut(&mut query, |(mut transform, target, rate)| {
+        let mut smoothed = *transform;
+        smooth(
+            &mut smoothed.translation,
+            target.translation,
+            rate.translation,
+            delta_secs,
+        );
+        smooth(
+            &mut smoothed.rotation,
+            target.rotation,
+            rate.rotation,
+            delta_secs,
+        );
+        smoothed.scale = target.scale;
+
+        // Only write when the value changes to avoid triggering change detection.
+        transform.set_if_neq(smoothed);
+    });
+}
@@ -0,0 +1,424 @@
+//! Snapshot-based [`Transform`] interpolation with a configurable render delay,
+//! typically used for networked entities that receive authoritative transforms at a fixed server tick.
+//!
+//! See the [`SnapshotInterpolationPlugin`] for more information.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+use crate::{for_each_mut, EasingPaused, EasingSchedules, TransformEasingSet};
+
+/// A plugin for interpolating [`Transform`] between buffered snapshots with a configurable [`RenderDelay`].
+///
+/// Unlike the [`TransformInterpolationPlugin`](crate::interpolation::TransformInterpolationPlugin),
+/// which interpolates from the previous fixed timestep to the current one, snapshot interpolation
+/// renders entities slightly in the past. Each entity with a [`SnapshotInterpolation`] component stores a buffer
+/// of `(tick, Transform)` samples, and every frame, the two snapshots bracketing `now - delay` are interpolated.
+///
+/// This is useful for multiplayer clients receiving authoritative transforms from a server: as long as
+/// the delay is larger than the typical network jitter, there is always a snapshot to interpolate towards.
+///
+/// When the buffer runs dry, the [`Transform`] either holds at the latest snapshot or extrapolates
+/// based on the two latest snapshots, depending on the [`SnapshotUnderrun`] behavior.
+///
+/// This plugin is independent from the [`TransformEasingPlugin`](crate::TransformEasingPlugin),
+/// and the easing state components are not used for [`SnapshotInterpolation`].
+///
+/// # Usage
+///
+/// Add the plugin with the duration of a server tick, and push snapshots to the [`SnapshotInterpolation`]
+/// component of an entity whenever they arrive:
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::snapshot::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             // The server runs at 30 ticks per second.
+///             SnapshotInterpolationPlugin::new(Duration::from_secs_f64(1.0 / 30.0)),
+///         ))
+///         // Render entities 100 milliseconds in the past.
+///         .insert_resource(RenderDelay(Duration::from_millis(100)))
+///         .add_systems(Update, receive_snapshots)
+///         .run();
+/// }
+///
+/// # #[derive(Component)]
+/// # struct ServerMessage(Option<(u64, Transform)>);
+/// #
+/// fn receive_snapshots(
+///     mut query: Query<(&mut SnapshotInterpolation, &mut ServerMessage)>,
+///     mut clock: ResMut<SnapshotClock>,
+/// ) {
+///     for (mut snapshots, mut message) in &mut query {
+///         if let Some((tick, transform)) = message.0.take() {
+///             snapshots.push_snapshot(tick, transform);
+///
+///             // Keep the clock in sync with the server.
+///             clock.sync_to_tick(tick);
+///         }
+///     }
+/// }
+/// ```
+///
+/// Note that the [`Transform`] of entities with [`SnapshotInterpolation`] is written every frame,
+/// so it should not be modified directly.
+#[derive(Debug)]
+pub struct SnapshotInterpolationPlugin {
+    /// The duration of a single tick of the snapshots.
+    ///
+    /// Default: `15.625` milliseconds, matching the default fixed timestep of 64 Hz
+    pub tick_duration: Duration,
+    /// The schedules that the snapshot interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+}
+
+impl Default for SnapshotInterpolationPlugin {
+    fn default() -> Self {
+        Self::new(Duration::from_micros(15625))
+    }
+}
+
+impl SnapshotInterpolationPlugin {
+    /// Creates a new [`SnapshotInterpolationPlugin`] with the given duration of a single tick.
+    pub const fn new(tick_duration: Duration) -> Self {
+        Self {
+            tick_duration,
+            schedules: EasingSchedules::DEFAULT,
+        }
+    }
+
+    /// Sets the schedules that the snapshot interpolation systems run in.
+    ///
+    /// Snapshots are interpolated once per frame in the `fixed_main_loop` schedule.
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl Plugin for SnapshotInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<(
+            SnapshotInterpolation,
+            TransformSnapshot,
+            SnapshotUnderrun,
+            RenderDelay,
+            SnapshotClock,
+        )>();
+
+        app.init_resource::<RenderDelay>();
+        app.init_resource::<EasingPaused>();
+        app.insert_resource(SnapshotClock::new(self.tick_duration));
+
+        // Ease at the same point in the frame as the other easing backends.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            ease_snapshot_interpolation
+                .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop)
+                .before(TransformEasingSet::UpdateEasingTick)
+                .run_if(resource_equals(EasingPaused(false))),
+        );
+    }
+}
+
+/// A resource for how far in the past entities with [`SnapshotInterpolation`] are rendered.
+///
+/// A larger delay is more robust against network jitter and packet loss, but increases latency.
+///
+/// Default: `100` milliseconds
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct RenderDelay(pub Duration);
+
+impl Default for RenderDelay {
+    fn default() -> Self {
+        Self(Duration::from_millis(100))
+    }
+}
+
+/// A resource tracking the current time in terms of snapshot ticks.
+///
+/// The clock is advanced by the frame delta every frame, and can be synchronized
+/// with incoming snapshots using [`SnapshotClock::sync_to_tick`].
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Debug)]
+pub struct SnapshotClock {
+    /// The duration of a single tick.
+    pub tick_duration: Duration,
+    /// The elapsed time since tick `0`.
+    pub elapsed: Duration,
+}
+
+impl SnapshotClock {
+    /// Creates a new [`SnapshotClock`] at tick `0` with the given duration of a single tick.
+    pub const fn new(tick_duration: Duration) -> Self {
+        Self {
+            tick_duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns the current tick, including the fraction of a tick elapsed since the last whole tick.
+    pub fn current_tick(&self) -> f64 {
+        self.ticks_at(self.elapsed)
+    }
+
+    /// Returns the tick that should currently be rendered with the given `delay`.
+    pub fn render_tick(&self, delay: Duration) -> f64 {
+        self.ticks_at(self.elapsed.saturating_sub(delay))
+    }
+
+    /// Moves the clock forward to the given `tick` if it is behind it.
+    ///
+    /// This should be called when a new snapshot arrives, so that the clock does not drift
+    /// behind the source of the snapshots.
+    pub fn sync_to_tick(&mut self, tick: u64) {
+        let elapsed = Duration::from_secs_f64(tick as f64 * self.tick_duration.as_secs_f64());
+        self.elapsed = self.elapsed.max(elapsed);
+    }
+
+    fn ticks_at(&self, elapsed: Duration) -> f64 {
+        if self.tick_duration.is_zero() {
+            0.0
+        } else {
+            elapsed.as_secs_f64() / self.tick_duration.as_secs_f64()
+        }
+    }
+}
+
+/// Determines what happens when the render time of a [`SnapshotInterpolation`]
+/// is past the latest buffered snapshot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default)]
+pub enum SnapshotUnderrun {
+    /// Hold the [`Transform`] at the latest snapshot until a new one arrives.
+    #[default]
+    Hold,
+    /// Extrapolate the [`Transform`] based on the two latest snapshots,
+    /// for at most `max_ticks` ticks past the latest snapshot.
+    Extrapolate {
+        /// The maximum number of ticks to extrapolate past the latest snapshot.
+        max_ticks: f32,
+    },
+}
+
+/// A single [`Transform`] sample for a given tick, stored in a [`SnapshotInterpolation`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Debug, Default)]
+pub struct TransformSnapshot {
+    /// The tick of the snapshot.
+    pub tick: u64,
+    /// The [`Transform`] at the tick.
+    pub transform: Transform,
+}
+
+/// Interpolates the [`Transform`] of an entity between buffered snapshots.
+///
+/// See the [`SnapshotInterpolationPlugin`] for more information.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::snapshot::*;
+///
+/// let mut snapshots = SnapshotInterpolation::default();
+/// snapshots.push_snapshot(10, Transform::from_xyz(0.0, 0.0, 0.0));
+/// snapshots.push_snapshot(12, Transform::from_xyz(2.0, 0.0, 0.0));
+///
+/// // Snapshots can arrive out of order.
+/// snapshots.push_snapshot(11, Transform::from_xyz(1.0, 0.0, 0.0));
+///
+/// let sample = snapshots.sample(11.5).unwrap();
+/// assert!(sample.translation.distance(Vec3::new(1.5, 0.0, 0.0)) < 1e-6);
+///
+/// // The buffer has run dry, so the transform holds at the latest snapshot.
+/// let sample = snapshots.sample(14.0).unwrap();
+/// assert_eq!(sample.translation, Vec3::new(2.0, 0.0, 0.0));
+///
+/// // With extrapolation, the motion is continued instead.
+/// snapshots.underrun = SnapshotUnderrun::Extrapolate { max_ticks: 1.0 };
+/// let sample = snapshots.sample(12.5).unwrap();
+/// assert!(sample.translation.distance(Vec3::new(2.5, 0.0, 0.0)) < 1e-6);
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+#[reflect(Component, Debug, Default)]
+pub struct SnapshotInterpolation {
+    /// The buffered snapshots, ordered from oldest to newest.
+    pub snapshots: VecDeque<TransformSnapshot>,
+    /// The maximum number of snapshots to buffer. When exceeded, the oldest snapshots are discarded.
+    pub capacity: usize,
+    /// What happens when the render time is past the latest snapshot.
+    pub underrun: SnapshotUnderrun,
+}
+
+impl Default for SnapshotInterpolation {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl SnapshotInterpolation {
+    /// Creates a new empty [`SnapshotInterpolation`] that buffers at most `capacity` snapshots.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+            underrun: SnapshotUnderrun::Hold,
+        }
+    }
+
+    /// Sets the [`SnapshotUnderrun`] behavior.
+    pub fn with_underrun(mut self, underrun: SnapshotUnderrun) -> Self {
+        self.underrun = underrun;
+        self
+    }
+
+    /// Inserts a snapshot for the given `tick`, keeping the buffer ordered by tick.
+    ///
+    /// If a snapshot already exists for the tick, it is replaced. Snapshots older than
+    /// the oldest buffered snapshot are discarded when the buffer is full.
+    pub fn push_snapshot(&mut self, tick: u64, transform: Transform) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let snapshot = TransformSnapshot { tick, transform };
+        let index = self.snapshots.partition_point(|s| s.tick < tick);
+
+        if self.snapshots.get(index).is_some_and(|s| s.tick == tick) {
+            self.snapshots[index] = snapshot;
+            return;
+        }
+
+        if self.snapshots.len() >= self.capacity {
+            if index == 0 {
+                return;
+            }
+            self.snapshots.pop_front();
+            self.snapshots.insert(index - 1, snapshot);
+        } else {
+            self.snapshots.insert(index, snapshot);
+        }
+    }
+
+    /// Returns the latest buffered snapshot.
+    pub fn latest(&self) -> Option<&TransformSnapshot> {
+        self.snapshots.back()
+    }
+
+    /// Removes all buffered snapshots.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+
+    /// Samples the [`Transform`] at the given `tick` by interpolating between the two
+    /// bracketing snapshots.
+    ///
+    /// If the tick is before the oldest snapshot, the oldest snapshot is returned.
+    /// If it is past the latest snapshot, the [`SnapshotUnderrun`] behavior is used.
+    ///
+    /// Returns `None` if there are no snapshots.
+    pub fn sample(&self, tick: f64) -> Option<Transform> {
+        let first = self.snapshots.front()?;
+        let last = self.snapshots.back()?;
+
+        if tick <= first.tick as f64 {
+            return Some(first.transform);
+        }
+
+        if tick >= last.tick as f64 {
+            let SnapshotUnderrun::Extrapolate { max_ticks } = self.underrun else {
+                return Some(last.transform);
+            };
+            let Some(previous) = self.snapshots.iter().rev().nth(1) else {
+                return Some(last.transform);
+            };
+            let extrapolated = (tick - last.tick as f64).min(max_ticks.max(0.0) as f64);
+            return Some(lerp_snapshots(
+                previous,
+                last,
+                last.tick as f64 + extrapolated,
+            ));
+        }
+
+        // The first snapshot that is after the tick.
+        let index = self.snapshots.partition_point(|s| s.tick as f64 <= tick);
+        let start = &self.snapshots[index - 1];
+        let end = &self.snapshots[index];
+
+        Some(lerp_snapshots(start, end, tick))
+    }
+
+    /// Discards snapshots that are no longer needed for sampling at the given `tick` or later.
+    fn prune(&mut self, tick: f64) {
+        while self.snapshots.len() > 2 && self.snapshots[1].tick as f64 <= tick {
+            self.snapshots.pop_front();
+        }
+    }
+}
+
+/// Interpolates between two snapshots at the given `tick`.
+///
+/// Ticks past `end` extrapolate the motion.
+fn lerp_snapshots(start: &TransformSnapshot, end: &TransformSnapshot, tick: f64) -> Transform {
+    let span = end.tick.saturating_sub(start.tick) as f64;
+    if span <= 0.0 {
+        return end.transform;
+    }
+
+    let t = ((tick - start.tick as f64) / span) as f32;
+
+    Transform {
+        translation: start
+            .transform
+            .translation
+            .lerp(end.transform.translation, t),
+        rotation: start.transform.rotation.slerp(end.transform.rotation, t),
+        scale: start.transform.scale.lerp(end.transform.scale, t),
+    }
+}
+
+/// Advances the [`SnapshotClock`] and samples the [`Transform`] of entities
+/// with [`SnapshotInterpolation`] at the current render tick.
+fn ease_snapshot_interpolation(
+    mut query: Query<(&mut Transform, &mut SnapshotInterpolation)>,
+    mut clock: ResMut<SnapshotClock>,
+    delay: Res<RenderDelay>,
+    time: Res<Time>,
+) {
+    clock.elapsed += time.delta();
+    let render_tick = clock.render_tick(delay.0);
+
+    for_each_mut(&mut query, |(mut transform, mut snapshots)| {
+        snapshots.prune(render_tick);
+
+        if let Some(sampled) = snapshots.sample(render_tick) {
+            // Only write when the value changes to avoid triggering change detection.
+            transform.set_if_neq(sampled);
+        }
+    });
+}
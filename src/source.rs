@@ -0,0 +1,291 @@
+//! Interpolation of positions read from components other than [`Transform`],
+//! such as the `Position` and `Rotation` components of a physics engine.
+//!
+//! See the [`SourcePositionInterpolationPlugin`] for more information.
+
+use core::marker::PhantomData;
+
+use crate::{
+    EasingSchedules, NoRotationEasing, NoTranslationEasing, RotationEasingState,
+    TransformEasingPlugin, TransformEasingSet, TranslationEasingState,
+};
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+/// A trait for specifying the components that store the authoritative position and rotation of entities
+/// for the [`SourcePositionInterpolationPlugin`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::source::PositionSource;
+///
+/// #[derive(Component)]
+/// struct Position(Vec2);
+///
+/// #[derive(Component)]
+/// struct Rotation(f32);
+///
+/// struct PhysicsPositionSource;
+///
+/// impl PositionSource for PhysicsPositionSource {
+///     type Position = Position;
+///     type Rotation = Rotation;
+///
+///     fn position(position: &Self::Position) -> Vec3 {
+///         position.0.extend(0.0)
+///     }
+///
+///     fn rotation(rotation: &Self::Rotation) -> Quat {
+///         Quat::from_rotation_z(rotation.0)
+///     }
+/// }
+/// ```
+pub trait PositionSource: Send + Sync + 'static {
+    /// The component that stores the position.
+    type Position: Component;
+
+    /// The component that stores the rotation.
+    type Rotation: Component;
+
+    /// Returns the position as a translation.
+    fn position(position: &Self::Position) -> Vec3;
+
+    /// Returns the rotation.
+    fn rotation(rotation: &Self::Rotation) -> Quat;
+}
+
+/// A plugin for interpolating [`Transform`] based on positions stored in other components,
+/// making movement in [`FixedUpdate`] appear smooth.
+///
+/// Some architectures store the authoritative position of entities in custom components,
+/// such as the `Position` and `Rotation` components of a physics engine, and only use [`Transform`] for rendering.
+/// Instead of reading the `start` and `end` of interpolation from [`Transform`], this plugin reads them
+/// from the components specified by the given [`PositionSource`], and the eased result is written to [`Transform`].
+///
+/// Interpolation can be enabled for an entity with the [`SourcePositionInterpolation`] component,
+/// or the individual [`SourceTranslationInterpolation`] and [`SourceRotationInterpolation`] components.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::source::*;
+/// #
+/// # #[derive(Component)]
+/// # struct Position(Vec3);
+/// #
+/// # #[derive(Component)]
+/// # struct Rotation(Quat);
+/// #
+/// # struct PhysicsPositionSource;
+/// #
+/// # impl PositionSource for PhysicsPositionSource {
+/// #     type Position = Position;
+/// #     type Rotation = Rotation;
+/// #
+/// #     fn position(position: &Self::Position) -> Vec3 {
+/// #         position.0
+/// #     }
+/// #
+/// #     fn rotation(rotation: &Self::Rotation) -> Quat {
+/// #         rotation.0
+/// #     }
+/// # }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins(SourcePositionInterpolationPlugin::<PhysicsPositionSource>::default());
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Transform::default(),
+///         Position(Vec3::ZERO),
+///         Rotation(Quat::IDENTITY),
+///         SourcePositionInterpolation,
+///     ));
+/// }
+/// ```
+///
+/// The position source is the ground truth, so [`Transform`] should *not* also be synchronized
+/// from the source components for interpolated entities. Otherwise, the synchronization overwrites the easing,
+/// or is treated as a teleport.
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+#[derive(Debug)]
+pub struct SourcePositionInterpolationPlugin<P: PositionSource> {
+    /// The schedules that the interpolation systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<P>,
+}
+
+impl<P: PositionSource> Default for SourcePositionInterpolationPlugin<P> {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: PositionSource> SourcePositionInterpolationPlugin<P> {
+    /// Sets the schedules that the interpolation systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<P: PositionSource> Plugin for SourcePositionInterpolationPlugin<P> {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<(
+            SourcePositionInterpolation,
+            SourceTranslationInterpolation,
+            SourceRotationInterpolation,
+        )>();
+
+        // Update the start state of the interpolation at the start of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            (
+                update_translation_source_start::<P>,
+                update_rotation_source_start::<P>,
+            )
+                .in_set(TransformEasingSet::UpdateStart),
+        );
+
+        // Update the end state of the interpolation at the end of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_last(),
+            (
+                update_translation_source_end::<P>,
+                update_rotation_source_end::<P>,
+            )
+                .in_set(TransformEasingSet::UpdateEnd),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        // It performs the actual easing based on the start and end states set by this plugin.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Enables interpolation of the translation and rotation of an entity based on a [`PositionSource`].
+///
+/// See the [`SourcePositionInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(SourceTranslationInterpolation, SourceRotationInterpolation)]
+pub struct SourcePositionInterpolation;
+
+/// Enables interpolation of the translation of an entity based on the position of a [`PositionSource`].
+///
+/// See the [`SourcePositionInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(TranslationEasingState)]
+pub struct SourceTranslationInterpolation;
+
+/// Enables interpolation of the rotation of an entity based on the rotation of a [`PositionSource`].
+///
+/// See the [`SourcePositionInterpolationPlugin`] for more information.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[require(RotationEasingState)]
+pub struct SourceRotationInterpolation;
+
+fn update_translation_source_start<P: PositionSource>(
+    mut query: Query<
+        (&P::Position, &mut TranslationEasingState),
+        (
+            With<SourceTranslationInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
+    >,
+) {
+    for (position, mut easing) in &mut query {
+        let translation = P::position(position);
+        easing.start = Some(translation);
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(translation);
+        }
+    }
+}
+
+fn update_translation_source_end<P: PositionSource>(
+    mut query: Query<
+        (&P::Position, &mut TranslationEasingState),
+        (
+            With<SourceTranslationInterpolation>,
+            Without<NoTranslationEasing>,
+        ),
+    >,
+) {
+    for (position, mut easing) in &mut query {
+        let translation = P::position(position);
+        easing.end = Some(translation);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(translation);
+    }
+}
+
+fn update_rotation_source_start<P: PositionSource>(
+    mut query: Query<
+        (&P::Rotation, &mut RotationEasingState),
+        (With<SourceRotationInterpolation>, Without<NoRotationEasing>),
+    >,
+) {
+    for (rotation, mut easing) in &mut query {
+        let rotation = P::rotation(rotation);
+        easing.start = Some(rotation);
+
+        // Initialize `end` too for newly added easing states, so that the first rendered frame is stable.
+        if easing.is_added() {
+            easing.end = Some(rotation);
+        }
+    }
+}
+
+fn update_rotation_source_end<P: PositionSource>(
+    mut query: Query<
+        (&P::Rotation, &mut RotationEasingState),
+        (With<SourceRotationInterpolation>, Without<NoRotationEasing>),
+    >,
+) {
+    for (rotation, mut easing) in &mut query {
+        let rotation = P::rotation(rotation);
+        easing.end = Some(rotation);
+
+        // Backfill `start` for easing states added in the middle of the fixed timestep,
+        // after the `start` was already updated.
+        easing.start.get_or_insert(rotation);
+    }
+}
@@ -0,0 +1,299 @@
+//! Easing into components other than [`Transform`], such as custom 2D transform components
+//! or the transforms of third-party crates.
+//!
+//! See the [`EaseTargetPlugin`] for more information.
+
+use core::{any::type_name, marker::PhantomData};
+
+use crate::{
+    ease_rotation_slerp, ease_scale_lerp, ease_translation_lerp,
+    interpolation::{
+        complete_rotation_easing, complete_scale_easing, complete_translation_easing,
+        update_rotation_interpolation_end, update_rotation_interpolation_start,
+        update_scale_interpolation_end, update_scale_interpolation_start,
+        update_translation_interpolation_end, update_translation_interpolation_start,
+    },
+    EasingSchedules, TransformEasingPlugin, TransformEasingSet,
+};
+use bevy::{
+    ecs::{component::RequiredComponentsError, schedule::ScheduleLabel},
+    prelude::*,
+};
+
+/// A component that stores a translation, rotation, and scale that easing can be applied to.
+///
+/// This is implemented for [`Transform`], which is the target of easing by default.
+/// Other components can be eased with the [`EaseTargetPlugin`].
+///
+/// Components without a scale can rely on the default implementations of [`scale`](EaseTarget::scale)
+/// and [`set_scale`](EaseTarget::set_scale), which treat the scale as always being [`Vec3::ONE`].
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::target::EaseTarget;
+///
+/// #[derive(Component)]
+/// struct Transform2d {
+///     position: Vec2,
+///     angle: f32,
+/// }
+///
+/// impl EaseTarget for Transform2d {
+///     fn translation(&self) -> Vec3 {
+///         self.position.extend(0.0)
+///     }
+///
+///     fn set_translation(&mut self, translation: Vec3) {
+///         self.position = translation.truncate();
+///     }
+///
+///     fn rotation(&self) -> Quat {
+///         Quat::from_rotation_z(self.angle)
+///     }
+///
+///     fn set_rotation(&mut self, rotation: Quat) {
+///         self.angle = rotation.to_euler(EulerRot::ZYX).0;
+///     }
+/// }
+/// ```
+pub trait EaseTarget: Component {
+    /// Returns the translation.
+    fn translation(&self) -> Vec3;
+
+    /// Sets the translation.
+    fn set_translation(&mut self, translation: Vec3);
+
+    /// Returns the rotation.
+    fn rotation(&self) -> Quat;
+
+    /// Sets the rotation.
+    fn set_rotation(&mut self, rotation: Quat);
+
+    /// Returns the scale.
+    ///
+    /// Defaults to [`Vec3::ONE`].
+    fn scale(&self) -> Vec3 {
+        Vec3::ONE
+    }
+
+    /// Sets the scale.
+    ///
+    /// Does nothing by default.
+    fn set_scale(&mut self, _scale: Vec3) {}
+}
+
+impl EaseTarget for Transform {
+    fn translation(&self) -> Vec3 {
+        self.translation
+    }
+
+    fn set_translation(&mut self, translation: Vec3) {
+        self.translation = translation;
+    }
+
+    fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    fn set_rotation(&mut self, rotation: Quat) {
+        self.rotation = rotation;
+    }
+
+    fn scale(&self) -> Vec3 {
+        self.scale
+    }
+
+    fn set_scale(&mut self, scale: Vec3) {
+        self.scale = scale;
+    }
+}
+
+/// A marker component for entities whose easing is applied to an [`EaseTarget`] other than [`Transform`].
+///
+/// This is inserted automatically for entities with the target component of an [`EaseTargetPlugin`],
+/// and excludes them from the interpolation and easing of [`Transform`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default)]
+pub struct CustomEaseTarget;
+
+/// A plugin for interpolating a component other than [`Transform`] that implements [`EaseTarget`],
+/// making changes to it in [`FixedUpdate`] appear smooth.
+///
+/// Entities with the target component `T` use the normal interpolation components, such as
+/// [`TransformInterpolation`], but the `start` and `end` of interpolation are read from `T`,
+/// and the eased result is written to `T` instead of [`Transform`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, target::*};
+/// #
+/// # #[derive(Component, Default)]
+/// # struct Transform2d {
+/// #     position: Vec2,
+/// #     angle: f32,
+/// # }
+/// #
+/// # impl EaseTarget for Transform2d {
+/// #     fn translation(&self) -> Vec3 {
+/// #         self.position.extend(0.0)
+/// #     }
+/// #
+/// #     fn set_translation(&mut self, translation: Vec3) {
+/// #         self.position = translation.truncate();
+/// #     }
+/// #
+/// #     fn rotation(&self) -> Quat {
+/// #         Quat::from_rotation_z(self.angle)
+/// #     }
+/// #
+/// #     fn set_rotation(&mut self, rotation: Quat) {
+/// #         self.angle = rotation.to_euler(EulerRot::ZYX).0;
+/// #     }
+/// # }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((
+///         TransformInterpolationPlugin::default(),
+///         EaseTargetPlugin::<Transform2d>::default(),
+///     ));
+///
+///     // ...
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Interpolate the `Transform2d` instead of the `Transform`.
+///     commands.spawn((Transform2d::default(), TransformInterpolation));
+/// }
+/// ```
+///
+/// Only the core interpolation with `lerp` and `slerp` is supported for custom targets, including the
+/// [`TransformEasingCurve`], [`RotationEasingMode`], [`EasingFlags`], and other configuration read by it.
+/// Extrapolation, Hermite interpolation, and other features that operate on [`Transform`] directly are not applied
+/// to custom targets. Changes to the target component outside of the fixed timestep are not detected as teleports,
+/// so the easing should be reset manually, such as with [`ResetEasing`].
+///
+/// This plugin requires the [`TransformEasingPlugin`] to function. It is automatically added
+/// if not already present in the app.
+///
+/// [`TransformInterpolation`]: crate::interpolation::TransformInterpolation
+/// [`TransformEasingCurve`]: crate::TransformEasingCurve
+/// [`RotationEasingMode`]: crate::RotationEasingMode
+/// [`EasingFlags`]: crate::EasingFlags
+/// [`ResetEasing`]: crate::commands::ResetEasing
+#[derive(Debug)]
+pub struct EaseTargetPlugin<T: EaseTarget> {
+    /// The schedules that the easing systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`].
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<T>,
+}
+
+impl<T: EaseTarget> Default for EaseTargetPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: EaseTarget> EaseTargetPlugin<T> {
+    /// Sets the schedules that the easing systems run in.
+    /// The [`TransformEasingPlugin`] added by this plugin also uses the same schedules.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<T: EaseTarget> Plugin for EaseTargetPlugin<T> {
+    fn build(&self, app: &mut App) {
+        // Register components.
+        app.register_type::<CustomEaseTarget>();
+
+        // Exclude entities with the target component from the easing of `Transform`.
+        // Registration fails if entities with the target component already exist,
+        // in which case their `Transform` would also be eased.
+        if let Err(RequiredComponentsError::ArchetypeExists(_)) =
+            app.try_register_required_components::<T, CustomEaseTarget>()
+        {
+            warn!(
+                "`EaseTargetPlugin<{}>` was added after entities with the target component were spawned, \
+                so their `Transform` may also be eased. Add the plugin before spawning them.",
+                type_name::<T>()
+            );
+        }
+
+        // Make sure the previous easing is fully applied before the next easing starts.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            (
+                complete_translation_easing::<T, ()>,
+                complete_rotation_easing::<T, ()>,
+                complete_scale_easing::<T, ()>,
+            )
+                .chain()
+                .before(TransformEasingSet::Reset),
+        );
+
+        // Update the start state of the interpolation at the start of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            (
+                update_translation_interpolation_start::<T, ()>,
+                update_rotation_interpolation_start::<T, ()>,
+                update_scale_interpolation_start::<T, ()>,
+            )
+                .chain()
+                .in_set(TransformEasingSet::UpdateStart),
+        );
+
+        // Update the end state of the interpolation at the end of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_last(),
+            (
+                update_translation_interpolation_end::<T, ()>,
+                update_rotation_interpolation_end::<T, ()>,
+                update_scale_interpolation_end::<T, ()>,
+            )
+                .chain()
+                .in_set(TransformEasingSet::UpdateEnd),
+        );
+
+        // Perform easing.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            (
+                ease_translation_lerp::<T, ()>,
+                ease_rotation_slerp::<T, ()>,
+                ease_scale_lerp::<T, ()>,
+            )
+                .in_set(TransformEasingSet::Ease),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Add the `TransformEasingPlugin` if it hasn't been added yet.
+        if !app.is_plugin_added::<TransformEasingPlugin>() {
+            app.add_plugins(TransformEasingPlugin {
+                schedules: self.schedules,
+                ..default()
+            });
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! A minimal headless app for testing easing behavior, mainly intended for tests of this crate
+//! and of crates building on it.
+//!
+//! Requires the `test-support` feature.
+//!
+//! See [`EasingTestApp`] for more information. For tests of moving entities, the app can move entities
+//! with a [`LinearVelocity`] in [`FixedUpdate`] with [`EasingTestApp::with_movement`].
+
+use core::time::Duration;
+
+use bevy::{
+    app::{Plugins, PluginsState},
+    ecs::system::EntityCommands,
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+
+use crate::EasingOverstep;
+
+/// A headless [`App`] for testing easing behavior with full control over time.
+///
+/// Time only advances through [`advance_render`], [`step_fixed`], and the other stepping methods.
+/// Each call runs one frame of the real main schedule, so the fixed timesteps in [`RunFixedMainLoop`],
+/// the easing systems, and the schedules around them all run in the same order as in a real app.
+///
+/// The app includes the [`MinimalPlugins`], [`TransformPlugin`], and [`HierarchyPlugin`].
+/// Easing plugins are added with [`with_plugins`], and entities with a [`LinearVelocity`]
+/// are moved in [`FixedUpdate`] if the app was created with [`with_movement`].
+///
+/// [`advance_render`]: EasingTestApp::advance_render
+/// [`step_fixed`]: EasingTestApp::step_fixed
+/// [`with_plugins`]: EasingTestApp::with_plugins
+/// [`with_movement`]: EasingTestApp::with_movement
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, test_support::EasingTestApp};
+///
+/// #[derive(Component)]
+/// struct Velocity(Vec3);
+///
+/// fn movement(mut query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
+///     for (mut transform, velocity) in &mut query {
+///         transform.translation += velocity.0 * time.delta_secs();
+///     }
+/// }
+///
+/// let mut app = EasingTestApp::new()
+///     .with_fixed_hz(10.0)
+///     .with_plugins(TransformInterpolationPlugin::default());
+/// app.app_mut().add_systems(FixedUpdate, movement);
+///
+/// let entity = app.spawn((
+///     Transform::default(),
+///     TransformInterpolation,
+///     Velocity(Vec3::new(10.0, 0.0, 0.0)),
+/// ));
+///
+/// // Run two fixed timesteps, moving the entity from 0 to 1 and then from 1 to 2.
+/// app.step_fixed();
+/// app.step_fixed();
+///
+/// // Halfway to the next fixed timestep, the entity is eased halfway between 1 and 2.
+/// app.advance_to_overstep(0.5);
+/// let translation = app.transform(entity).translation;
+/// assert!(translation.abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4));
+/// ```
+///
+/// Systems in [`Startup`] run when the app is started. Easing states are initialized lazily on the first
+/// fixed timestep, so entities positioned in [`Startup`] stay where they were placed:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{
+///     prelude::*, test_support::EasingTestApp, TranslationEasingState,
+/// };
+///
+/// #[derive(Component)]
+/// struct Crate;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn((Transform::default(), Crate));
+/// }
+///
+/// fn position(mut query: Query<&mut Transform, With<Crate>>) {
+///     for mut transform in &mut query {
+///         transform.translation = Vec3::new(5.0, 0.0, 0.0);
+///     }
+/// }
+///
+/// let mut app =
+///     EasingTestApp::new().with_plugins(TransformInterpolationPlugin::interpolate_all());
+/// app.app_mut()
+///     .add_systems(Startup, (spawn, position).chain());
+///
+/// app.step_fixed();
+/// app.advance_to_overstep(0.5);
+///
+/// let world = app.world_mut();
+/// let (entity, &easing) = world
+///     .query_filtered::<(Entity, &TranslationEasingState), With<Crate>>()
+///     .single(world);
+/// assert_eq!(easing.start, Some(Vec3::new(5.0, 0.0, 0.0)));
+/// assert_eq!(easing.end, Some(Vec3::new(5.0, 0.0, 0.0)));
+/// assert_eq!(app.transform(entity).translation, Vec3::new(5.0, 0.0, 0.0));
+/// ```
+///
+/// Moving entities can also be spawned with [`spawn_moving`](EasingTestApp::spawn_moving).
+/// The first example can then be written as:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, test_support::EasingTestApp};
+///
+/// let mut app = EasingTestApp::new()
+///     .with_fixed_hz(10.0)
+///     .with_movement()
+///     .with_plugins(TransformInterpolationPlugin::default());
+///
+/// let entity = app.spawn_moving(TransformInterpolation);
+/// app.step_fixed_n(2);
+///
+/// app.advance_to_overstep(0.5);
+/// let translation = app.transform(entity).translation;
+/// assert!(translation.abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4));
+/// ```
+pub struct EasingTestApp {
+    app: App,
+    started: bool,
+}
+
+impl Default for EasingTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EasingTestApp {
+    /// Creates a new [`EasingTestApp`] with the default fixed timestep of 64 Hz and no easing plugins.
+    pub fn new() -> Self {
+        let mut app = App::new();
+
+        app.add_plugins((MinimalPlugins, TransformPlugin, HierarchyPlugin));
+
+        // Advance time manually instead of based on the real clock.
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+
+        // Allow arbitrarily large render frames, so that tests can run several fixed timesteps at once.
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::MAX);
+
+        Self {
+            app,
+            started: false,
+        }
+    }
+
+    /// Adds the given plugins to the app, such as the [`TransformInterpolationPlugin`].
+    ///
+    /// Plugins must be added before the app is started by stepping it or spawning entities.
+    ///
+    /// [`TransformInterpolationPlugin`]: crate::interpolation::TransformInterpolationPlugin
+    pub fn with_plugins<M>(mut self, plugins: impl Plugins<M>) -> Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+
+    /// Moves entities with a [`LinearVelocity`] in [`FixedUpdate`] with [`apply_linear_velocity`].
+    pub fn with_movement(mut self) -> Self {
+        self.app.add_systems(FixedUpdate, apply_linear_velocity);
+        self
+    }
+
+    /// Sets the fixed timestep frequency in Hertz.
+    pub fn with_fixed_hz(self, hz: f64) -> Self {
+        self.with_timestep(Duration::from_secs_f64(1.0 / hz))
+    }
+
+    /// Sets the fixed timestep.
+    pub fn with_timestep(mut self, timestep: Duration) -> Self {
+        self.app
+            .world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .set_timestep(timestep);
+        self
+    }
+
+    /// Returns a reference to the underlying [`App`].
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns a mutable reference to the underlying [`App`].
+    ///
+    /// This can be used for adding systems and resources before the app is started.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Returns a reference to the [`World`] of the app.
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Returns a mutable reference to the [`World`] of the app, starting the app if it hasn't been started yet.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.start();
+        self.app.world_mut()
+    }
+
+    /// Spawns an entity with the given bundle, starting the app if it hasn't been started yet.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.world_mut().spawn(bundle).id()
+    }
+
+    /// Spawns an entity with the given bundle and a [`LinearVelocity`] that moves it along the X axis
+    /// by one unit per fixed timestep, starting the app if it hasn't been started yet.
+    ///
+    /// The app must be created with [`with_movement`](EasingTestApp::with_movement) for the entity to move.
+    pub fn spawn_moving(&mut self, bundle: impl Bundle) -> Entity {
+        let speed = 1.0 / self.timestep().as_secs_f32();
+        self.spawn((bundle, LinearVelocity(Vec2::new(speed, 0.0))))
+    }
+
+    /// Runs the given function with [`EntityCommands`] for the given entity, and applies the commands.
+    ///
+    /// This is useful for commands like [`TeleportAndReset`](crate::commands::TeleportAndReset).
+    pub fn entity_commands(&mut self, entity: Entity, func: impl FnOnce(&mut EntityCommands)) {
+        let world = self.world_mut();
+        func(&mut world.commands().entity(entity));
+        world.flush();
+    }
+
+    /// Returns the [`Transform`] of the given entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not exist or has no [`Transform`].
+    pub fn transform(&self, entity: Entity) -> Transform {
+        *self
+            .world()
+            .get::<Transform>(entity)
+            .expect("entity should have a `Transform`")
+    }
+
+    /// Returns the fixed timestep.
+    pub fn timestep(&self) -> Duration {
+        self.world().resource::<Time<Fixed>>().timestep()
+    }
+
+    /// Returns the [`EasingOverstep`] used for easing on the last frame.
+    pub fn overstep(&self) -> f32 {
+        self.world()
+            .get_resource::<EasingOverstep>()
+            .map_or(0.0, |overstep| overstep.0)
+    }
+
+    /// Runs one render frame that advances time by `delta`, running as many fixed timesteps
+    /// as fit into the accumulated time, followed by easing.
+    pub fn advance_render(&mut self, delta: Duration) {
+        self.start();
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+        self.app.update();
+    }
+
+    /// Runs one render frame that advances time by `delta_secs` seconds.
+    ///
+    /// See [`advance_render`](EasingTestApp::advance_render) for more information.
+    pub fn advance_render_secs(&mut self, delta_secs: f32) {
+        self.advance_render(Duration::from_secs_f32(delta_secs));
+    }
+
+    /// Runs one render frame that advances time by exactly one fixed timestep.
+    ///
+    /// If there is no accumulated overstep, this runs exactly one fixed timestep,
+    /// and the [`EasingOverstep`] for the frame is `0.0`.
+    pub fn step_fixed(&mut self) {
+        let timestep = self.timestep();
+        self.advance_render(timestep);
+    }
+
+    /// Runs the given number of fixed timesteps, each in its own render frame.
+    ///
+    /// See [`step_fixed`](EasingTestApp::step_fixed) for more information.
+    pub fn step_fixed_n(&mut self, count: usize) {
+        for _ in 0..count {
+            self.step_fixed();
+        }
+    }
+
+    /// Runs one render frame that advances time until the overstep of [`Time<Fixed>`] reaches the given fraction
+    /// of the fixed timestep, between `0.0` and `1.0`.
+    ///
+    /// If the current overstep is already past the fraction, time is advanced over the next fixed timestep,
+    /// which is run as a part of the frame.
+    ///
+    /// Note that the [`EasingOverstep`] can differ from the given fraction if it is clamped
+    /// with an [`OverstepClamp`](crate::OverstepClamp) or computed with a custom overstep source.
+    pub fn advance_to_overstep(&mut self, fraction: f32) {
+        let timestep = self.timestep();
+        let overstep = self.world().resource::<Time<Fixed>>().overstep();
+        let target = timestep.mul_f32(fraction.clamp(0.0, 1.0));
+
+        let delta = if target >= overstep {
+            target - overstep
+        } else {
+            timestep - overstep + target
+        };
+        self.advance_render(delta);
+    }
+
+    /// Finishes adding plugins and primes the clocks, so that the next frame advances time by the expected amount.
+    fn start(&mut self) {
+        if self.started {
+            return;
+        }
+        self.started = true;
+
+        while self.app.plugins_state() == PluginsState::Adding {
+            bevy::tasks::tick_global_task_pools_on_main_thread();
+        }
+        self.app.finish();
+        self.app.cleanup();
+
+        // The first update only initializes the clocks without advancing them.
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+        self.app.update();
+    }
+}
+
+/// A 2D linear velocity that moves the [`Transform`] of an entity in [`FixedUpdate`]
+/// if the [`EasingTestApp`] was created with [`with_movement`](EasingTestApp::with_movement).
+///
+/// The velocity dereferences to a [`Vec2`], so it can also be used as a velocity source for extrapolation
+/// with [`LinVel2dSource`](crate::velocity::LinVel2dSource).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Deref, DerefMut)]
+#[require(Transform)]
+pub struct LinearVelocity(pub Vec2);
+
+/// Moves entities along the XY plane by their [`LinearVelocity`].
+///
+/// This is added to [`FixedUpdate`] by [`EasingTestApp::with_movement`].
+pub fn apply_linear_velocity(mut query: Query<(&mut Transform, &LinearVelocity)>, time: Res<Time>) {
+    for (mut transform, velocity) in &mut query {
+        transform.translation += velocity.extend(0.0) * time.delta_secs();
+    }
+}
@@ -0,0 +1,252 @@
+//! Ready-made [`VelocitySource`] adapters for common velocity components,
+//! and the [`TrackPreviousPlugin`] for maintaining previous velocities.
+//!
+//! See [`LinVel2dSource`], [`AngVel2dSource`], and [`TrackPreviousPlugin`] for more information.
+
+use core::{marker::PhantomData, ops::Deref};
+
+use bevy::{
+    ecs::{query::QueryData, schedule::ScheduleLabel},
+    prelude::*,
+};
+
+use crate::{EasingSchedules, TransformEasingSet, VelocitySource};
+
+/// A [`VelocitySource`] for 2D linear velocity components that dereference to a [`Vec2`].
+///
+/// The velocity is extended to a [`Vec3`] with a Z component of `0.0`.
+///
+/// `C` is the component storing the current velocity, and `P` is the component storing the previous velocity.
+/// The previous velocity is only required for some easing backends such as [Hermite interpolation],
+/// so it defaults to the same component as `C`.
+///
+/// [Hermite interpolation]: crate::hermite::TransformHermiteEasingPlugin
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{
+///     hermite::TransformHermiteEasingPlugin, prelude::*, velocity::*,
+/// };
+///
+/// #[derive(Component, Deref)]
+/// struct LinearVelocity(Vec2);
+///
+/// #[derive(Component, Deref)]
+/// struct PreviousLinearVelocity(Vec2);
+///
+/// #[derive(Component, Deref)]
+/// struct AngularVelocity(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct PreviousAngularVelocity(f32);
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             TransformInterpolationPlugin::default(),
+///             // Extrapolation only requires the current velocity.
+///             TransformExtrapolationPlugin::<
+///                 LinVel2dSource<LinearVelocity>,
+///                 AngVel2dSource<AngularVelocity>,
+///             >::default(),
+///             // Hermite interpolation also requires the previous velocity.
+///             TransformHermiteEasingPlugin::<
+///                 LinVel2dSource<LinearVelocity, PreviousLinearVelocity>,
+///                 AngVel2dSource<AngularVelocity, PreviousAngularVelocity>,
+///             >::default(),
+///         ))
+///         // ...
+///         .run();
+/// }
+/// ```
+#[derive(QueryData)]
+pub struct LinVel2dSource<C, P = C>
+where
+    C: Component,
+    P: Component,
+{
+    _phantom: PhantomData<(C, P)>,
+}
+
+impl<C, P> VelocitySource for LinVel2dSource<C, P>
+where
+    C: Component + Deref<Target = Vec2>,
+    P: Component + Deref<Target = Vec2>,
+{
+    type Previous = P;
+    type Current = C;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        start.extend(0.0)
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        end.extend(0.0)
+    }
+}
+
+/// A [`VelocitySource`] for 2D angular velocity components that dereference to an [`f32`].
+///
+/// The velocity is converted to a [`Vec3`] representing a rotation around the Z axis.
+///
+/// `C` is the component storing the current velocity, and `P` is the component storing the previous velocity.
+/// The previous velocity is only required for some easing backends such as [Hermite interpolation],
+/// so it defaults to the same component as `C`.
+///
+/// See [`LinVel2dSource`] for an example.
+///
+/// [Hermite interpolation]: crate::hermite::TransformHermiteEasingPlugin
+#[derive(QueryData)]
+pub struct AngVel2dSource<C, P = C>
+where
+    C: Component,
+    P: Component,
+{
+    _phantom: PhantomData<(C, P)>,
+}
+
+impl<C, P> VelocitySource for AngVel2dSource<C, P>
+where
+    C: Component + Deref<Target = f32>,
+    P: Component + Deref<Target = f32>,
+{
+    type Previous = P;
+    type Current = C;
+
+    fn previous(start: &Self::Previous) -> Vec3 {
+        Vec3::Z * **start
+    }
+
+    fn current(end: &Self::Current) -> Vec3 {
+        Vec3::Z * **end
+    }
+}
+
+/// A plugin that maintains a [`Previous<C>`] component storing the value of the component `C`
+/// from the start of the current fixed timestep.
+///
+/// This is useful for easing backends that require the previous velocity, such as [Hermite interpolation],
+/// without having to write a system that copies the velocity manually.
+///
+/// [`Previous<C>`] is inserted automatically when `C` is added to an entity, and is updated
+/// in [`TransformEasingSet::UpdateStart`] at the start of each fixed timestep.
+///
+/// [Hermite interpolation]: crate::hermite::TransformHermiteEasingPlugin
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{
+///     hermite::TransformHermiteEasingPlugin, prelude::*, velocity::*,
+/// };
+///
+/// #[derive(Component, Clone, Deref)]
+/// struct LinearVelocity(Vec2);
+///
+/// #[derive(Component, Clone, Deref)]
+/// struct AngularVelocity(f32);
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             TransformInterpolationPlugin::default(),
+///             // Track the previous velocities.
+///             TrackPreviousPlugin::<LinearVelocity>::default(),
+///             TrackPreviousPlugin::<AngularVelocity>::default(),
+///             // Read the previous velocities from the `Previous` components.
+///             TransformHermiteEasingPlugin::<
+///                 LinVel2dSource<LinearVelocity, Previous<LinearVelocity>>,
+///                 AngVel2dSource<AngularVelocity, Previous<AngularVelocity>>,
+///             >::default(),
+///         ))
+///         // ...
+///         .run();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TrackPreviousPlugin<C: Component + Clone> {
+    /// The schedules that the tracking systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<C>,
+}
+
+impl<C: Component + Clone> Default for TrackPreviousPlugin<C> {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Component + Clone> TrackPreviousPlugin<C> {
+    /// Sets the schedules that the tracking systems run in.
+    ///
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<C: Component + Clone> Plugin for TrackPreviousPlugin<C> {
+    fn build(&self, app: &mut App) {
+        // Initialize `Previous<C>` with the current value when `C` is added.
+        app.add_observer(init_previous::<C>);
+
+        // Store the current value at the start of the fixed timestep.
+        app.add_systems(
+            self.schedules.fixed_first(),
+            update_previous::<C>.in_set(TransformEasingSet::UpdateStart),
+        );
+    }
+}
+
+/// The value of the component `C` from the start of the current fixed timestep.
+///
+/// This is maintained by the [`TrackPreviousPlugin`].
+///
+/// [`Previous<C>`] dereferences to the target of `C`, so that it can be used directly with
+/// velocity sources such as [`LinVel2dSource`] and [`AngVel2dSource`]. Use the `0` field to access `C` itself.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Previous<C>(pub C);
+
+impl<C: Deref> Deref for Previous<C> {
+    type Target = C::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn init_previous<C: Component + Clone>(
+    trigger: Trigger<OnAdd, C>,
+    mut commands: Commands,
+    query: Query<&C, Without<Previous<C>>>,
+) {
+    let entity = trigger.entity();
+    if let Ok(current) = query.get(entity) {
+        commands.entity(entity).insert(Previous(current.clone()));
+    }
+}
+
+fn update_previous<C: Component + Clone>(mut query: Query<(&C, &mut Previous<C>)>) {
+    for (current, mut previous) in &mut query {
+        previous.0 = current.clone();
+    }
+}
@@ -0,0 +1,272 @@
+//! Rotation easing that respects the winding direction of spinning objects.
+//!
+//! See the [`RotationWindingPlugin`] for more information.
+
+use core::{
+    f32::consts::{PI, TAU},
+    marker::PhantomData,
+};
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+use crate::{
+    ease_rotation_slerp,
+    extrapolation::{FastSpin, RotationExtrapolation},
+    for_each_mut,
+    target::CustomEaseTarget,
+    CustomRotationInterpolator, EasingCulled, EasingFlags, EasingOverstep, EasingSchedules,
+    EasingTimeScale, NoRotationEasing, NonlinearRotationEasing, OverstepClamp, RotationEasingMode,
+    RotationEasingState, SkipEasingOnce, TransformEasingCurve, TransformEasingSet, VelocitySource,
+    VelocitySourceItem,
+};
+
+/// A plugin for easing rotation in the direction of angular velocity
+/// for entities with [`RotationEasingMode::RespectWinding`] or [`RotationEasingMode::Planar`].
+///
+/// By default, rotation is eased with `slerp`, which always takes the shortest path.
+/// When an object rotates by more than 180 degrees in a single fixed timestep, this makes
+/// the rotation visually flip to the other direction. This plugin uses the angular velocity
+/// from the given [`VelocitySource`] to determine which way the rotation should actually go,
+/// similar to the unwrapping done by [`hermite_quat`](crate::hermite::hermite_quat).
+///
+/// Entities with [`RotationEasingMode::RespectWinding`] or [`RotationEasingMode::Planar`]
+/// but without the velocity component fall back to the shortest path.
+///
+/// Extrapolated entities with [`FastSpin`](crate::extrapolation::FastSpin) already follow the predicted rotation,
+/// so this plugin doesn't affect them.
+///
+/// # Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::{prelude::*, velocity::AngVel2dSource, winding::*};
+///
+/// #[derive(Component, Deref)]
+/// struct AngularVelocity(f32);
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             TransformInterpolationPlugin::default(),
+///             RotationWindingPlugin::<AngVel2dSource<AngularVelocity>>::default(),
+///         ))
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // A fast spinning object that should always rotate counterclockwise.
+///     commands.spawn((
+///         Transform::default(),
+///         TransformInterpolation,
+///         RotationEasingMode::RespectWinding,
+///         AngularVelocity(50.0),
+///     ));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RotationWindingPlugin<AngVel: VelocitySource> {
+    /// The schedules that the winding systems run in.
+    /// These should match the schedules of the [`TransformEasingPlugin`](crate::TransformEasingPlugin).
+    ///
+    /// Default: [`FixedFirst`], [`FixedLast`], and [`RunFixedMainLoop`]
+    pub schedules: EasingSchedules,
+    /// Phantom data use the type parameters.
+    #[doc(hidden)]
+    pub _phantom: PhantomData<AngVel>,
+}
+
+impl<AngVel: VelocitySource> Default for RotationWindingPlugin<AngVel> {
+    fn default() -> Self {
+        Self {
+            schedules: EasingSchedules::DEFAULT,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<AngVel: VelocitySource> RotationWindingPlugin<AngVel> {
+    /// Sets the schedules that the winding systems run in.
+    ///
+    /// Rotation is eased once per frame in the `fixed_main_loop` schedule.
+    /// See [`EasingSchedules::new`] for more information.
+    pub fn with_schedules(
+        mut self,
+        fixed_first: impl ScheduleLabel,
+        fixed_last: impl ScheduleLabel,
+        fixed_main_loop: impl ScheduleLabel,
+    ) -> Self {
+        self.schedules = EasingSchedules::new(fixed_first, fixed_last, fixed_main_loop);
+        self
+    }
+}
+
+impl<AngVel: VelocitySource> Plugin for RotationWindingPlugin<AngVel> {
+    fn build(&self, app: &mut App) {
+        // Overwrite the shortest path rotation for entities that respect winding.
+        app.add_systems(
+            self.schedules.fixed_main_loop(),
+            ease_rotation_respect_winding::<AngVel>
+                .after(ease_rotation_slerp::<Transform, Without<CustomEaseTarget>>)
+                .in_set(TransformEasingSet::Ease),
+        );
+    }
+}
+
+/// Performs spherical linear interpolation from `start` to `end`, choosing the path
+/// whose rotation is closest to the given `expected_rotation`, which is represented as a scaled axis.
+///
+/// The `expected_rotation` is typically the angular velocity multiplied by the timestep.
+/// Unlike [`Quat::slerp`], this can also handle multiple full revolutions.
+///
+/// # Example
+///
+/// ```
+/// use std::f32::consts::PI;
+///
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::winding::slerp_with_winding;
+///
+/// let start = Quat::IDENTITY;
+/// let end = Quat::from_rotation_z(-0.5 * PI);
+///
+/// // Rotating counterclockwise by 270 degrees ends up at the same rotation as rotating clockwise by 90 degrees.
+/// let halfway = slerp_with_winding(start, end, Vec3::Z * 1.5 * PI, 0.5);
+/// let expected = Quat::from_rotation_z(0.75 * PI);
+/// assert!(halfway.abs_diff_eq(expected, 1e-4));
+/// ```
+pub fn slerp_with_winding(start: Quat, end: Quat, expected_rotation: Vec3, t: f32) -> Quat {
+    let mut rotation = (end * start.inverse()).to_scaled_axis();
+    let direction = rotation.normalize_or_zero();
+
+    // Add multiples of 2π to the angle to minimize its distance to the expected rotation.
+    let extra_angle = direction.dot(expected_rotation - rotation);
+    rotation += (extra_angle / TAU).round() * TAU * direction;
+
+    Quat::from_scaled_axis(rotation * t) * start
+}
+
+/// Interpolates the signed angle around the given `axis` from `start` to `end`,
+/// and applies the interpolated rotation around the axis to `start`.
+///
+/// Only the rotation around the axis is eased, so this works best when `start` and `end`
+/// only differ by a rotation around the axis, such as in 2D games rotating around [`Vec3::Z`].
+///
+/// If `expected_angle` is `None`, the angle takes the shortest path. Otherwise, multiples of 2π are added
+/// to the angle to minimize its distance to the expected angle, which is typically the angular velocity
+/// around the axis multiplied by the timestep. This can also represent multiple full revolutions.
+///
+/// # Example
+///
+/// ```
+/// use std::f32::consts::PI;
+///
+/// use bevy::prelude::*;
+/// use bevy_transform_interpolation::winding::planar_rotation_lerp;
+///
+/// let start = Quat::IDENTITY;
+/// let end = Quat::from_rotation_z(-0.5 * PI);
+///
+/// // Without an expected angle, the shortest path is taken.
+/// let halfway = planar_rotation_lerp(start, end, Vec3::Z, None, 0.5);
+/// assert!(halfway.abs_diff_eq(Quat::from_rotation_z(-0.25 * PI), 1e-4));
+///
+/// // Rotating counterclockwise by 270 degrees ends up at the same rotation as rotating clockwise by 90 degrees.
+/// let halfway = planar_rotation_lerp(start, end, Vec3::Z, Some(1.5 * PI), 0.5);
+/// assert!(halfway.abs_diff_eq(Quat::from_rotation_z(0.75 * PI), 1e-4));
+/// ```
+pub fn planar_rotation_lerp(
+    start: Quat,
+    end: Quat,
+    axis: Vec3,
+    expected_angle: Option<f32>,
+    t: f32,
+) -> Quat {
+    let axis = axis.normalize_or_zero();
+
+    // Compute the signed angles around the axis, and wrap their difference to the range [-π, π].
+    let start_angle = 2.0 * ops::atan2(start.xyz().dot(axis), start.w);
+    let end_angle = 2.0 * ops::atan2(end.xyz().dot(axis), end.w);
+    let mut angle = (end_angle - start_angle + PI).rem_euclid(TAU) - PI;
+
+    // Add multiples of 2π to the angle to minimize its distance to the expected angle.
+    if let Some(expected_angle) = expected_angle {
+        angle += ((expected_angle - angle) / TAU).round() * TAU;
+    }
+
+    Quat::from_axis_angle(axis, angle * t) * start
+}
+
+/// Eases the rotations of entities with [`RotationEasingMode::RespectWinding`] or [`RotationEasingMode::Planar`]
+/// in the direction of their angular velocity.
+///
+/// Entities with a [`CustomRotationInterpolator`] are skipped, since it takes precedence over the [`RotationEasingMode`].
+/// Extrapolated entities with [`FastSpin`] are also skipped, since they are eased along the predicted rotation
+/// by the [`TransformExtrapolationPlugin`](crate::extrapolation::TransformExtrapolationPlugin) instead.
+fn ease_rotation_respect_winding<V: VelocitySource>(
+    mut query: Query<
+        (
+            &mut Transform,
+            &RotationEasingState,
+            &RotationEasingMode,
+            &V::Current,
+            Option<&TransformEasingCurve>,
+            Option<&EasingTimeScale>,
+            Option<&EasingFlags>,
+        ),
+        (
+            Without<NonlinearRotationEasing>,
+            Without<NoRotationEasing>,
+            Without<CustomRotationInterpolator>,
+            Or<(Without<FastSpin>, Without<RotationExtrapolation>)>,
+            Without<CustomEaseTarget>,
+            Without<SkipEasingOnce>,
+            Without<EasingCulled>,
+        ),
+    >,
+    time: Option<Res<Time<Fixed>>>,
+    overstep: Res<EasingOverstep>,
+    overstep_clamp: Res<OverstepClamp>,
+) {
+    // Without a fixed timestep, there is nothing to ease.
+    let Some(time) = time else {
+        return;
+    };
+
+    let overstep = overstep.0;
+    let delta_secs = time.delta_secs();
+
+    for_each_mut(
+        &mut query,
+        |(mut transform, interpolation, mode, ang_vel, curve, time_scale, flags)| {
+            if !matches!(
+                mode,
+                RotationEasingMode::RespectWinding | RotationEasingMode::Planar { .. }
+            ) || flags.is_some_and(|flags| !flags.rotation)
+            {
+                return;
+            }
+            if let (Some(start), Some(end)) = (interpolation.start, interpolation.end) {
+                let overstep = time_scale.map_or(overstep, |time_scale| {
+                    time_scale.apply(overstep, &overstep_clamp)
+                });
+                let alpha = curve.map_or(overstep, |curve| curve.sample(overstep));
+                let ang_vel =
+                    <V::Item<'static> as VelocitySourceItem<V>>::current_angular(ang_vel, end);
+                let rotation = match *mode {
+                    RotationEasingMode::Planar { axis } => {
+                        let expected_angle = ang_vel.dot(axis.normalize_or_zero()) * delta_secs;
+                        planar_rotation_lerp(start, end, axis, Some(expected_angle), alpha)
+                    }
+                    _ => slerp_with_winding(start, end, ang_vel * delta_secs, alpha),
+                };
+
+                // Only write when the value changes to avoid triggering change detection.
+                if transform.rotation != rotation {
+                    transform.rotation = rotation;
+                }
+            }
+        },
+    );
+}
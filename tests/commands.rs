@@ -0,0 +1,113 @@
+//! Tests for the easing [`commands`](bevy_transform_interpolation::commands).
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{
+    prelude::*,
+    test_support::{EasingTestApp, LinearVelocity},
+    velocity::LinVel2dSource,
+    TranslationEasingState,
+};
+
+/// Asserts that the entity has no stale easing state, and is at the given translation.
+fn assert_reset_at(app: &EasingTestApp, entity: Entity, translation: Vec3) {
+    let easing = app.world().get::<TranslationEasingState>(entity).unwrap();
+    assert_eq!(easing.start, None);
+    assert_eq!(easing.end, None);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(translation, 1e-4));
+}
+
+#[test]
+fn set_easing_mode_switches_between_interpolation_and_extrapolation() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins((
+            TransformInterpolationPlugin::default(),
+            TransformExtrapolationPlugin::<LinVel2dSource<LinearVelocity>, ()>::default(),
+        ));
+
+    let entity = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+
+    // Interpolate halfway from 1 to 2.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4));
+
+    // Switching to extrapolation restores the true transform of the last fixed timestep.
+    app.entity_commands(entity, |commands| {
+        commands.set_easing_mode(EasingMode::Extrapolation);
+    });
+    assert!(!app
+        .world()
+        .entity(entity)
+        .contains::<TransformInterpolation>());
+    assert!(app
+        .world()
+        .entity(entity)
+        .contains::<TransformExtrapolation>());
+    assert_reset_at(&app, entity, Vec3::new(2.0, 0.0, 0.0));
+
+    // Extrapolate halfway from 3 towards the predicted 4.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+
+    // Switching back to interpolation restores the true transform again.
+    app.entity_commands(entity, |commands| {
+        commands.set_easing_mode(EasingMode::Interpolation);
+    });
+    assert!(app
+        .world()
+        .entity(entity)
+        .contains::<TransformInterpolation>());
+    assert!(!app
+        .world()
+        .entity(entity)
+        .contains::<TransformExtrapolation>());
+    assert_reset_at(&app, entity, Vec3::new(3.0, 0.0, 0.0));
+
+    // Interpolate halfway from 3 to 4.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn reset_easing_only_sends_events_for_eased_entities() {
+    let mut app = EasingTestApp::new().with_plugins((
+        TransformEasingPlugin {
+            emit_reset_events: true,
+            ..default()
+        },
+        TransformInterpolationPlugin::default(),
+    ));
+
+    let eased = app.spawn((Transform::default(), TransformInterpolation));
+    let not_eased = app.spawn(Transform::default());
+    app.step_fixed();
+
+    for entity in [eased, not_eased] {
+        app.entity_commands(entity, |commands| {
+            commands.reset_easing();
+        });
+    }
+
+    let reset_entities: Vec<Entity> = app
+        .world()
+        .resource::<Events<EasingResetEvent>>()
+        .iter_current_update_events()
+        .filter(|event| event.reason == EasingResetReason::Command)
+        .map(|event| event.entity)
+        .collect();
+    assert_eq!(reset_entities, vec![eased]);
+}
@@ -0,0 +1,166 @@
+//! Tests for extrapolation with the [`TransformExtrapolationPlugin`].
+
+use core::time::Duration;
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{
+    prelude::*,
+    test_support::{apply_linear_velocity, EasingTestApp, LinearVelocity},
+    velocity::{AngVel2dSource, LinVel2dSource},
+    TranslationEasingState,
+};
+
+/// The translations seen by the simulation at the start of each fixed timestep.
+#[derive(Resource, Default)]
+struct SimulatedTranslations(Vec<Vec3>);
+
+fn record_simulated_translations(
+    query: Query<&Transform, With<LinearVelocity>>,
+    mut simulated: ResMut<SimulatedTranslations>,
+) {
+    simulated
+        .0
+        .extend(query.iter().map(|transform| transform.translation));
+}
+
+/// Creates an app with a fixed timestep of 10 Hz, where entities with a [`LinearVelocity`]
+/// are extrapolated with the given plugin and move in [`FixedUpdate`].
+fn extrapolation_app(
+    plugin: TransformExtrapolationPlugin<LinVel2dSource<LinearVelocity>, ()>,
+) -> EasingTestApp {
+    EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(plugin)
+}
+
+#[test]
+fn pausing_mid_extrapolation_restores_true_transform() {
+    let mut app = extrapolation_app(TransformExtrapolationPlugin {
+        complete_extrapolation: true,
+        ..default()
+    });
+    app.app_mut()
+        .init_resource::<SimulatedTranslations>()
+        .add_systems(
+            FixedUpdate,
+            record_simulated_translations.before(apply_linear_velocity),
+        );
+
+    let entity = app.spawn_moving(TranslationExtrapolation);
+    app.step_fixed_n(2);
+
+    // Extrapolate halfway from 2 towards the predicted 3, and pause.
+    app.advance_to_overstep(0.5);
+    let paused_translation = app.transform(entity).translation;
+    assert!(paused_translation.abs_diff_eq(Vec3::new(2.5, 0.0, 0.0), 1e-4));
+    app.world_mut().insert_resource(EasingPaused(true));
+
+    // The transform doesn't move while paused.
+    app.advance_to_overstep(0.75);
+    assert_eq!(app.transform(entity).translation, paused_translation);
+
+    // The next fixed timestep still starts from the true transform instead of the extrapolated one.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4));
+
+    // After resuming, extrapolation continues from the true transform.
+    app.world_mut().insert_resource(EasingPaused(false));
+    app.advance_to_overstep(0.75);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.75, 0.0, 0.0), 1e-4));
+    app.advance_to_overstep(0.5);
+
+    // The simulation only ever saw the true translations.
+    let simulated = &app.world().resource::<SimulatedTranslations>().0;
+    assert_eq!(simulated.len(), 4);
+    for (i, translation) in simulated.iter().enumerate() {
+        assert!(
+            translation.abs_diff_eq(Vec3::new(i as f32, 0.0, 0.0), 1e-4),
+            "fixed timestep {i} started from {translation}"
+        );
+    }
+}
+
+#[test]
+fn pausing_skips_extrapolation_by_default() {
+    let mut app = extrapolation_app(TransformExtrapolationPlugin::default());
+    let entity = app.spawn_moving(TranslationExtrapolation);
+    app.step_fixed_n(2);
+
+    // Extrapolate halfway from 2 towards the predicted 3, and pause.
+    app.advance_to_overstep(0.5);
+    app.world_mut().insert_resource(EasingPaused(true));
+
+    // The extrapolation systems are skipped while paused, so the fixed timestep continues
+    // from the partially extrapolated transform instead of the true transform.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn extrapolation_predicts_over_fixed_timestep() {
+    let mut app = extrapolation_app(TransformExtrapolationPlugin::default());
+    let entity = app.spawn_moving(TranslationExtrapolation);
+    app.step_fixed_n(2);
+
+    // Run a frame that is longer than the fixed timestep, with a single fixed timestep.
+    app.advance_render(Duration::from_millis(130));
+
+    // The `end` is predicted one fixed timestep ahead, not one frame ahead.
+    let easing = app.world().get::<TranslationEasingState>(entity).unwrap();
+    assert!(easing
+        .start
+        .is_some_and(|start| start.abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4)));
+    assert!(easing
+        .end
+        .is_some_and(|end| end.abs_diff_eq(Vec3::new(4.0, 0.0, 0.0), 1e-4)));
+}
+
+#[derive(Component, Deref)]
+struct AngularVelocity(f32);
+
+fn spin(mut query: Query<(&mut Transform, &AngularVelocity)>, time: Res<Time>) {
+    for (mut transform, ang_vel) in &mut query {
+        transform.rotate_z(ang_vel.0 * time.delta_secs());
+    }
+}
+
+#[test]
+fn fast_spin_extrapolates_more_than_full_revolution() {
+    let mut app =
+        EasingTestApp::new()
+            .with_fixed_hz(10.0)
+            .with_plugins(TransformExtrapolationPlugin::<
+                (),
+                AngVel2dSource<AngularVelocity>,
+            >::default());
+    app.app_mut().add_systems(FixedUpdate, spin);
+
+    // Rotate by 7 radians per fixed timestep, which is more than a full revolution.
+    let entity = app.spawn((
+        Transform::default(),
+        RotationExtrapolation,
+        FastSpin::default(),
+        AngularVelocity(70.0),
+    ));
+    app.step_fixed_n(2);
+
+    // Halfway through the fixed timestep, the rotation follows the full predicted rotation
+    // instead of the shortest path to the predicted `end`.
+    app.advance_to_overstep(0.5);
+    let expected = Quat::from_rotation_z(14.0 + 3.5);
+    let rotation = app.transform(entity).rotation;
+    assert!(
+        rotation.angle_between(expected) < 1e-3,
+        "expected {expected}, got {rotation}"
+    );
+}
@@ -0,0 +1,511 @@
+//! Tests for [`Transform`] interpolation with the [`TransformInterpolationPlugin`].
+
+use core::time::Duration;
+
+use bevy::{math::ops::FloatPow, prelude::*};
+use bevy_transform_interpolation::{
+    prelude::*,
+    test_support::{apply_linear_velocity, EasingTestApp, LinearVelocity},
+    TranslationEasingState,
+};
+
+/// Creates an app with a fixed timestep of 10 Hz, where entities with a [`LinearVelocity`] move in [`FixedUpdate`].
+fn interpolation_app() -> EasingTestApp {
+    EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(TransformInterpolationPlugin::default())
+}
+
+#[test]
+fn spawned_entity_does_not_ease_from_origin() {
+    let mut app = interpolation_app();
+    let entity = app.spawn((
+        Transform::from_xyz(5.0, 0.0, 0.0),
+        TransformInterpolation,
+        LinearVelocity(Vec2::new(10.0, 0.0)),
+    ));
+
+    // The first fixed timestep moves the entity from 5 to 6, and easing starts at the spawn position.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-4));
+
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(5.5, 0.0, 0.0), 1e-4));
+}
+
+/// A marker for entities spawned by [`spawn_in_fixed_update`].
+#[derive(Component)]
+struct SpawnedInFixedUpdate;
+
+fn spawn_in_fixed_update(mut commands: Commands, mut spawned: Local<bool>) {
+    if !*spawned {
+        *spawned = true;
+        commands.spawn((
+            Transform::from_xyz(3.0, 0.0, 0.0),
+            TransformInterpolation,
+            LinearVelocity(Vec2::new(10.0, 0.0)),
+            SpawnedInFixedUpdate,
+        ));
+    }
+}
+
+#[test]
+fn entity_spawned_in_fixed_update_is_eased() {
+    let mut app = interpolation_app();
+    app.app_mut()
+        .add_systems(FixedUpdate, spawn_in_fixed_update);
+
+    // The entity is spawned after the `start` of easing was updated for the fixed timestep.
+    app.step_fixed();
+    app.advance_to_overstep(0.5);
+
+    let world = app.world_mut();
+    let entity = world
+        .query_filtered::<Entity, With<SpawnedInFixedUpdate>>()
+        .single(world);
+    let easing = *world.get::<TranslationEasingState>(entity).unwrap();
+    assert!(easing.start.is_some() && easing.end.is_some());
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4));
+
+    // The next fixed timestep moves the entity from 3 to 4, which is eased without a pop.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+}
+
+/// The entities whose [`Transform`] was changed during the last frame.
+#[derive(Resource, Default)]
+struct ChangedTransforms(Vec<Entity>);
+
+fn record_changed_transforms(
+    query: Query<Entity, Changed<Transform>>,
+    mut changed: ResMut<ChangedTransforms>,
+) {
+    changed.0 = query.iter().collect();
+}
+
+#[test]
+fn stationary_entity_does_not_trigger_change_detection() {
+    let mut app = interpolation_app();
+    app.app_mut()
+        .init_resource::<ChangedTransforms>()
+        .add_systems(Last, record_changed_transforms);
+
+    let stationary = app.spawn((Transform::from_xyz(1.0, 2.0, 3.0), TransformInterpolation));
+    let moving = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+    app.advance_to_overstep(0.25);
+
+    // Ease on a frame without a fixed timestep.
+    app.advance_to_overstep(0.75);
+
+    let changed = &app.world().resource::<ChangedTransforms>().0;
+    assert!(changed.contains(&moving));
+    assert!(!changed.contains(&stationary));
+}
+
+#[test]
+fn shrinking_timestep_keeps_easing_speed() {
+    let mut app = interpolation_app();
+    let entity = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+
+    // Ease a quarter of the way from 1 to 2.
+    app.advance_render(Duration::from_millis(25));
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.25, 0.0, 0.0), 1e-4));
+
+    // Halve the timestep in the middle of the easing. The current easing was recorded
+    // with the old timestep, so it keeps its speed instead of jumping ahead.
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .set_timestep(Duration::from_millis(50));
+    app.advance_render(Duration::from_millis(10));
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.35, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn frames_with_zero_or_multiple_fixed_timesteps_use_current_overstep() {
+    let mut app = interpolation_app();
+    let entity = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+
+    // Frames without a fixed timestep keep easing from 1 to 2 with the current overstep.
+    for (overstep, expected) in [(0.25, 1.25), (0.5, 1.5), (0.75, 1.75)] {
+        app.advance_to_overstep(overstep);
+        assert!(
+            app.transform(entity)
+                .translation
+                .abs_diff_eq(Vec3::new(expected, 0.0, 0.0), 1e-4),
+            "expected {expected} at overstep {overstep}"
+        );
+    }
+
+    // A frame with three fixed timesteps eases from the start of the last one.
+    app.advance_render(Duration::from_millis(250));
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(4.25, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn easing_without_fixed_time_does_not_panic() {
+    // Build the app without the `TimePlugin`, so there is no `Time<Fixed>` resource.
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        TransformPlugin,
+        HierarchyPlugin,
+        TransformInterpolationPlugin::default(),
+    ));
+    app.finish();
+    app.cleanup();
+
+    let entity = app
+        .world_mut()
+        .spawn((Transform::from_xyz(1.0, 2.0, 3.0), TransformInterpolation))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(
+        app.world().get::<Transform>(entity),
+        Some(&Transform::from_xyz(1.0, 2.0, 3.0))
+    );
+}
+
+fn spin(mut query: Query<&mut Transform, With<LinearVelocity>>, time: Res<Time>) {
+    for mut transform in &mut query {
+        transform.rotate_z(3.0 * time.delta_secs());
+    }
+}
+
+/// Runs the same sequence of frames with varying frame times for many moving entities,
+/// and returns the eased transforms of every frame.
+fn run_eased_sequence() -> Vec<Vec<Transform>> {
+    let mut app = interpolation_app();
+    app.app_mut().add_systems(FixedUpdate, spin);
+
+    let entities: Vec<Entity> = (0..100)
+        .map(|i| {
+            let i = i as f32;
+            app.spawn((
+                Transform::from_xyz(i, -i, 0.5 * i),
+                TransformInterpolation,
+                LinearVelocity(Vec2::new(ops::sin(i), ops::cos(i))),
+            ))
+        })
+        .collect();
+
+    [7, 33, 61, 100, 3, 145, 52, 88, 17, 240]
+        .into_iter()
+        .map(|millis| {
+            app.advance_render(Duration::from_millis(millis));
+            entities
+                .iter()
+                .map(|&entity| app.transform(entity))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn easing_is_deterministic() {
+    // The eased transforms must be bit-identical, not just approximately equal.
+    assert_eq!(run_eased_sequence(), run_eased_sequence());
+}
+
+/// The number of fixed timesteps run, and the translation of the entity at the end of the last one.
+#[derive(Resource, Default)]
+struct SimulatedSteps {
+    count: u32,
+    translation: Vec3,
+}
+
+fn record_simulation(
+    query: Query<&Transform, With<LinearVelocity>>,
+    mut simulated: ResMut<SimulatedSteps>,
+) {
+    if let Ok(transform) = query.get_single() {
+        simulated.count += 1;
+        simulated.translation = transform.translation;
+    }
+}
+
+#[test]
+fn long_session_does_not_drift() {
+    // At 8 Hz, the entity moves by exactly 0.25 per fixed timestep, which has no rounding error,
+    // so any drift would come from eased values leaking into the simulation.
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(8.0)
+        .with_movement()
+        .with_plugins(TransformInterpolationPlugin::default());
+    app.app_mut()
+        .init_resource::<SimulatedSteps>()
+        .add_systems(FixedUpdate, record_simulation.after(apply_linear_velocity));
+
+    app.spawn((
+        Transform::default(),
+        TransformInterpolation,
+        LinearVelocity(Vec2::new(2.0, 0.0)),
+    ));
+
+    // Ease at overstep fractions that are not exactly representable in between fixed timesteps.
+    // Each iteration runs one fixed timestep when wrapping around from 0.7 to 0.3.
+    for _ in 0..5000 {
+        app.advance_to_overstep(0.7);
+        app.advance_to_overstep(0.3);
+    }
+
+    let simulated = app.world().resource::<SimulatedSteps>();
+    assert_eq!(simulated.count, 5000);
+    assert!(simulated
+        .translation
+        .abs_diff_eq(Vec3::new(0.25 * 5000.0, 0.0, 0.0), 1e-6));
+}
+
+#[test]
+fn easing_strength_blends_towards_true_transform() {
+    let mut app = interpolation_app();
+    let entities = [0.0, 0.5, 1.0]
+        .map(|strength| app.spawn_moving((TransformInterpolation, EasingStrength(strength))));
+    app.step_fixed_n(2);
+
+    // Halfway from 1 to 2, the eased translation is 1.5, and the true translation is 2.
+    app.advance_to_overstep(0.5);
+
+    for (entity, expected) in entities.into_iter().zip([2.0, 1.75, 1.5]) {
+        let translation = app.transform(entity).translation;
+        assert!(
+            translation.abs_diff_eq(Vec3::new(expected, 0.0, 0.0), 1e-4),
+            "expected {expected}, got {translation}"
+        );
+    }
+}
+
+/// Moves the [`GlobalTransform`] of entities with [`GlobalTransformInterpolation`] directly,
+/// by one unit along the X axis per fixed timestep.
+fn move_global_transform(
+    mut query: Query<&mut GlobalTransform, With<GlobalTransformInterpolation>>,
+) {
+    for mut global_transform in &mut query {
+        *global_transform =
+            GlobalTransform::from_translation(global_transform.translation() + Vec3::X);
+    }
+}
+
+#[test]
+fn global_transform_only_scene_is_eased() {
+    // Only the `GlobalTransform` is eased, so no entity has a local easing state.
+    let mut app = interpolation_app();
+    app.app_mut()
+        .add_systems(FixedUpdate, move_global_transform);
+
+    let entity = app.spawn((GlobalTransform::IDENTITY, GlobalTransformInterpolation));
+    app.step_fixed_n(2);
+
+    // Halfway from 1 to 2.
+    app.advance_to_overstep(0.5);
+    let translation = app
+        .world()
+        .get::<GlobalTransform>(entity)
+        .unwrap()
+        .translation();
+    assert!(
+        translation.abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4),
+        "expected 1.5, got {translation}"
+    );
+}
+
+#[test]
+fn global_transform_easing_respects_easing_strength() {
+    let mut app = interpolation_app();
+    app.app_mut()
+        .add_systems(FixedUpdate, move_global_transform);
+
+    let entity = app.spawn((
+        GlobalTransform::IDENTITY,
+        GlobalTransformInterpolation,
+        EasingStrength(0.5),
+    ));
+    app.step_fixed_n(2);
+
+    // Halfway between the end at 2 and the fully eased translation at 1.5.
+    app.advance_to_overstep(0.5);
+    let translation = app
+        .world()
+        .get::<GlobalTransform>(entity)
+        .unwrap()
+        .translation();
+    assert!(
+        translation.abs_diff_eq(Vec3::new(1.75, 0.0, 0.0), 1e-4),
+        "expected 1.75, got {translation}"
+    );
+}
+
+fn spawn_world_space_child_in_fixed_update(mut commands: Commands, mut spawned: Local<bool>) {
+    if !*spawned {
+        *spawned = true;
+        commands
+            .spawn(Transform::default())
+            .with_children(|parent| {
+                parent.spawn((
+                    Transform::from_xyz(3.0, 0.0, 0.0),
+                    TransformInterpolation,
+                    EasingSpace::World,
+                    LinearVelocity(Vec2::new(10.0, 0.0)),
+                    SpawnedInFixedUpdate,
+                ));
+            });
+    }
+}
+
+#[test]
+fn world_space_child_spawned_in_fixed_update_is_eased() {
+    let mut app = interpolation_app();
+    app.app_mut()
+        .add_systems(FixedUpdate, spawn_world_space_child_in_fixed_update);
+
+    // The child is spawned after the world space `start` was updated for the fixed timestep.
+    app.step_fixed();
+    app.advance_to_overstep(0.5);
+
+    let world = app.world_mut();
+    let entity = world
+        .query_filtered::<Entity, With<SpawnedInFixedUpdate>>()
+        .single(world);
+    let easing = *world.get::<GlobalSpaceEasingState>(entity).unwrap();
+    assert!(easing.start.is_some() && easing.end.is_some());
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4));
+
+    // The next fixed timestep moves the child from 3 to 4, which is eased in world space without a pop.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn world_space_child_is_not_eased_while_auto_disabled() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins((
+            TransformEasingPlugin {
+                auto_disable_when_fast: true,
+                ..default()
+            },
+            TransformInterpolationPlugin::default(),
+        ));
+
+    let parent = app.spawn(Transform::default());
+    let child = app.spawn((
+        Transform::default(),
+        TransformInterpolation,
+        EasingSpace::World,
+        LinearVelocity(Vec2::new(10.0, 0.0)),
+    ));
+    app.world_mut().entity_mut(parent).add_child(child);
+    app.step_fixed_n(2);
+
+    // The frame time is longer than the fixed timestep, so easing is disabled,
+    // and the child keeps its true transform instead of being eased from 2 to 3.
+    app.advance_render_secs(0.15);
+    assert!(app.world().resource::<EasingAutoDisabled>().0);
+    let translation = app.transform(child).translation;
+    assert!(
+        translation.abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4),
+        "expected 3.0, got {translation}"
+    );
+}
+
+/// A marker for entities rotated by [`rotate_by_one_radian`].
+#[derive(Component)]
+struct Rotating;
+
+/// Rotates entities with [`Rotating`] by one radian around the Y axis per fixed timestep.
+fn rotate_by_one_radian(mut query: Query<&mut Transform, With<Rotating>>) {
+    for mut transform in &mut query {
+        transform.rotate_y(1.0);
+    }
+}
+
+#[test]
+fn nlerp_rotation_easing_stays_close_to_slerp() {
+    let mut app = interpolation_app();
+    app.app_mut().add_systems(FixedUpdate, rotate_by_one_radian);
+
+    let entity = app.spawn((
+        Transform::default(),
+        TransformInterpolation,
+        RotationEasingMode::Nlerp,
+        Rotating,
+    ));
+    app.step_fixed_n(2);
+
+    // A quarter of the way from 1 to 2 radians.
+    app.advance_to_overstep(0.25);
+    let rotation = app.transform(entity).rotation;
+    let slerp = Quat::from_rotation_y(1.0).slerp(Quat::from_rotation_y(2.0), 0.25);
+
+    // The angular error of `nlerp` is at most about `angle^3 / 240` for a step of `angle` radians.
+    let error = rotation.angle_between(slerp);
+    let step_angle: f32 = 1.0;
+    assert!(
+        error <= step_angle.cubed() / 240.0 + 1e-4,
+        "error {error} is too large"
+    );
+
+    // The rotation is still eased with `nlerp` rather than `slerp`.
+    assert!(error > 1e-3, "expected nlerp, got slerp");
+}
+
+#[test]
+fn easing_time_scale_respects_overstep_clamp() {
+    // Allow overshooting past the `end` of easing.
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins((
+            TransformEasingPlugin {
+                overstep_clamp: OverstepClamp { min: 0.0, max: 1.5 },
+                ..default()
+            },
+            TransformInterpolationPlugin::default(),
+        ));
+
+    let entity = app.spawn_moving((TransformInterpolation, EasingTimeScale(2.0)));
+    app.step_fixed_n(2);
+
+    // The scaled overstep of 1.4 is within the clamp, so the entity overshoots from 2 to 2.4.
+    app.advance_to_overstep(0.7);
+    let translation = app.transform(entity).translation;
+    assert!(
+        translation.abs_diff_eq(Vec3::new(2.4, 0.0, 0.0), 1e-4),
+        "expected 2.4, got {translation}"
+    );
+}
@@ -0,0 +1,42 @@
+//! Tests for 2D interpolation with the [`Transform2dInterpolationPlugin`].
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{interpolation_2d::*, prelude::*, test_support::EasingTestApp};
+
+#[test]
+fn teleport_resets_2d_easing() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(Transform2dInterpolationPlugin::default());
+
+    let entity = app.spawn_moving(Transform2dInterpolation);
+    app.step_fixed_n(2);
+
+    // Ease a quarter of the way from 1 to 2, and teleport.
+    app.advance_to_overstep(0.25);
+    app.entity_commands(entity, |commands| {
+        commands.teleport_no_ease(Transform::from_xyz(50.0, 0.0, 0.0));
+    });
+
+    let translation_easing = app.world().get::<Translation2dEasingState>(entity).unwrap();
+    assert_eq!(translation_easing.start, None);
+    assert_eq!(translation_easing.end, None);
+    let rotation_easing = app.world().get::<Rotation2dEasingState>(entity).unwrap();
+    assert_eq!(rotation_easing.start, None);
+    assert_eq!(rotation_easing.end, None);
+
+    // The entity snaps to the teleported position instead of being eased across the screen.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(50.0, 0.0, 0.0), 1e-4));
+
+    // The next fixed timestep eases from the teleported position.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(50.5, 0.0, 0.0), 1e-4));
+}
@@ -0,0 +1,104 @@
+//! Tests for double-precision interpolation with the [`TransformF64InterpolationPlugin`].
+
+use bevy::{
+    math::{DQuat, DVec3},
+    prelude::*,
+};
+use bevy_transform_interpolation::{
+    hermite::{hermite_dquat, hermite_dvec3},
+    interpolation_f64::*,
+    test_support::EasingTestApp,
+};
+
+/// A position far away from the origin, where single-precision floats can only represent
+/// multiples of `128.0`.
+const FAR_AWAY: f64 = 1.0e9;
+
+/// A double-precision transform that moves along the X axis by one unit every fixed timestep.
+#[derive(Component, Default)]
+struct DoubleTransform {
+    translation: DVec3,
+    rotation: DQuat,
+}
+
+impl EaseTargetF64 for DoubleTransform {
+    fn translation(&self) -> DVec3 {
+        self.translation
+    }
+
+    fn set_translation(&mut self, translation: DVec3) {
+        self.translation = translation;
+    }
+
+    fn rotation(&self) -> DQuat {
+        self.rotation
+    }
+
+    fn set_rotation(&mut self, rotation: DQuat) {
+        self.rotation = rotation;
+    }
+}
+
+fn move_double_transforms(mut query: Query<&mut DoubleTransform>) {
+    for mut transform in &mut query {
+        transform.translation.x += 1.0;
+        transform.rotation = DQuat::from_rotation_z(0.5) * transform.rotation;
+    }
+}
+
+#[test]
+fn f64_interpolation_is_precise_far_from_origin() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_plugins(TransformF64InterpolationPlugin::<DoubleTransform>::default());
+    app.app_mut()
+        .add_systems(FixedUpdate, move_double_transforms);
+
+    let entity = app.spawn((
+        DoubleTransform {
+            translation: DVec3::new(FAR_AWAY, 0.0, 0.0),
+            rotation: DQuat::IDENTITY,
+        },
+        TransformF64Interpolation,
+    ));
+
+    // The second fixed timestep moves the entity from 1 to 2 units away from `FAR_AWAY`.
+    app.step_fixed_n(2);
+
+    app.advance_to_overstep(0.25);
+    let transform = app.world().get::<DoubleTransform>(entity).unwrap();
+    assert!(transform
+        .translation
+        .abs_diff_eq(DVec3::new(FAR_AWAY + 1.25, 0.0, 0.0), 1e-6));
+    assert!(transform
+        .rotation
+        .abs_diff_eq(DQuat::from_rotation_z(0.625), 1e-9));
+
+    // The easing states are stored in double precision too.
+    let easing = app
+        .world()
+        .get::<TranslationF64EasingState>(entity)
+        .unwrap();
+    assert_eq!(easing.start, Some(DVec3::new(FAR_AWAY + 1.0, 0.0, 0.0)));
+    assert_eq!(easing.end, Some(DVec3::new(FAR_AWAY + 2.0, 0.0, 0.0)));
+}
+
+#[test]
+fn hermite_f64_functions_match_endpoints() {
+    let p0 = DVec3::new(FAR_AWAY, 0.0, 0.0);
+    let p1 = DVec3::new(FAR_AWAY + 1.0, 0.0, 0.0);
+    let v = DVec3::X;
+
+    assert_eq!(hermite_dvec3(p0, p1, v, v, 0.0), p0);
+    assert_eq!(hermite_dvec3(p0, p1, v, v, 1.0), p1);
+
+    // With constant velocity, the curve is a straight line.
+    assert!(
+        hermite_dvec3(p0, p1, v, v, 0.5).abs_diff_eq(DVec3::new(FAR_AWAY + 0.5, 0.0, 0.0), 1e-6)
+    );
+
+    let q0 = DQuat::IDENTITY;
+    let q1 = DQuat::from_rotation_z(1.0);
+    let w = DVec3::Z;
+    assert!(hermite_dquat(q0, q1, w, w, 0.5, true).abs_diff_eq(DQuat::from_rotation_z(0.5), 1e-9));
+}
@@ -0,0 +1,56 @@
+//! Tests for following a [`TransformPath`] with the [`TransformPathPlugin`].
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{path::*, test_support::EasingTestApp};
+
+/// Creates an app with a fixed timestep of 10 Hz and an entity following a straight linear path
+/// from the origin to `(10, 0, 0)` in one second.
+fn path_app() -> (EasingTestApp, Entity) {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_plugins(TransformPathPlugin::default());
+
+    let entity = app.spawn((
+        Transform::default(),
+        TransformPath {
+            points: vec![Transform::default(), Transform::from_xyz(10.0, 0.0, 0.0)],
+            segment_secs: 1.0,
+            interpolation: PathInterpolation::Linear,
+            ..default()
+        },
+    ));
+
+    (app, entity)
+}
+
+#[test]
+fn path_is_eased_between_fixed_timesteps() {
+    let (mut app, entity) = path_app();
+
+    // Two fixed timesteps move the cursor from 0.1 to 0.2 along the segment.
+    app.step_fixed_n(2);
+
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn non_looping_path_stops_at_last_point() {
+    let (mut app, entity) = path_app();
+
+    app.step_fixed_n(15);
+    app.advance_to_overstep(0.5);
+
+    assert!(app
+        .world()
+        .get::<TransformPath>(entity)
+        .unwrap()
+        .is_finished());
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(10.0, 0.0, 0.0), 1e-4));
+}
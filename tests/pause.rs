@@ -0,0 +1,90 @@
+//! Tests for pausing and resuming easing with the [`EasingPaused`] resource and [`Time<Virtual>`].
+
+use core::time::Duration;
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{prelude::*, test_support::EasingTestApp};
+
+/// Creates an app with a fixed timestep of 10 Hz, where entities with a
+/// [`LinearVelocity`](bevy_transform_interpolation::test_support::LinearVelocity) move in [`FixedUpdate`].
+fn interpolation_app() -> EasingTestApp {
+    EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(TransformInterpolationPlugin::default())
+}
+
+#[test]
+fn resume_continues_from_paused_transform() {
+    let mut app = interpolation_app();
+    let entity = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+
+    // Ease a quarter of the way from 1 to 2, and pause.
+    app.advance_to_overstep(0.25);
+    let paused_translation = app.transform(entity).translation;
+    assert!(paused_translation.abs_diff_eq(Vec3::new(1.25, 0.0, 0.0), 1e-4));
+    app.world_mut().insert_resource(EasingPaused(true));
+
+    // The transform doesn't move while paused.
+    app.advance_to_overstep(0.5);
+    assert_eq!(app.transform(entity).translation, paused_translation);
+
+    // Resuming continues from the paused transform instead of jumping to the current overstep.
+    app.world_mut().insert_resource(EasingPaused(false));
+    app.advance_to_overstep(0.6);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(paused_translation, 1e-4));
+
+    // Easing moves on towards the end of the fixed timestep.
+    app.advance_to_overstep(0.8);
+    let translation = app.transform(entity).translation;
+    assert!(translation.x > paused_translation.x && translation.x < 2.0);
+
+    // The next fixed timestep starts from the end of the previous one as usual.
+    app.advance_to_overstep(1.0);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn teleport_while_virtual_time_is_paused_does_not_jump_on_resume() {
+    let mut app = interpolation_app();
+    let entity = app.spawn_moving(TransformInterpolation);
+    app.step_fixed_n(2);
+
+    // Ease halfway from 1 to 2, and pause virtual time, which also stops the fixed timesteps.
+    app.advance_to_overstep(0.5);
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    app.advance_render(Duration::from_millis(50));
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.5, 0.0, 0.0), 1e-4));
+
+    // Teleport the entity while paused, for example from a pause menu.
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation = Vec3::new(10.0, 0.0, 0.0);
+    app.advance_render(Duration::from_millis(50));
+
+    // Resuming keeps the entity where it was teleported instead of easing it from the old overstep.
+    app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+    app.advance_render(Duration::from_millis(10));
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(10.0, 0.0, 0.0), 1e-4));
+
+    // The next fixed timestep eases from the teleported position.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(10.5, 0.0, 0.0), 1e-4));
+}
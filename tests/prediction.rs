@@ -0,0 +1,93 @@
+//! Tests for acceleration-aware prediction with the [`TransformPredictionPlugin`].
+
+use core::time::Duration;
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{
+    prelude::*,
+    test_support::{EasingTestApp, LinearVelocity},
+    velocity::LinVel2dSource,
+    TranslationEasingState,
+};
+
+/// Creates an app with a fixed timestep of 10 Hz, where entities with a [`LinearVelocity`]
+/// are predicted with the given plugin and move in [`FixedUpdate`].
+fn prediction_app(
+    plugin: TransformPredictionPlugin<LinVel2dSource<LinearVelocity>, ()>,
+) -> EasingTestApp {
+    EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(plugin)
+}
+
+#[test]
+fn pausing_mid_prediction_restores_true_transform() {
+    let mut app = prediction_app(TransformPredictionPlugin {
+        complete_prediction: true,
+        ..default()
+    });
+    let entity = app.spawn_moving(TranslationPrediction);
+    app.step_fixed_n(2);
+
+    // Predict halfway from 2 towards the predicted 3, and pause.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(2.5, 0.0, 0.0), 1e-4));
+    app.world_mut().insert_resource(EasingPaused(true));
+
+    // The next fixed timestep starts from the true transform instead of the predicted one.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4));
+
+    // After resuming, prediction continues from the true transform.
+    app.world_mut().insert_resource(EasingPaused(false));
+    app.advance_to_overstep(0.75);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.75, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn pausing_skips_prediction_by_default() {
+    let mut app = prediction_app(TransformPredictionPlugin::default());
+    let entity = app.spawn_moving(TranslationPrediction);
+    app.step_fixed_n(2);
+
+    // Predict halfway from 2 towards the predicted 3, and pause.
+    app.advance_to_overstep(0.5);
+    app.world_mut().insert_resource(EasingPaused(true));
+
+    // The prediction systems are skipped while paused, so the fixed timestep continues
+    // from the partially predicted transform instead of the true transform.
+    app.step_fixed();
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(3.5, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn prediction_predicts_over_fixed_timestep() {
+    let mut app = prediction_app(TransformPredictionPlugin::default());
+    let entity = app.spawn_moving(TranslationPrediction);
+    app.step_fixed_n(2);
+
+    // Run a frame that is longer than the fixed timestep, with a single fixed timestep.
+    app.advance_render(Duration::from_millis(130));
+
+    // The `end` is predicted one fixed timestep ahead, not one frame ahead.
+    let easing = app.world().get::<TranslationEasingState>(entity).unwrap();
+    assert!(easing
+        .start
+        .is_some_and(|start| start.abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-4)));
+    assert!(easing
+        .end
+        .is_some_and(|end| end.abs_diff_eq(Vec3::new(4.0, 0.0, 0.0), 1e-4)));
+}
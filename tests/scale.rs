@@ -0,0 +1,40 @@
+//! Tests for scale easing options, such as [`ScaleSignSnap`].
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{prelude::*, test_support::EasingTestApp};
+
+/// Flips the sprite on the X axis and grows it on the Y axis every fixed timestep.
+fn flip_and_grow(mut query: Query<&mut Transform, With<ScaleSignSnap>>) {
+    for mut transform in &mut query {
+        transform.scale.x = -transform.scale.x;
+        transform.scale.y += 1.0;
+    }
+}
+
+#[test]
+fn scale_sign_snap_does_not_pass_through_zero() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_plugins(TransformInterpolationPlugin::default());
+    app.app_mut().add_systems(FixedUpdate, flip_and_grow);
+
+    let entity = app.spawn((Transform::default(), TransformInterpolation, ScaleSignSnap));
+
+    // The second fixed timestep flips `scale.x` from -1 to 1 and grows `scale.y` from 2 to 3.
+    app.step_fixed_n(2);
+
+    for overstep in [0.25, 0.5, 0.75] {
+        app.advance_to_overstep(overstep);
+        let scale = app.transform(entity).scale;
+
+        // The flipped axis snaps to the end instead of having a near-zero value,
+        // while the other axes are eased normally.
+        assert_eq!(
+            scale.x, 1.0,
+            "scale.x is {} at overstep {overstep}",
+            scale.x
+        );
+        assert!((scale.y - (2.0 + overstep)).abs() < 1e-4);
+        assert_eq!(scale.z, 1.0);
+    }
+}
@@ -0,0 +1,111 @@
+//! Tests for easing entities at different times, such as with custom easing in other schedules.
+
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use bevy_transform_interpolation::interpolation_2d::{
+    Transform2dInterpolation, Transform2dInterpolationPlugin, Translation2dEasingState,
+};
+use bevy_transform_interpolation::{
+    prelude::*, test_support::EasingTestApp, update_entity_easing_ticks, TranslationEasingState,
+};
+
+/// A marker for entities eased by [`ease_in_update`] instead of the built-in easing.
+#[derive(Component)]
+struct EasedInUpdate;
+
+/// Eases the translation of entities with [`EasedInUpdate`] in [`Update`], after the built-in easing has run.
+fn ease_in_update(
+    mut query: Query<(&mut Transform, &TranslationEasingState), With<EasedInUpdate>>,
+    overstep: Res<EasingOverstep>,
+) {
+    for (mut transform, easing) in &mut query {
+        if let (Some(start), Some(end)) = (easing.start, easing.end) {
+            transform.translation = start.lerp(end, overstep.0);
+        }
+    }
+}
+
+/// Eases the 2D translation of entities with [`EasedInUpdate`] in [`Update`], after the built-in easing has run.
+#[cfg(feature = "2d")]
+fn ease_2d_in_update(
+    mut query: Query<(&mut Transform, &Translation2dEasingState), With<EasedInUpdate>>,
+    overstep: Res<EasingOverstep>,
+) {
+    for (mut transform, easing) in &mut query {
+        if let (Some(start), Some(end)) = (easing.start, easing.end) {
+            let z = transform.translation.z;
+            transform.translation = start.lerp(end, overstep.0).extend(z);
+        }
+    }
+}
+
+#[test]
+fn entities_eased_in_different_schedules_are_not_reset() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(TransformInterpolationPlugin::default());
+    app.app_mut()
+        .add_systems(Update, (ease_in_update, update_entity_easing_ticks).chain());
+
+    // One entity is eased by the built-in easing, and the other by the custom easing in `Update`.
+    let default_entity = app.spawn_moving(TransformInterpolation);
+    let update_entity = app.spawn_moving((
+        TransformInterpolation,
+        NonlinearTranslationEasing,
+        EasedInUpdate,
+    ));
+    app.step_fixed_n(2);
+
+    app.advance_to_overstep(0.5);
+    app.advance_to_overstep(0.75);
+
+    // The transform written by the custom easing in `Update` is not treated as a teleport.
+    let easing = app
+        .world()
+        .get::<TranslationEasingState>(update_entity)
+        .unwrap();
+    assert!(easing
+        .start
+        .is_some_and(|start| start.abs_diff_eq(Vec3::new(1.0, 0.0, 0.0), 1e-4)));
+    assert!(easing
+        .end
+        .is_some_and(|end| end.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-4)));
+
+    for entity in [default_entity, update_entity] {
+        assert!(app
+            .transform(entity)
+            .translation
+            .abs_diff_eq(Vec3::new(1.75, 0.0, 0.0), 1e-4));
+    }
+}
+
+#[cfg(feature = "2d")]
+#[test]
+fn entities_eased_in_2d_in_different_schedules_are_not_reset() {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(Transform2dInterpolationPlugin::default());
+    app.app_mut().add_systems(
+        Update,
+        (ease_2d_in_update, update_entity_easing_ticks).chain(),
+    );
+
+    // The entity is eased by the built-in 2D easing, and then again by the custom easing in `Update`.
+    let entity = app.spawn_moving((Transform2dInterpolation, EasedInUpdate));
+    app.step_fixed_n(2);
+
+    app.advance_to_overstep(0.5);
+    app.advance_to_overstep(0.75);
+
+    // The transform written by the custom easing in `Update` is not treated as a teleport.
+    let easing = app.world().get::<Translation2dEasingState>(entity).unwrap();
+    assert_eq!(easing.start, Some(Vec2::new(1.0, 0.0)));
+    assert_eq!(easing.end, Some(Vec2::new(2.0, 0.0)));
+
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.75, 0.0, 0.0), 1e-4));
+}
@@ -0,0 +1,55 @@
+//! Tests for detecting teleports when the [`Transform`] is changed outside of the fixed timestep.
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{
+    prelude::*, test_support::EasingTestApp, TranslationEasingState,
+};
+
+/// A translation error of the magnitude caused by floating-point round-trips,
+/// for example through other transform representations.
+const ROUND_TRIP_ERROR: Vec3 = Vec3::new(0.0, 1e-5, 0.0);
+
+/// Spawns an interpolated entity at rest, and runs fixed timesteps until its easing states are initialized.
+fn spawn_at_rest(app: &mut EasingTestApp) -> Entity {
+    let entity = app.spawn((
+        Transform::from_xyz(100.0, 20.0, 0.0),
+        TransformInterpolation,
+    ));
+    app.step_fixed_n(2);
+    entity
+}
+
+/// Changes the translation by the round-trip error in between fixed timesteps,
+/// and runs easing for the next frame.
+fn nudge_by_round_trip_error(app: &mut EasingTestApp, entity: Entity) {
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation += ROUND_TRIP_ERROR;
+    app.advance_to_overstep(0.5);
+}
+
+#[test]
+fn round_trip_error_does_not_reset_easing() {
+    let mut app = EasingTestApp::new().with_plugins(TransformInterpolationPlugin::default());
+    let entity = spawn_at_rest(&mut app);
+
+    nudge_by_round_trip_error(&mut app, entity);
+
+    let easing = app.world().get::<TranslationEasingState>(entity).unwrap();
+    assert_eq!(easing.start, Some(Vec3::new(100.0, 20.0, 0.0)));
+    assert_eq!(easing.end, Some(Vec3::new(100.0, 20.0, 0.0)));
+}
+
+#[test]
+fn round_trip_error_resets_easing_with_zero_epsilon() {
+    let mut app = EasingTestApp::new().with_plugins(TransformInterpolationPlugin::default());
+    let entity = spawn_at_rest(&mut app);
+    app.world_mut().insert_resource(TeleportEpsilon::ZERO);
+
+    nudge_by_round_trip_error(&mut app, entity);
+
+    let easing = app.world().get::<TranslationEasingState>(entity).unwrap();
+    assert_eq!(easing.start, None);
+    assert_eq!(easing.end, None);
+}
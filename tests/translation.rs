@@ -0,0 +1,84 @@
+//! Tests for translation easing options, such as [`WrappingTranslation`] and [`SnapZLayer`].
+
+use bevy::prelude::*;
+use bevy_transform_interpolation::{
+    prelude::*,
+    test_support::{apply_linear_velocity, EasingTestApp, LinearVelocity},
+};
+
+/// The size of the wrapping world on the X axis, centered at the origin.
+const WORLD_SIZE: f32 = 10.0;
+
+/// Wraps the X coordinate of entities with [`WrappingTranslation`] to the world bounds.
+fn wrap_around(mut query: Query<&mut Transform, With<WrappingTranslation>>) {
+    for mut transform in &mut query {
+        let x = transform.translation.x;
+        transform.translation.x = (x + WORLD_SIZE / 2.0).rem_euclid(WORLD_SIZE) - WORLD_SIZE / 2.0;
+    }
+}
+
+/// Moves entities with [`SnapZLayer`] up by one layer every fixed timestep.
+fn climb_layer(mut query: Query<&mut Transform, With<SnapZLayer>>) {
+    for mut transform in &mut query {
+        transform.translation.z += 1.0;
+    }
+}
+
+/// Creates an app with a fixed timestep of 10 Hz, where entities with a [`LinearVelocity`] move in [`FixedUpdate`].
+fn translation_app() -> EasingTestApp {
+    let mut app = EasingTestApp::new()
+        .with_fixed_hz(10.0)
+        .with_movement()
+        .with_plugins(TransformInterpolationPlugin::default());
+    app.app_mut().add_systems(
+        FixedUpdate,
+        (wrap_around, climb_layer).after(apply_linear_velocity),
+    );
+    app
+}
+
+#[test]
+fn wrapping_translation_eases_across_boundary() {
+    let mut app = translation_app();
+    let entity = app.spawn_moving((
+        Transform::from_xyz(4.5, 0.0, 0.0),
+        TransformInterpolation,
+        WrappingTranslation {
+            bounds: Vec3::new(WORLD_SIZE, 0.0, 0.0),
+        },
+    ));
+
+    // The fixed timestep moves the entity across the boundary from 4.5 to -4.5.
+    app.step_fixed();
+
+    // The entity is eased across the boundary instead of through the middle of the world.
+    app.advance_to_overstep(0.25);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(4.75, 0.0, 0.0), 1e-4));
+
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn snap_z_layer_eases_only_x_and_y() {
+    let mut app = translation_app();
+    let entity = app.spawn((
+        TransformInterpolation,
+        SnapZLayer,
+        LinearVelocity(Vec2::new(10.0, 10.0)),
+    ));
+    app.step_fixed_n(2);
+
+    // X and Y are eased from 1 to 2, while the Z layer is already at 2.
+    app.advance_to_overstep(0.5);
+    assert!(app
+        .transform(entity)
+        .translation
+        .abs_diff_eq(Vec3::new(1.5, 1.5, 2.0), 1e-4));
+}